@@ -0,0 +1,51 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Compares the time taken to convert a year of ordinal days with
+//! `bulk::convert_ordinals` and with `discordian_components` date by date;
+//! run with `cargo bench`.
+
+use chrono::NaiveDate;
+use ddate::{bulk::convert_ordinals, DiscordianDate};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of ordinal days converted each way.
+const ORDINALS: usize = 10_000_000;
+
+/// Times one call of `convert`, returning the time taken per ordinal day.
+fn time<T>(ordinals: &[u16], convert: impl Fn(&[u16]) -> Vec<T>) -> Duration {
+    let start = Instant::now();
+    black_box(convert(black_box(ordinals)));
+
+    start.elapsed() / ordinals.len() as u32
+}
+
+fn main() {
+    let ordinals: Vec<u16> = (0..ORDINALS).map(|n| (n % 365 + 1) as u16).collect();
+
+    let bulk = time(&ordinals, |ordinals| {
+        let mut out = Vec::with_capacity(ordinals.len());
+        convert_ordinals(2017, ordinals, &mut out);
+        out
+    });
+    let per_date = time(&ordinals, |ordinals| {
+        ordinals
+            .iter()
+            .map(|&o| {
+                NaiveDate::from_yo_opt(2017, u32::from(o))
+                    .unwrap()
+                    .discordian_components()
+            })
+            .collect()
+    });
+
+    println!("convert_ordinals:      {:?} per date", bulk);
+    println!("discordian_components: {:?} per date", per_date);
+}
@@ -0,0 +1,47 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Compares the time taken to render dates with `to_poee`, which allocates a
+//! `String` for each, and `with_poee_buffered`, which reuses one; run with
+//! `cargo bench`.
+
+use chrono::NaiveDate;
+use ddate::DiscordianDate;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of times each year of dates is rendered.
+const ROUNDS: usize = 200;
+
+/// Renders every date of a leap YOLD `ROUNDS` times with `render`, returning
+/// the time taken per date.
+fn time(dates: &[NaiveDate], render: impl Fn(&NaiveDate) -> usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for date in dates {
+            black_box(render(black_box(date)));
+        }
+    }
+
+    start.elapsed() / (ROUNDS * dates.len()) as u32
+}
+
+fn main() {
+    let dates: Vec<NaiveDate> = NaiveDate::from_ymd_opt(2016, 1, 1)
+        .unwrap()
+        .iter_days()
+        .take(366)
+        .collect();
+
+    let allocated = time(&dates, |date| date.to_poee().len());
+    let buffered = time(&dates, |date| date.with_poee_buffered(str::len));
+
+    println!("to_poee:            {:?} per date", allocated);
+    println!("with_poee_buffered: {:?} per date", buffered);
+}
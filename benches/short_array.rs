@@ -0,0 +1,47 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Compares the time taken to render dates with `to_poee_short`, which
+//! allocates a `String` for each, and `to_poee_short_array`, which fills a
+//! fixed buffer; run with `cargo bench`.
+
+use chrono::NaiveDate;
+use ddate::DiscordianDate;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of times each year of dates is rendered.
+const ROUNDS: usize = 200;
+
+/// Renders every date of a leap YOLD `ROUNDS` times with `render`, returning
+/// the time taken per date.
+fn time(dates: &[NaiveDate], render: impl Fn(&NaiveDate) -> usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for date in dates {
+            black_box(render(black_box(date)));
+        }
+    }
+
+    start.elapsed() / (ROUNDS * dates.len()) as u32
+}
+
+fn main() {
+    let dates: Vec<NaiveDate> = NaiveDate::from_ymd_opt(2016, 1, 1)
+        .unwrap()
+        .iter_days()
+        .take(366)
+        .collect();
+
+    let string = time(&dates, |date| date.to_poee_short().len());
+    let array = time(&dates, |date| date.to_poee_short_array().0);
+
+    println!("to_poee_short:       {:?} per date", string);
+    println!("to_poee_short_array: {:?} per date", array);
+}
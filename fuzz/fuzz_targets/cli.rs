@@ -0,0 +1,41 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Feeds arbitrary arguments, separated by NUL bytes, through the parsing
+//! and conversion the `ddate` binary performs on its arguments: Gregorian
+//! dates through `dtparse`, and `--reverse` dates through
+//! `parse_discordian`.
+
+#![no_main]
+
+use ddate::{parse_discordian, DiscordianDate};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let dates: Vec<&str> = input
+        .split('\0')
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
+
+    for arg in &dates {
+        if let Ok((dt, _)) = dtparse::parse(arg) {
+            let date = dt.date();
+            let _ = date.to_poee();
+
+            let components = date.discordian_components();
+            assert_eq!(Some(date), components.to_naive_date());
+        }
+    }
+
+    if let Ok(date) = parse_discordian(&dates.join(" ")) {
+        if let Some(gregorian) = date.to_naive_date() {
+            assert_eq!(Ok(date), parse_discordian(&gregorian.to_poee()));
+        }
+    }
+});
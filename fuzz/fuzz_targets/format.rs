@@ -0,0 +1,35 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Compiles arbitrary format strings and renders them for an ordinary day,
+//! a holyday, St. Tib's Day, and the extremes of the YOLD.
+
+#![no_main]
+
+use ddate::{DiscordianDateComponents, Format, Season};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let format = Format::new(input);
+    let dates = [
+        DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16),
+        DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 50),
+        DiscordianDateComponents::st_tibs_day(3166),
+        DiscordianDateComponents::from_parts(i32::MIN, Season::Chaos, 1),
+        DiscordianDateComponents::from_parts(i32::MAX, Season::TheAftermath, 73),
+    ];
+
+    for date in dates.iter().flatten() {
+        let rendered = format.render(date);
+
+        if !input.contains('%') {
+            assert_eq!(input, rendered);
+        }
+    }
+});
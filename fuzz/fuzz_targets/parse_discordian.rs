@@ -0,0 +1,23 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Parses arbitrary Discordian date strings, checking that any date which
+//! parses comes back unchanged from its own rendering.
+
+#![no_main]
+
+use ddate::{parse_discordian, Format, DATE_FORMAT};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(date) = parse_discordian(input) {
+        let rendered = Format::new(DATE_FORMAT).render(&date);
+        assert_eq!(Ok(date), parse_discordian(&rendered), "{:?}", rendered);
+    }
+});
@@ -7,17 +7,2420 @@
 //
 // For more information, see the file UNLICENSE at this repository's root.
 
-use ddate::DiscordianDate;
-use chrono::Local;
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate};
+use ddate::{
+    csv, detect_date_with, holydays_in_gregorian_year, holydays_in_year, parse_discordian,
+    parse_gregorian, wrap, Calendar, DdateError, DetectedDate, DiscordianDate,
+    DiscordianDateComponents, Format, FortuneFile, Holyday, Locale, Names, POEE_SHORT_MAX_LEN,
+};
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::thread;
 
-fn main() {
-    if let Some(timestr) = std::env::args().nth(1) {
-        let (dt, _) = dtparse::parse(&timestr).unwrap_or_else(|_| {
-            println!("Could not parse provided date.");
-            std::process::exit(1);
+/// The version of ddate, that of the crate.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// What ddate is, printed after its version.
+const ABOUT: &str = "Prints dates in the Discordian calendar, like the ddate of util-linux.";
+
+/// Which single date to print when several are given.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Aggregate {
+    Min,
+    Max,
+}
+
+/// A zone given to `--zones`: an offset from UTC, or, with the `tz`
+/// feature, a named time zone, whose offset may change.
+#[derive(Debug)]
+enum Zone {
+    Fixed(FixedOffset),
+    #[cfg(feature = "tz")]
+    Named(ddate::Zone),
+}
+
+impl Zone {
+    /// Returns the date in the zone at a moment.
+    fn date_at(&self, moment: DateTime<FixedOffset>) -> NaiveDate {
+        match self {
+            Zone::Fixed(offset) => moment.with_timezone(offset).date_naive(),
+            #[cfg(feature = "tz")]
+            Zone::Named(zone) => moment
+                .with_timezone(&zone.offset_at(moment.to_utc()))
+                .date_naive(),
+        }
+    }
+}
+
+/// The options given on the command line.
+#[derive(Debug, Default)]
+struct Options {
+    reverse: bool,
+    relative: bool,
+    card: bool,
+    raw_ordinal: bool,
+    all_locales: bool,
+    warn_range: bool,
+    auto: bool,
+    batch: bool,
+    parallel: Option<usize>,
+    csv: bool,
+    input: Option<String>,
+    column: Option<String>,
+    append: Option<String>,
+    replace: bool,
+    compare_tools: bool,
+    test_vectors: bool,
+    version: bool,
+    zones: Option<Vec<(String, Zone)>>,
+    json: bool,
+    ics: Option<i32>,
+    iso: bool,
+    prefer: Option<Calendar>,
+    aggregate: Option<Aggregate>,
+    assert_holyday: Option<Option<Holyday>>,
+    interactive: bool,
+    format: Option<String>,
+    format_strict: bool,
+    fortune_file: Option<String>,
+    names_file: Option<String>,
+    fortune_weighted: bool,
+    lookahead: Option<u16>,
+    wrap: Option<usize>,
+    dates: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut args = args.iter();
+
+        if args.as_slice().first().map(String::as_str) == Some("csv") {
+            options.csv = true;
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--reverse" => options.reverse = true,
+                "--relative" => options.relative = true,
+                "--card" => options.card = true,
+                "--raw-ordinal" => options.raw_ordinal = true,
+                "--warn-range" => options.warn_range = true,
+                "--all-locales" => options.all_locales = true,
+                "--auto" => options.auto = true,
+                "--batch" => options.batch = true,
+                "--parallel" => options.parallel = Some(0),
+                flag if flag.starts_with("--parallel=") => {
+                    let threads = &flag["--parallel=".len()..];
+                    options.parallel = Some(parse_count(Some(threads), PARALLEL)?);
+                }
+                "--input" => match args.next() {
+                    Some(path) => options.input = Some(path.to_string()),
+                    None => return Err("Option --input requires a file.".to_string()),
+                },
+                flag if flag.starts_with("--input=") => {
+                    options.input = Some(flag["--input=".len()..].to_string());
+                }
+                "--column" => match args.next() {
+                    Some(column) => options.column = Some(column.to_string()),
+                    None => return Err("Option --column requires a name or number.".to_string()),
+                },
+                flag if flag.starts_with("--column=") => {
+                    options.column = Some(flag["--column=".len()..].to_string());
+                }
+                "--append" => match args.next() {
+                    Some(name) => options.append = Some(name.to_string()),
+                    None => return Err("Option --append requires a column name.".to_string()),
+                },
+                flag if flag.starts_with("--append=") => {
+                    options.append = Some(flag["--append=".len()..].to_string());
+                }
+                "--replace" => options.replace = true,
+                "--compare-tools" => options.compare_tools = true,
+                "--generate-test-vectors" => options.test_vectors = true,
+                "--version" => options.version = true,
+                "--zones" => options.zones = Some(parse_zones(args.next())?),
+                flag if flag.starts_with("--zones=") => {
+                    options.zones = Some(parse_zones(Some(&flag["--zones=".len()..]))?);
+                }
+                "--json" => options.json = true,
+                "--ics" => options.ics = Some(parse_count(args.next(), ICS)?),
+                "--iso" => options.iso = true,
+                "--prefer" => options.prefer = Some(parse_calendar(args.next())?),
+                flag if flag.starts_with("--prefer=") => {
+                    options.prefer = Some(parse_calendar(Some(&flag["--prefer=".len()..]))?);
+                }
+                "--interactive" => options.interactive = true,
+                "--format" => match args.next() {
+                    Some(format) => options.format = Some(format.to_string()),
+                    None => return Err("Option --format requires a format.".to_string()),
+                },
+                flag if flag.starts_with("--format=") => {
+                    options.format = Some(flag["--format=".len()..].to_string());
+                }
+                "--fortune-file" => match args.next() {
+                    Some(path) => options.fortune_file = Some(path.to_string()),
+                    None => return Err("Option --fortune-file requires a path.".to_string()),
+                },
+                flag if flag.starts_with("--fortune-file=") => {
+                    options.fortune_file = Some(flag["--fortune-file=".len()..].to_string());
+                }
+                "--names" => match args.next() {
+                    Some(path) => options.names_file = Some(path.to_string()),
+                    None => return Err("Option --names requires a path.".to_string()),
+                },
+                flag if flag.starts_with("--names=") => {
+                    options.names_file = Some(flag["--names=".len()..].to_string());
+                }
+                "--format-strict" => options.format_strict = true,
+                "--lookahead" => options.lookahead = Some(parse_count(args.next(), LOOKAHEAD)?),
+                flag if flag.starts_with("--lookahead=") => {
+                    let window = &flag["--lookahead=".len()..];
+                    options.lookahead = Some(parse_count(Some(window), LOOKAHEAD)?);
+                }
+                "--wrap" => options.wrap = Some(parse_count(args.next(), WRAP)?),
+                flag if flag.starts_with("--wrap=") => {
+                    let width = &flag["--wrap=".len()..];
+                    options.wrap = Some(parse_count(Some(width), WRAP)?);
+                }
+                "--fortune-weighted" => options.fortune_weighted = true,
+                "--min" => options.aggregate = Some(Aggregate::Min),
+                "--max" => options.aggregate = Some(Aggregate::Max),
+                "--assert-holyday" => options.assert_holyday = Some(None),
+                flag if flag.starts_with("--assert-holyday=") => {
+                    let name = &flag["--assert-holyday=".len()..];
+                    let holyday = name
+                        .parse()
+                        .map_err(|_| format!("Unrecognized holyday {}.", name))?;
+                    options.assert_holyday = Some(Some(holyday));
+                }
+                flag if flag.starts_with("--") => {
+                    return Err(format!("Unrecognized option {}.", flag))
+                }
+                date => options.dates.push(date.to_string()),
+            }
+        }
+
+        if options.parallel.is_some() && !options.batch {
+            return Err("Option --parallel requires --batch.".to_string());
+        }
+
+        let csv_options = options.input.is_some()
+            || options.column.is_some()
+            || options.append.is_some()
+            || options.replace;
+        if csv_options && !options.csv {
+            return Err(
+                "Options --input, --column, --append, and --replace require ddate csv.".to_string(),
+            );
+        }
+        if options.json && options.zones.is_none() {
+            return Err("Option --json requires --zones.".to_string());
+        }
+
+        if options.test_vectors && options.dates.len() != 2 {
+            return Err(
+                "Option --generate-test-vectors requires a start and end date.".to_string(),
+            );
+        }
+
+        if options.csv {
+            if options.column.is_none() {
+                return Err("ddate csv requires --column.".to_string());
+            }
+            if options.append.is_some() && options.replace {
+                return Err("Options --append and --replace cannot be given together.".to_string());
+            }
+            if let Some(date) = options.dates.first() {
+                return Err(format!("Unexpected argument {} to ddate csv.", date));
+            }
+        }
+
+        if let (Some(format), true) = (&options.format, options.format_strict) {
+            Format::strict(format).map_err(|e| format!("Invalid format: {}.", e))?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Parses the calendar given to `--prefer`.
+fn parse_calendar<S: AsRef<str>>(calendar: Option<S>) -> Result<Calendar, String> {
+    calendar
+        .and_then(|calendar| calendar.as_ref().parse().ok())
+        .ok_or_else(|| "Option --prefer requires gregorian or discordian.".to_string())
+}
+
+/// Parses the comma-separated zones given to `--zones`; see `parse_zone`.
+fn parse_zones<S: AsRef<str>>(zones: Option<S>) -> Result<Vec<(String, Zone)>, String> {
+    let zones = zones.ok_or_else(|| "Option --zones requires a list of zones.".to_string())?;
+
+    zones
+        .as_ref()
+        .split(',')
+        .map(str::trim)
+        .map(|zone| parse_zone(zone).map(|offset| (zone.to_string(), offset)))
+        .collect()
+}
+
+/// How to give a zone to `--zones`, following an unrecognized one.
+#[cfg(feature = "tz")]
+const ZONE_HINT: &str =
+    "give the name of a time zone, such as Pacific/Auckland, or an offset from UTC, such as +13:00";
+/// How to give a zone to `--zones`, following an unrecognized one.
+#[cfg(not(feature = "tz"))]
+const ZONE_HINT: &str = "ddate has no time zone data, so give an offset from UTC, such as +13:00";
+
+/// Parses a zone given to `--zones`: an offset from UTC, as `parse_offset`,
+/// or, with the `tz` feature, the name of a time zone, such as
+/// `Pacific/Auckland`.
+fn parse_zone(zone: &str) -> Result<Zone, String> {
+    let offset = parse_offset(zone);
+
+    #[cfg(feature = "tz")]
+    if offset.is_none() {
+        if let Ok(zone) = ddate::Zone::load(zone) {
+            return Ok(Zone::Named(zone));
+        }
+    }
+    offset
+        .map(Zone::Fixed)
+        .ok_or_else(|| format!("Unrecognized time zone {}: {}.", zone, ZONE_HINT))
+}
+
+/// Parses an offset from UTC given to `--zones`: `UTC`, or an offset such as
+/// `+13:00`, `-0800`, or `UTC+1`.
+fn parse_offset(zone: &str) -> Option<FixedOffset> {
+    let offset = ["UTC", "GMT"]
+        .iter()
+        .find_map(|prefix| zone.strip_prefix(prefix))
+        .unwrap_or(zone);
+    if zone.is_empty() {
+        return None;
+    }
+    if offset.is_empty() || offset == "Z" {
+        return FixedOffset::east_opt(0);
+    }
+
+    let (sign, offset) = match (offset.strip_prefix('+'), offset.strip_prefix('-')) {
+        (Some(offset), _) => (1, offset),
+        (_, Some(offset)) => (-1, offset),
+        _ => return None,
+    };
+    let (hours, minutes) = match offset.split_once(':') {
+        Some(parts) => parts,
+        None if offset.len() > 2 && offset.is_char_boundary(offset.len() - 2) => {
+            offset.split_at(offset.len() - 2)
+        }
+        None => (offset, "0"),
+    };
+
+    match (hours.parse::<i32>(), minutes.parse::<i32>()) {
+        (Ok(hours @ 0..=23), Ok(minutes @ 0..=59)) => {
+            FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+        }
+        _ => None,
+    }
+}
+
+/// The error for a missing or invalid number of days given to `--lookahead`.
+const LOOKAHEAD: &str = "Option --lookahead requires a number of days.";
+/// The error for a missing or invalid number of columns given to `--wrap`.
+const WRAP: &str = "Option --wrap requires a number of columns.";
+/// The error for a missing or invalid year given to `--ics`.
+const ICS: &str = "Option --ics requires a year.";
+/// The error for an invalid number of threads given to `--parallel`.
+const PARALLEL: &str = "Option --parallel requires a number of threads.";
+
+/// Parses the number given to an option, failing with `error`.
+fn parse_count<T: FromStr, S: AsRef<str>>(count: Option<S>, error: &str) -> Result<T, String> {
+    count
+        .and_then(|count| count.as_ref().parse().ok())
+        .ok_or_else(|| error.to_string())
+}
+
+/// Runs ddate with the given arguments, as though it were the moment `now`,
+/// returning the exit status. Interactive mode reads its commands from
+/// `input`, prompting for each if `input` is a terminal. Warnings which
+/// would corrupt the output, such as those of `ddate csv`, go to `err`.
+fn run(
+    args: &[String],
+    moment: DateTime<FixedOffset>,
+    input: &mut dyn BufRead,
+    terminal: bool,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> io::Result<i32> {
+    let now = moment.date_naive();
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(message) => {
+            writeln!(out, "{}", message)?;
+            return Ok(2);
+        }
+    };
+    if options.version {
+        writeln!(out, "ddate {}\n{}", VERSION, ABOUT)?;
+        return Ok(0);
+    }
+
+    let renderer = match Renderer::new(&options) {
+        Ok(renderer) => renderer,
+        Err(message) => {
+            writeln!(out, "{}", message)?;
+            return Ok(1);
+        }
+    };
+
+    if options.compare_tools {
+        return compare_tools(out);
+    }
+
+    if let Some(zones) = &options.zones {
+        return world_clock(zones, options.json, &renderer, moment, out);
+    }
+
+    if let Some(year) = options.ics {
+        return ics(year, moment, out);
+    }
+
+    if options.test_vectors {
+        let (start, end) = match (parse_date(&options.dates[0]), parse_date(&options.dates[1])) {
+            (Ok(start), Ok(end)) => (start, end),
+            (Err(message), _) | (_, Err(message)) => {
+                writeln!(out, "{}", message)?;
+                return Ok(1);
+            }
+        };
+        return test_vectors(start, end, out);
+    }
+
+    if options.csv {
+        return convert_csv(&options, &renderer, input, out, err);
+    }
+
+    if options.interactive {
+        return interactive(now, &renderer, input, terminal, out);
+    }
+
+    if options.batch {
+        return batch(&renderer, input, out, options.parallel);
+    }
+
+    if options.reverse {
+        let ddate = options.dates.join(" ");
+        return match parse_discordian(&ddate)
+            .ok()
+            .and_then(|ddate| ddate.to_naive_date())
+        {
+            Some(dt) => {
+                writeln!(out, "{}", dt)?;
+                Ok(0)
+            }
+            None => {
+                writeln!(out, "Could not parse provided Discordian date.")?;
+                Ok(1)
+            }
+        };
+    }
+
+    if options.auto {
+        return auto(&options, &renderer, out);
+    }
+
+    if options.dates.is_empty() {
+        if let Some(expected) = options.assert_holyday {
+            return Ok(holyday_status(&[now], expected));
+        }
+
+        if options.card {
+            write!(out, "{}", card(now))?;
+            return Ok(0);
+        }
+
+        if options.raw_ordinal {
+            writeln!(out, "{}", raw_ordinal(now))?;
+            return Ok(0);
+        }
+
+        if options.all_locales {
+            write!(out, "{}", all_locales(now))?;
+            return Ok(0);
+        }
+
+        if options.relative {
+            writeln!(out, "{}", renderer.relative(now, now)?)?;
+            return Ok(0);
+        }
+
+        writeln!(out, "{}", renderer.today(now)?)?;
+        for line in upcoming(now, options.lookahead.unwrap_or(0)) {
+            writeln!(out, "{}", line)?;
+        }
+        return Ok(0);
+    }
+
+    let mut dates = Vec::with_capacity(options.dates.len());
+    for timestr in &options.dates {
+        match parse_date(timestr) {
+            Ok(dt) => dates.push(dt),
+            Err(message) => {
+                writeln!(out, "{}", message)?;
+                return Ok(1);
+            }
+        }
+    }
+
+    if let Some(expected) = options.assert_holyday {
+        return Ok(holyday_status(&dates, expected));
+    }
+
+    let extreme = match options.aggregate {
+        Some(Aggregate::Min) => dates.iter().min_by_key(|dt| dt.discordian_components()),
+        Some(Aggregate::Max) => dates.iter().max_by_key(|dt| dt.discordian_components()),
+        None => None,
+    };
+    if let Some(&dt) = extreme {
+        dates = vec![dt];
+    }
+
+    for dt in dates {
+        if options.warn_range {
+            warn_range(dt, err)?;
+        }
+
+        if options.card {
+            write!(out, "{}", card(dt))?;
+        } else if options.raw_ordinal {
+            writeln!(out, "{}", raw_ordinal(dt))?;
+        } else if options.all_locales {
+            write!(out, "{}", all_locales(dt))?;
+        } else if options.relative {
+            writeln!(out, "{}", renderer.relative(now, dt)?)?;
+        } else {
+            writeln!(out, "{}", renderer.convert(dt)?)?;
+        }
+    }
+
+    Ok(0)
+}
+
+/// The YOLDs of plausible dates; `--warn-range` warns of dates outside of
+/// them, which are more often the result of misparsed data than meant.
+const PLAUSIBLE_YOLDS: RangeInclusive<i32> = 0..=10_000;
+
+/// Warns on `err`, for `--warn-range`, if a date is in an implausible YOLD.
+fn warn_range(dt: NaiveDate, err: &mut dyn Write) -> io::Result<()> {
+    let yold = dt.discordian_components().yold;
+
+    if !PLAUSIBLE_YOLDS.contains(&yold) {
+        writeln!(
+            err,
+            "Warning: {} is in YOLD {}, outside of YOLD {} to {}.",
+            dt,
+            yold,
+            PLAUSIBLE_YOLDS.start(),
+            PLAUSIBLE_YOLDS.end()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns the day of the YOLD of a date for `--raw-ordinal`, from 1 to 365,
+/// or 366 in a YOLD with St. Tib's Day, which is day 60. YOLDs begin with
+/// Gregorian years, so this is the Gregorian ordinal.
+fn raw_ordinal(dt: NaiveDate) -> u32 {
+    dt.ordinal()
+}
+
+/// Writes out a date in every locale for `--all-locales`, one per line after
+/// its language tag, with any holyday on the same line, so that locales can
+/// be compared side by side.
+fn all_locales(dt: NaiveDate) -> String {
+    Locale::ALL
+        .iter()
+        .map(|&locale| {
+            let poee = dt.to_poee_locale(locale).replace('\n', ". ");
+            format!("{}: {}\n", locale.tag(), poee)
+        })
+        .collect()
+}
+
+/// The width inside the frame of a `--card`: that of the longest short date,
+/// with a space on either side, so that every card is the same size.
+const CARD_WIDTH: usize = POEE_SHORT_MAX_LEN + 2;
+
+/// Renders a date as a card for `--card`, such as for a message of the day:
+/// the short date centered in a frame, with any holyday starred beneath it.
+fn card(dt: NaiveDate) -> String {
+    let mut lines = vec![dt.to_poee_short()];
+    if let Some(holyday) = dt.holyday() {
+        lines.push(format!("\u{2605} {} \u{2605}", holyday));
+    }
+
+    let rule = "\u{2500}".repeat(CARD_WIDTH);
+    let mut out = format!("\u{250c}{}\u{2510}\n", rule);
+    for line in lines {
+        let _ = writeln!(out, "\u{2502}{:^width$}\u{2502}", line, width = CARD_WIDTH);
+    }
+    let _ = writeln!(out, "\u{2514}{}\u{2518}", rule);
+
+    out
+}
+
+/// Parses a Gregorian date given on the command line, trying the formats of
+/// `parse_gregorian`, including ISO week and ordinal dates, before falling
+/// back to dtparse, which is more lenient but misreads some dates, such as
+/// 2000-02-29.
+fn parse_date(timestr: &str) -> Result<NaiveDate, String> {
+    match parse_gregorian(timestr) {
+        Ok(dt) => Ok(dt),
+        Err(e @ DdateError::ComponentOutOfRange { .. }) => {
+            Err(format!("Could not parse provided date: {}.", e))
+        }
+        Err(_) => {
+            parse_lenient(timestr).ok_or_else(|| "Could not parse provided date.".to_string())
+        }
+    }
+}
+
+/// Parses a date with dtparse, which understands many more formats than
+/// `parse_gregorian`, if not always correctly.
+#[cfg(feature = "cli-full")]
+fn parse_lenient(timestr: &str) -> Option<NaiveDate> {
+    dtparse::parse(timestr).ok().map(|(dt, _)| dt.date())
+}
+
+/// Parses nothing, as the minimal build has no dtparse to fall back to.
+#[cfg(not(feature = "cli-full"))]
+fn parse_lenient(_: &str) -> Option<NaiveDate> {
+    None
+}
+
+/// Converts a date given in either calendar into the other, or, with
+/// `--iso`, into both.
+///
+/// A bare number, such as `2017`, is no date in either calendar. It is not
+/// given to dtparse, which would fill in the month and day from the system
+/// clock rather than from `now`.
+fn auto(options: &Options, renderer: &Renderer, out: &mut dyn Write) -> io::Result<i32> {
+    let input = options.dates.join(" ");
+    let prefer = options.prefer.unwrap_or(Calendar::Gregorian);
+
+    let detected = detect_date_with(&input, prefer, |input| {
+        parse_gregorian(input)
+            .or_else(|e| {
+                if is_bare_number(input) {
+                    Err(e)
+                } else {
+                    parse_lenient(input).ok_or(e)
+                }
+            })
+            .map_err(|e| e.to_string())
+    });
+
+    let dt = match detected {
+        Ok(DetectedDate::Gregorian(dt)) if !options.iso => {
+            writeln!(out, "{}", renderer.discordian(dt)?)?;
+            return Ok(0);
+        }
+        Ok(DetectedDate::Gregorian(dt)) => dt,
+        Ok(DetectedDate::Discordian(ddate)) => match ddate.to_naive_date() {
+            Some(dt) if !options.iso => {
+                writeln!(out, "{}", dt)?;
+                return Ok(0);
+            }
+            Some(dt) => dt,
+            None => {
+                writeln!(out, "Could not convert provided Discordian date.")?;
+                return Ok(1);
+            }
+        },
+        Err(e) => {
+            writeln!(out, "Could not parse provided date: {}.", e)?;
+            return Ok(1);
+        }
+    };
+
+    writeln!(out, "{}", renderer.convert(dt)?)?;
+    Ok(0)
+}
+
+/// Whether input is only a number, optionally signed, such as a year alone.
+fn is_bare_number(input: &str) -> bool {
+    let digits = input.trim().trim_start_matches(['-', '+']);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Output expected of `util-linux`'s ddate, in the format of the fixtures of
+/// `tests/util_linux.rs`: a Gregorian date, the format given after `+`, and
+/// the output on each line, separated by tabs. These are written by hand
+/// after the examples of its manual page, not recorded from it.
+const EXPECTED: &str = include_str!("../../tests/fixtures/util-linux/expected.tsv");
+
+/// Reverses the escaping of newlines, tabs, and backslashes in a field of
+/// `EXPECTED`.
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+/// Returns each case of `EXPECTED`: the Gregorian date, the format, and the
+/// output expected.
+fn expected_cases() -> Vec<(NaiveDate, String, String)> {
+    EXPECTED
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let mut field = || fields.next().expect("expected cases have three fields");
+            let date =
+                NaiveDate::parse_from_str(field(), "%Y-%m-%d").expect("expected dates are valid");
+
+            (date, unescape(field()), unescape(field()))
+        })
+        .collect()
+}
+
+/// Describes each date of `EXPECTED` whose output differs from that
+/// expected.
+fn expected_mismatches() -> Vec<String> {
+    expected_cases()
+        .into_iter()
+        .filter_map(|(date, format, expected)| {
+            let actual = date.to_poee_format(&format);
+            if actual == expected {
+                None
+            } else {
+                Some(format!(
+                    "{} +{:?}\n  expected: {:?}\n    actual: {:?}",
+                    date, format, expected, actual
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Checks the output for each date of `EXPECTED` against that expected of
+/// `util-linux`, returning failure if any differ.
+fn compare_tools(out: &mut dyn Write) -> io::Result<i32> {
+    let total = expected_cases().len();
+    let mismatches = expected_mismatches();
+
+    for mismatch in &mismatches {
+        writeln!(out, "{}", mismatch)?;
+    }
+    writeln!(
+        out,
+        "{} of {} dates match the output expected of util-linux.",
+        total - mismatches.len(),
+        total
+    )?;
+
+    Ok(if mismatches.is_empty() { 0 } else { 1 })
+}
+
+/// Prints the Discordian date at `moment` in each of `zones`, one line to a
+/// zone, marking with `*` those where the date differs from that at `moment`
+/// in its own zone. With `--json`, prints an array of objects instead, each
+/// with the `zone`, its Gregorian `date`, the `discordian` date, and whether
+/// it `differs`.
+fn world_clock(
+    zones: &[(String, Zone)],
+    json: bool,
+    renderer: &Renderer,
+    moment: DateTime<FixedOffset>,
+    out: &mut dyn Write,
+) -> io::Result<i32> {
+    let mine = moment.date_naive();
+
+    if json {
+        writeln!(out, "[")?;
+    }
+    for (n, (name, zone)) in zones.iter().enumerate() {
+        let dt = zone.date_at(moment);
+        // A holyday is announced on the same line, keeping one to a zone.
+        let discordian = renderer.discordian(dt)?.replace('\n', "; ");
+
+        if json {
+            writeln!(
+                out,
+                "  {{\"zone\": {}, \"date\": \"{}\", \"discordian\": {}, \"differs\": {}}}{}",
+                json_string(name),
+                dt,
+                json_string(&discordian),
+                dt != mine,
+                if n + 1 < zones.len() { "," } else { "" }
+            )?;
+        } else {
+            let marker = if dt != mine { '*' } else { ' ' };
+            writeln!(out, "{} {}: {}", marker, name, discordian)?;
+        }
+    }
+    if json {
+        writeln!(out, "]")?;
+    }
+
+    Ok(0)
+}
+
+/// Quotes a string for JSON output.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The header of the CSV written by `--generate-test-vectors`.
+const TEST_VECTOR_HEADER: [&str; 7] = [
+    "gregorian",
+    "yold",
+    "season",
+    "day",
+    "weekday",
+    "holyday",
+    "st_tibs",
+];
+
+/// Writes test vectors for other implementations of the calendar, with the
+/// Discordian date of each day from `start` to `end`, inclusive, as CSV.
+///
+/// The format is stable. After the header, each record has these fields:
+///
+/// - `gregorian`, the date as `YYYY-MM-DD`, with a sign and more digits
+///   for years outside of 0 to 9999, as chrono writes them;
+/// - `yold`, the YOLD, a signed integer;
+/// - `season`, the name of the season, such as `The Aftermath`;
+/// - `day`, the day of the season, from 1 to 73;
+/// - `weekday`, the name of the weekday, such as `Prickle-Prickle`;
+/// - `holyday`, the name of the holyday, or empty if none;
+/// - `st_tibs`, `true` on St. Tib's Day, with `season`, `day`, `weekday`,
+///   and `holyday` empty, or `false` otherwise.
+///
+/// Days are written one at a time, so a range of any length can be given.
+fn test_vectors(start: NaiveDate, end: NaiveDate, out: &mut dyn Write) -> io::Result<i32> {
+    let mut out = BufWriter::with_capacity(BATCH_BUFFER, out);
+    csv::write_record(&mut out, &TEST_VECTOR_HEADER)?;
+
+    let days = std::iter::successors(Some(start), |dt| dt.succ_opt()).take_while(|dt| *dt <= end);
+    for dt in days {
+        let date = dt.discordian_components();
+        let record = match date.weekday {
+            Some(weekday) => [
+                dt.to_string(),
+                date.yold.to_string(),
+                date.season.name().to_string(),
+                date.day.to_string(),
+                weekday.name().to_string(),
+                date.holyday().map_or("", Holyday::name).to_string(),
+                "false".to_string(),
+            ],
+            None => [
+                dt.to_string(),
+                date.yold.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                "true".to_string(),
+            ],
+        };
+        csv::write_record(&mut out, &record)?;
+    }
+    out.flush()?;
+
+    Ok(0)
+}
+
+/// Writes the holydays of a Gregorian year as an iCalendar of all-day
+/// events, stamped with the moment `now`.
+fn ics(year: i32, now: DateTime<FixedOffset>, out: &mut dyn Write) -> io::Result<i32> {
+    let holydays = holydays_in_gregorian_year(year);
+    if holydays.len() < Holyday::ALL.len() {
+        writeln!(
+            out,
+            "Year {} is outside of the range ddate can convert.",
+            year
+        )?;
+        return Ok(1);
+    }
+
+    let stamp = now.naive_utc().format("%Y%m%dT%H%M%SZ");
+    let mut calendar = String::new();
+    // Lines are folded after 75 octets, as RFC 5545 requires, continuing
+    // on lines starting with a space.
+    let mut line = |text: &str| {
+        let mut width = 0;
+        for c in text.chars() {
+            if width + c.len_utf8() > 75 {
+                calendar.push_str("\r\n ");
+                width = 1;
+            }
+            calendar.push(c);
+            width += c.len_utf8();
+        }
+        calendar.push_str("\r\n");
+    };
+
+    line("BEGIN:VCALENDAR");
+    line("VERSION:2.0");
+    line("PRODID:-//ddate//Discordian holydays//EN");
+    line("CALSCALE:GREGORIAN");
+    for (date, holyday) in holydays {
+        let yold = date.discordian_components().yold;
+        let end = date.succ_opt().unwrap_or(date);
+        let poee = date.to_poee();
+        let description = poee.lines().next().unwrap_or_default();
+
+        line("BEGIN:VEVENT");
+        line(&format!(
+            "UID:{}-{}@ddate",
+            holyday.name().to_lowercase(),
+            yold
+        ));
+        line(&format!("DTSTAMP:{}", stamp));
+        line(&format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+        line(&format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        line(&format!("SUMMARY:{}", ics_text(holyday.name())));
+        line(&format!("DESCRIPTION:{}", ics_text(description)));
+        line("TRANSP:TRANSPARENT");
+        line("END:VEVENT");
+    }
+    line("END:VCALENDAR");
+
+    out.write_all(calendar.as_bytes())?;
+    Ok(0)
+}
+
+/// Escapes text for an iCalendar property value.
+fn ics_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Reads commands from `input` until it ends or is told to quit, writing
+/// the result of each to `out`.
+fn interactive(
+    now: NaiveDate,
+    renderer: &Renderer,
+    input: &mut dyn BufRead,
+    terminal: bool,
+    out: &mut dyn Write,
+) -> io::Result<i32> {
+    let mut line = String::new();
+
+    loop {
+        if terminal {
+            write!(out, "ddate> ")?;
+            out.flush()?;
+        }
+
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(0);
+        }
+
+        let command = line.trim();
+        let lowercase = command.to_lowercase();
+        let words: Vec<&str> = lowercase.split_whitespace().collect();
+
+        match words[..] {
+            [] => continue,
+            ["quit"] | ["exit"] => return Ok(0),
+            ["today"] => writeln!(out, "{}", renderer.today(now)?)?,
+            ["next", "holyday"] => match next_holyday(now.succ_opt(), None) {
+                Some((date, holyday)) => writeln!(
+                    out,
+                    "{} is in {}, on {}",
+                    holyday,
+                    days(date.signed_duration_since(now).num_days()),
+                    renderer.short(date)?
+                )?,
+                None => writeln!(out, "There are no more holydays.")?,
+            },
+            ["until", ..] => {
+                let name = command["until".len()..].trim();
+                match name.parse() {
+                    Ok(holyday) => match next_holyday(Some(now), Some(holyday)) {
+                        Some((date, _)) => writeln!(
+                            out,
+                            "{} until {}",
+                            days(date.signed_duration_since(now).num_days()),
+                            holyday
+                        )?,
+                        None => writeln!(out, "There are no more holydays.")?,
+                    },
+                    Err(_) => writeln!(out, "Unrecognized holyday {}.", name)?,
+                }
+            }
+            _ => match parse_date(command) {
+                Ok(dt) => writeln!(out, "{}", renderer.convert(dt)?)?,
+                Err(message) => {
+                    match parse_discordian(command).map(|ddate| ddate.to_naive_date()) {
+                        Ok(Some(dt)) => writeln!(out, "{}", dt)?,
+                        _ => writeln!(out, "{}", message)?,
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// The size of the buffer batch output is written through.
+const BATCH_BUFFER: usize = 64 * 1024;
+/// The number of lines read at once for the workers of `--parallel`.
+const BATCH_CHUNK: usize = 16 * 1024;
+
+/// Converts each line of `input`, a Gregorian date, writing a line to `out`
+/// for each, in order. With `--parallel`, lines are converted by that many
+/// worker threads, or by one for each CPU if no number is given.
+///
+/// Blank lines are written out blank, and lines which are not dates are
+/// reported in place, making the exit status 1.
+fn batch(
+    renderer: &Renderer,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+    parallel: Option<usize>,
+) -> io::Result<i32> {
+    let mut out = BufWriter::with_capacity(BATCH_BUFFER, out);
+    let status = match parallel {
+        Some(threads) => batch_parallel(renderer, input, &mut out, threads)?,
+        None => batch_serial(renderer, input, &mut out)?,
+    };
+    out.flush()?;
+
+    Ok(status)
+}
+
+/// Converts each line of `input` in turn on this thread, for `batch`,
+/// returning 1 if any line was not a date, and 0 otherwise.
+fn batch_serial(
+    renderer: &Renderer,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<i32> {
+    let mut status = 0;
+    let mut line = String::new();
+    let mut buffer = String::new();
+
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(status);
+        }
+
+        buffer.clear();
+        if !batch_line(renderer, &line, &mut buffer)? {
+            status = 1;
+        }
+        out.write_all(buffer.as_bytes())?;
+    }
+}
+
+/// Converts the lines of `input` on `threads` worker threads, or one for
+/// each CPU if `threads` is 0, for `--parallel`, returning 1 if any line was
+/// not a date, and 0 otherwise.
+///
+/// Lines are read in chunks of up to `BATCH_CHUNK`, and each chunk is split
+/// into as many contiguous runs as there are workers. Each worker converts
+/// its run into a buffer of its own, and the buffers are written out in
+/// turn once all of the chunk is done, so output is in the order of input
+/// just as with `batch_serial`, a chunk at a time.
+fn batch_parallel(
+    renderer: &Renderer,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+    threads: usize,
+) -> io::Result<i32> {
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    };
+    let mut status = 0;
+    let mut lines = vec![String::new(); BATCH_CHUNK];
+    let mut buffers = vec![String::new(); threads];
+
+    loop {
+        let mut count = 0;
+        while count < BATCH_CHUNK {
+            lines[count].clear();
+            if input.read_line(&mut lines[count])? == 0 {
+                break;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Ok(status);
+        }
+
+        // Each worker converts a contiguous run of lines into its own
+        // buffer, so writing the buffers in turn preserves input order.
+        let results: Vec<io::Result<bool>> = thread::scope(|scope| {
+            let workers: Vec<_> = lines[..count]
+                .chunks(count.div_ceil(threads))
+                .zip(buffers.iter_mut())
+                .map(|(lines, buffer)| {
+                    scope.spawn(move || {
+                        buffer.clear();
+                        let mut ok = true;
+                        for line in lines {
+                            ok &= batch_line(renderer, line, buffer)?;
+                        }
+                        Ok(ok)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("batch worker panicked"))
+                .collect()
         });
-        println!("{} is {}", &dt.date(), &dt.to_poee());
+
+        for (result, buffer) in results.into_iter().zip(&buffers) {
+            if !result? {
+                status = 1;
+            }
+            out.write_all(buffer.as_bytes())?;
+        }
+
+        if count < BATCH_CHUNK {
+            return Ok(status);
+        }
+    }
+}
+
+/// Converts one line of batch input, appending the line of output to
+/// `buffer`, and returning whether the line was a date or blank.
+fn batch_line(renderer: &Renderer, line: &str, buffer: &mut String) -> io::Result<bool> {
+    let line = line.trim();
+    let ok = if line.is_empty() {
+        true
+    } else if let Some(dt) = parse_plain_date(line) {
+        renderer.convert_into(dt, buffer)?;
+        true
     } else {
-        println!("Today is {}", Local::today().to_poee());
+        buffer.push_str("Could not parse provided date.");
+        false
+    };
+    buffer.push('\n');
+
+    Ok(ok)
+}
+
+/// Parses a date read from batch input or a CSV cell, in `YYYY-MM-DD` or
+/// another format of `parse_gregorian`, without the slower and more lenient
+/// fallback to dtparse.
+fn parse_plain_date(text: &str) -> Option<NaiveDate> {
+    text.parse::<NaiveDate>()
+        .ok()
+        .or_else(|| parse_gregorian(text).ok())
+}
+
+/// The name of the column `ddate csv` appends, unless another is given.
+const CSV_COLUMN: &str = "discordian";
+
+/// Converts a column of dates in CSV read from `--input`, or from `input` if
+/// no file is given, writing the CSV to `out` with the Discordian dates in a
+/// column appended to each record, or in place of the Gregorian dates with
+/// `--replace`. Records are converted one at a time as they are read.
+///
+/// The first record is the header, in which `--column` is looked up by name,
+/// or else by number, counting from 1. Cells which are not dates are left
+/// empty, and the number of them is reported to `err` once the CSV is done.
+/// Records shorter than the header are filled out with empty cells, and in
+/// those longer than it, the appended column is written in line with its
+/// name, before the extra cells.
+fn convert_csv(
+    options: &Options,
+    renderer: &Renderer,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> io::Result<i32> {
+    let mut file;
+    let input: &mut dyn BufRead = match &options.input {
+        Some(path) => match fs::File::open(path) {
+            Ok(opened) => {
+                file = BufReader::new(opened);
+                &mut file
+            }
+            Err(e) => {
+                writeln!(err, "Could not read input file {}: {}.", path, e)?;
+                return Ok(1);
+            }
+        },
+        None => input,
+    };
+    let mut reader = csv::Reader::new(input);
+    let mut out = BufWriter::with_capacity(BATCH_BUFFER, out);
+
+    let mut record = Vec::new();
+    if !reader.read_record(&mut record)? {
+        return Ok(0);
+    }
+
+    let column = options.column.as_deref().unwrap_or_default();
+    let index = record.iter().position(|name| name == column).or_else(|| {
+        column
+            .parse::<usize>()
+            .ok()
+            .filter(|&n| n >= 1 && n <= record.len())
+            .map(|n| n - 1)
+    });
+    let index = match index {
+        Some(index) => index,
+        None => {
+            writeln!(err, "Column {} is not in the header.", column)?;
+            return Ok(1);
+        }
     };
+    let width = record.len();
+
+    if !options.replace {
+        record.push(options.append.as_deref().unwrap_or(CSV_COLUMN).to_string());
+    }
+    csv::write_record(&mut out, &record)?;
+
+    let mut unparsed = 0;
+    while reader.read_record(&mut record)? {
+        // Short records are filled out to the header, and the appended
+        // column goes just after the header's last, before any extra cells
+        // of long records, keeping it in line with its name.
+        if record.len() < width {
+            record.resize(width, String::new());
+        }
+
+        let cell = record[index].trim();
+        let converted = if cell.is_empty() {
+            String::new()
+        } else if let Some(dt) = parse_plain_date(cell) {
+            renderer.discordian(dt)?
+        } else {
+            unparsed += 1;
+            String::new()
+        };
+
+        if options.replace {
+            record[index] = converted;
+        } else {
+            record.insert(width, converted);
+        }
+        csv::write_record(&mut out, &record)?;
+    }
+    out.flush()?;
+
+    match unparsed {
+        0 => {}
+        1 => writeln!(err, "Warning: 1 cell could not be parsed as a date.")?,
+        n => writeln!(err, "Warning: {} cells could not be parsed as dates.", n)?,
+    }
+
+    Ok(0)
+}
+
+/// Renders dates as chosen on the command line.
+struct Renderer {
+    format: Option<Format>,
+    fortunes: Option<FortuneFile>,
+    fortune_weighted: bool,
+    names: Names,
+    wrap: Option<usize>,
+}
+
+impl Renderer {
+    fn new(options: &Options) -> Result<Renderer, String> {
+        let fortunes = match &options.fortune_file {
+            Some(path) => Some(
+                FortuneFile::open(path)
+                    .map_err(|e| format!("Could not read fortune file {}: {}.", path, e))?,
+            ),
+            None => None,
+        };
+        let names = match &options.names_file {
+            Some(path) => fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| Names::parse(&text).map_err(|e| e.to_string()))
+                .map_err(|e| format!("Could not read names file {}: {}.", path, e))?,
+            None => Names::default(),
+        };
+
+        Ok(Renderer {
+            format: options.format.as_deref().map(Format::new),
+            fortunes,
+            fortune_weighted: options.fortune_weighted,
+            names,
+            wrap: options.wrap,
+        })
+    }
+
+    /// Renders a date in the chosen format, if any, drawing any exclamation
+    /// from the fortune file, if any.
+    fn format(&self, dt: NaiveDate) -> io::Result<Option<String>> {
+        let format = match &self.format {
+            Some(format) => format,
+            None => return Ok(None),
+        };
+        let date = dt.discordian_components();
+        let exclamation = self.exclamation(format, &date)?;
+
+        Ok(Some(format.render_with_names(
+            &date,
+            &exclamation,
+            &self.names,
+        )))
+    }
+
+    /// Chooses the exclamation for a date, from the fortune file if the
+    /// format has any use for one.
+    fn exclamation(
+        &self,
+        format: &Format,
+        date: &DiscordianDateComponents,
+    ) -> io::Result<Cow<'static, str>> {
+        match &self.fortunes {
+            Some(fortunes) if format.uses_exclamation() => fortunes
+                .choose(date, self.fortune_weighted)
+                .map(Cow::Owned)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            _ => Ok(Cow::Borrowed(Format::exclamation(date))),
+        }
+    }
+
+    /// Renders today's date.
+    fn today(&self, now: NaiveDate) -> io::Result<String> {
+        Ok(self.wrap(
+            self.format(now)?
+                .unwrap_or_else(|| format!("Today is {}", self.poee(now))),
+        ))
+    }
+
+    /// Renders a converted date.
+    fn convert(&self, dt: NaiveDate) -> io::Result<String> {
+        let mut out = String::new();
+        self.convert_into(dt, &mut out)?;
+        Ok(out)
+    }
+
+    /// Renders a converted date as `convert` does, appending to `out`.
+    fn convert_into(&self, dt: NaiveDate, out: &mut String) -> io::Result<()> {
+        let start = out.len();
+        let date = dt.discordian_components();
+
+        match &self.format {
+            Some(format) => {
+                let exclamation = self.exclamation(format, &date)?;
+                format.render_into(&date, &exclamation, &self.names, out);
+            }
+            None => {
+                let _ = write!(out, "{} is ", dt);
+                let _ = self.names.write_poee(&date, out);
+            }
+        }
+
+        if let Some(width) = self.wrap {
+            let wrapped = wrap(&out[start..], width);
+            out.truncate(start);
+            out.push_str(&wrapped);
+        }
+
+        Ok(())
+    }
+
+    /// Renders a Discordian date with how far it is from `now`.
+    fn relative(&self, now: NaiveDate, dt: NaiveDate) -> io::Result<String> {
+        let rendered = self.format(dt)?.unwrap_or_else(|| self.poee(dt));
+        let when = match dt.signed_duration_since(now).num_days() {
+            0 => "today".to_string(),
+            n if n > 0 => format!("{} from now", days(n)),
+            n => format!("{} ago", days(-n)),
+        };
+
+        // Any holyday is announced on a line after the date and its distance.
+        Ok(self.wrap(match rendered.split_once('\n') {
+            Some((date, rest)) => format!("That's {} \u{2014} {}\n{}", date, when, rest),
+            None => format!("That's {} \u{2014} {}", rendered, when),
+        }))
+    }
+
+    /// Renders a Discordian date alone.
+    fn discordian(&self, dt: NaiveDate) -> io::Result<String> {
+        Ok(self.wrap(self.format(dt)?.unwrap_or_else(|| self.poee(dt))))
+    }
+
+    /// Renders a date in the style of `to_poee`, with the chosen names.
+    fn poee(&self, dt: NaiveDate) -> String {
+        self.names.to_poee(&dt.discordian_components())
+    }
+
+    /// Wraps rendered output to the chosen width, if any.
+    fn wrap(&self, text: String) -> String {
+        match self.wrap {
+            Some(width) => wrap(&text, width),
+            None => text,
+        }
+    }
+
+    /// Renders a Discordian date compactly.
+    fn short(&self, dt: NaiveDate) -> io::Result<String> {
+        Ok(self.format(dt)?.unwrap_or_else(|| dt.to_poee_short()))
+    }
+}
+
+/// Returns the first holyday on or after `from`, or the first occurrence of
+/// the given holyday.
+fn next_holyday(from: Option<NaiveDate>, holyday: Option<Holyday>) -> Option<(NaiveDate, Holyday)> {
+    let from = from?;
+    let yold = from.discordian_components().yold;
+
+    holydays_in_year(yold)
+        .into_iter()
+        .chain(holydays_in_year(yold + 1))
+        .find(|&(date, h)| date >= from && holyday.is_none_or(|holyday| holyday == h))
+}
+
+/// Announces each holyday and St. Tib's Day within `window` days after
+/// `now`, soonest first.
+fn upcoming(now: NaiveDate, window: u16) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut date = now;
+
+    for n in 1..=i64::from(window) {
+        date = match date.succ_opt() {
+            Some(date) => date,
+            None => break,
+        };
+
+        let ddate = date.discordian_components();
+        let name = match ddate.holyday() {
+            Some(holyday) => holyday.name(),
+            None if ddate.st_tibs => "St. Tib's Day",
+            None => continue,
+        };
+        let when = if n == 1 {
+            "tomorrow".to_string()
+        } else {
+            format!("in {}", days(n))
+        };
+
+        lines.push(match ddate.weekday {
+            Some(weekday) => format!(
+                "{} is {} ({}, {} {})",
+                name, when, weekday, ddate.season, ddate.day
+            ),
+            None => format!("{} is {}", name, when),
+        });
+    }
+
+    lines
+}
+
+/// Renders a number of days.
+fn days(n: i64) -> String {
+    if n == 1 {
+        "1 day".to_string()
+    } else {
+        format!("{} days", n)
+    }
+}
+
+/// Returns a successful exit status if every date is a holyday, or the
+/// expected holyday if one is given.
+fn holyday_status(dates: &[NaiveDate], expected: Option<Holyday>) -> i32 {
+    let mut holydays = dates.iter().map(|dt| dt.holyday());
+
+    let all = match expected {
+        Some(expected) => holydays.all(|holyday| holyday == Some(expected)),
+        None => holydays.all(|holyday| holyday.is_some()),
+    };
+
+    if all {
+        0
+    } else {
+        1
+    }
+}
+
+/// Returns the current moment, or that given in `DDATE_NOW` to run as
+/// though it were now, either in RFC 3339, as `2017-11-04T23:30:00+01:00`,
+/// or as a date, `YYYY-MM-DD`, taken as its midnight in UTC.
+fn now_or_override() -> Result<DateTime<FixedOffset>, String> {
+    match std::env::var("DDATE_NOW") {
+        Ok(now) => DateTime::parse_from_rfc3339(&now)
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(&now, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|dt| dt.and_hms_opt(0, 0, 0))
+                    .map(|dt| dt.and_utc().fixed_offset())
+            })
+            .ok_or_else(|| format!("Could not parse DDATE_NOW {:?}.", now)),
+        Err(_) => Ok(Local::now().fixed_offset()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let now = now_or_override().unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        std::process::exit(2);
+    });
+    let stdin = io::stdin();
+    let terminal = stdin.is_terminal();
+
+    let status = run(
+        &args,
+        now,
+        &mut stdin.lock(),
+        terminal,
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock(),
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        1
+    });
+    std::process::exit(status);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expected_mismatches, run, upcoming};
+    use chrono::{DateTime, FixedOffset, NaiveDate};
+    use std::fs;
+    use std::io;
+
+    /// The moment the tests run at, the start of 2017-11-04 in UTC.
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2017-11-04T00:00:00Z").unwrap()
+    }
+
+    fn run_with(args: &[&str]) -> (i32, String) {
+        run_with_input(args, "")
+    }
+
+    fn run_with_input(args: &[&str], input: &str) -> (i32, String) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let now = now();
+        let mut out = Vec::new();
+
+        let status = run(
+            &args,
+            now,
+            &mut input.as_bytes(),
+            false,
+            &mut out,
+            &mut io::sink(),
+        )
+        .unwrap();
+        (status, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn today_test() {
+        assert_eq!(
+            (
+                0,
+                "Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n".to_string()
+            ),
+            run_with(&[])
+        );
+    }
+
+    #[test]
+    fn dates_test() {
+        let (status, out) = run_with(&["2017-11-04", "2016-02-29"]);
+
+        assert_eq!(0, status);
+        assert_eq!(
+            "2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n\
+             2016-02-29 is St. Tib's Day, in the YOLD 3182\n",
+            out
+        );
+    }
+
+    #[test]
+    fn aggregate_test() {
+        let dates = ["2017-11-04", "2016-02-29", "2018-01-01", "1999-12-31"];
+
+        let (_, out) = run_with(&[&["--max"], &dates[..]].concat());
+        assert_eq!(
+            "2018-01-01 is Sweetmorn, the 1st day of Chaos in the YOLD 3184\n",
+            out
+        );
+
+        let (_, out) = run_with(&[&["--min"], &dates[..]].concat());
+        assert_eq!(
+            "1999-12-31 is Setting Orange, the 73rd day of The Aftermath in the YOLD 3165\n",
+            out
+        );
+    }
+
+    #[test]
+    fn reverse_test() {
+        assert_eq!(
+            (0, "2000-02-29\n".to_string()),
+            run_with(&["--reverse", "St. Tib's Day in the YOLD 3166"])
+        );
+        assert_eq!(1, run_with(&["--reverse", "Fnord"]).0);
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert_eq!(
+            (1, "Could not parse provided date.\n".to_string()),
+            run_with(&["not a date"])
+        );
+        assert_eq!(2, run_with(&["--fnord"]).0);
+    }
+
+    #[test]
+    fn assert_holyday_test() {
+        assert_eq!(
+            (0, String::new()),
+            run_with(&["--assert-holyday", "2017-09-26"])
+        );
+        assert_eq!(
+            (1, String::new()),
+            run_with(&["--assert-holyday", "2017-11-04"])
+        );
+        assert_eq!(
+            (0, String::new()),
+            run_with(&["--assert-holyday=Bureflux", "2017-09-26"])
+        );
+        assert_eq!(
+            (1, String::new()),
+            run_with(&["--assert-holyday=bureflux", "2017-10-24"])
+        );
+        assert_eq!(1, run_with(&["--assert-holyday"]).0);
+        assert_eq!(2, run_with(&["--assert-holyday=Fnordflux"]).0);
+    }
+
+    #[test]
+    fn interactive_test() {
+        let input = "today\n\
+                     next holyday\n\
+                     until mungday\n\
+                     until Fnordflux\n\
+                     \n\
+                     2016-02-29\n\
+                     St. Tib's Day in the YOLD 3166\n\
+                     not a date\n\
+                     quit\n\
+                     today\n";
+
+        assert_eq!(
+            (
+                0,
+                "Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n\
+                 Afflux is in 34 days, on Boomtime, The Aftermath 50, 3183 YOLD\n\
+                 62 days until Mungday\n\
+                 Unrecognized holyday Fnordflux.\n\
+                 2016-02-29 is St. Tib's Day, in the YOLD 3182\n\
+                 2000-02-29\n\
+                 Could not parse provided date.\n"
+                    .to_string()
+            ),
+            run_with_input(&["--interactive"], input)
+        );
+    }
+
+    #[test]
+    fn interactive_prompt_test() {
+        let args = vec!["--interactive".to_string()];
+        let now = now();
+        let mut out = Vec::new();
+
+        let status = run(
+            &args,
+            now,
+            &mut &b"today\nquit\n"[..],
+            true,
+            &mut out,
+            &mut io::sink(),
+        )
+        .unwrap();
+        assert_eq!(0, status);
+        assert_eq!(
+            "ddate> Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\nddate> ",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn interactive_format_test() {
+        assert_eq!(
+            (
+                0,
+                "PD Afm 16\n\
+                 BT Afm 50\n\
+                 Afflux is in 34 days, on BT Afm 50\n"
+                    .to_string()
+            ),
+            run_with_input(
+                &["--interactive", "--format", "%a %b %d"],
+                "today\n2017-12-08\nnext holyday\n"
+            )
+        );
+        assert_eq!(2, run_with(&["--interactive", "--format"]).0);
+    }
+
+    #[test]
+    fn format_test() {
+        assert_eq!(
+            (0, "PD, Afm 16\n".to_string()),
+            run_with(&["--format=%a, %b %d"])
+        );
+        assert_eq!(
+            (0, "St. Tib's Day, 3182 YOLD\n".to_string()),
+            run_with(&["--format", "%{%A, %B %d%}, %Y YOLD", "2016-02-29"])
+        );
+    }
+
+    #[test]
+    fn format_strict_test() {
+        assert_eq!(
+            (0, "%Q Afm\n".to_string()),
+            run_with(&["--format", "%Q %b"])
+        );
+        assert_eq!(
+            (2, "Invalid format: unknown format code %Q.\n".to_string()),
+            run_with(&["--format", "%Q %b", "--format-strict"])
+        );
+        assert_eq!(
+            (0, "PD Afm\n".to_string()),
+            run_with(&["--format-strict", "--format", "%a %b"])
+        );
+    }
+
+    #[test]
+    fn lookahead_test() {
+        let today = "Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n";
+
+        assert_eq!((0, today.to_string()), run_with(&["--lookahead", "33"]));
+        assert_eq!(
+            (
+                0,
+                format!(
+                    "{}Afflux is in 34 days (Boomtime, The Aftermath 50)\n",
+                    today
+                )
+            ),
+            run_with(&["--lookahead=34"])
+        );
+        assert_eq!(2, run_with(&["--lookahead", "soon"]).0);
+        assert_eq!(2, run_with(&["--lookahead"]).0);
+    }
+
+    #[test]
+    fn auto_test() {
+        assert_eq!(
+            (
+                0,
+                "Pungenday, the 16th day of The Aftermath in the YOLD 3183\n".to_string()
+            ),
+            run_with(&["--auto", "Nov 4, 2017"])
+        );
+        assert_eq!(
+            (0, "2017-11-04\n".to_string()),
+            run_with(&["--auto", "Pungenday, The Aftermath 16, 3183 YOLD"])
+        );
+        assert_eq!(
+            (0, "2016-02-29\n".to_string()),
+            run_with(&["--auto", "St.", "Tib's", "Day", "3182"])
+        );
+        assert_eq!(
+            (
+                0,
+                "2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"
+                    .to_string()
+            ),
+            run_with(&["--auto", "--iso", "The Aftermath 16, 3183"])
+        );
+        assert_eq!(
+            (
+                0,
+                "2000-02-29 is St. Tib's Day, in the YOLD 3166\n".to_string()
+            ),
+            run_with(&["--auto", "--iso", "--prefer=discordian", "2000-02-29"])
+        );
+
+        // A bare year is neither a Gregorian nor a Discordian date, whatever
+        // the day of the clock.
+        let (status, output) = run_with(&["--auto", "2017"]);
+        assert_eq!(1, status);
+        assert_eq!(
+            "Could not parse provided date: \
+             not a Gregorian date (could not parse Gregorian date: \"2017\" is in no known format), \
+             nor a Discordian date (could not parse Discordian date: no season given).\n",
+            output
+        );
+        assert_eq!(1, run_with(&["--auto", "3183"]).0);
+
+        let (status, output) = run_with(&["--auto", "Fnord 3183"]);
+        assert_eq!(1, status);
+        assert_eq!(
+            "Could not parse provided date: \
+             not a Gregorian date (could not parse Gregorian date: \"Fnord 3183\" is in no known format), \
+             nor a Discordian date (could not parse Discordian date: unexpected word \"fnord\").\n",
+            output
+        );
+
+        assert_eq!(
+            2,
+            run_with(&["--auto", "--prefer", "julian", "2017-11-04"]).0
+        );
+        assert_eq!(2, run_with(&["--auto", "--prefer"]).0);
+    }
+
+    #[test]
+    fn compare_tools_test() {
+        assert_eq!(Vec::<String>::new(), expected_mismatches());
+        assert_eq!(
+            (
+                0,
+                "11 of 11 dates match the output expected of util-linux.\n".to_string()
+            ),
+            run_with(&["--compare-tools"])
+        );
+    }
+
+    #[test]
+    fn names_test() {
+        let path = std::env::temp_dir().join(format!("ddate-names-{}.names", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        fs::write(path, "weekdays = A, B, C, D, E\nst_tibs = Tibs").unwrap();
+        assert_eq!(
+            (
+                0,
+                "Today is C, the 16th day of The Aftermath in the YOLD 3183\n".to_string()
+            ),
+            run_with(&["--names", path])
+        );
+        assert_eq!(
+            (0, "2016-02-29 is Tibs, in the YOLD 3182\n".to_string()),
+            run_with(&["--names", path, "2016-02-29"])
+        );
+
+        fs::write(path, "weekdays = A, B, C").unwrap();
+        assert_eq!(
+            (
+                1,
+                format!(
+                    "Could not read names file {}: line 1 gives 3 names for weekdays, not 5.\n",
+                    path
+                )
+            ),
+            run_with(&["--names", path])
+        );
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(2, run_with(&["--names"]).0);
+    }
+
+    #[test]
+    fn wrap_test() {
+        assert_eq!(
+            (
+                0,
+                "Today is Pungenday, the 16th day of The\nAftermath in the YOLD 3183\n".to_string()
+            ),
+            run_with(&["--wrap", "40"])
+        );
+        assert_eq!(
+            (
+                0,
+                "2017-09-26 is\nPrickle-Prickle, the\n50th day of\nBureaucracy in the\nYOLD 3183\nCelebrate Bureflux\n"
+                    .to_string()
+            ),
+            run_with(&["--wrap=20", "2017-09-26"])
+        );
+        assert_eq!(
+            (
+                2,
+                "Option --wrap requires a number of columns.\n".to_string()
+            ),
+            run_with(&["--wrap", "-1"])
+        );
+        assert_eq!(2, run_with(&["--wrap"]).0);
+    }
+
+    #[test]
+    fn iso_input_test() {
+        assert_eq!(
+            (
+                0,
+                "2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n\
+                 2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n\
+                 2000-02-29 is St. Tib's Day, in the YOLD 3166\n"
+                    .to_string()
+            ),
+            run_with(&["2017-W44-6", "2017-308", "2000-02-29"])
+        );
+        assert_eq!(
+            (
+                1,
+                "Could not parse provided date: week 54 is outside of 1 to 52.\n".to_string()
+            ),
+            run_with(&["2017-W54-1"])
+        );
+        assert_eq!(
+            (
+                1,
+                "Could not parse provided date: ordinal day 367 is outside of 1 to 365.\n"
+                    .to_string()
+            ),
+            run_with(&["2017-367"])
+        );
+        assert_eq!(
+            (0, "Bcy 50\n".to_string()),
+            run_with_input(&["--interactive", "--format=%b %d"], "2017-W39-2\n")
+        );
+    }
+
+    #[test]
+    fn relative_test() {
+        assert_eq!(
+            (
+                0,
+                "That's Prickle-Prickle, the 67th day of The Aftermath in the YOLD 3183 \u{2014} 51 days from now\n"
+                    .to_string()
+            ),
+            run_with(&["--relative", "2017-12-25"])
+        );
+        assert_eq!(
+            (
+                0,
+                "That's Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183 \u{2014} 39 days ago\n\
+                 Celebrate Bureflux\n"
+                    .to_string()
+            ),
+            run_with(&["--relative", "2017-09-26"])
+        );
+        assert_eq!(
+            (
+                0,
+                "That's Prickle-Prickle, Afm 17 \u{2014} 1 day from now\n".to_string()
+            ),
+            run_with(&["--relative", "--format=%A, %b %d", "2017-11-05"])
+        );
+        assert_eq!(
+            (
+                0,
+                "That's Pungenday, the 16th day of The Aftermath in the YOLD 3183 \u{2014} today\n"
+                    .to_string()
+            ),
+            run_with(&["--relative"])
+        );
+    }
+
+    #[test]
+    fn batch_test() {
+        assert_eq!(
+            (
+                1,
+                "2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n\
+                 \n\
+                 Could not parse provided date.\n\
+                 2016-02-29 is St. Tib's Day, in the YOLD 3182\n"
+                    .to_string()
+            ),
+            run_with_input(&["--batch"], "2017-11-04\n\nfnord\nFeb 29, 2016")
+        );
+        assert_eq!(
+            (0, "Afm 16\nBcy 50\n".to_string()),
+            run_with_input(&["--batch", "--format=%b %d"], " 2017-11-04 \n2017-09-26\n")
+        );
+        assert_eq!(
+            (2, "Option --parallel requires --batch.\n".to_string()),
+            run_with(&["--parallel"])
+        );
+        assert_eq!(2, run_with(&["--batch", "--parallel=many"]).0);
+    }
+
+    /// Lines of batch input spanning several years, with some which are
+    /// blank or not dates.
+    fn batch_input(lines: usize) -> String {
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let mut input = String::new();
+
+        for (n, dt) in start.iter_days().take(lines).enumerate() {
+            match n % 1000 {
+                999 => input.push_str("fnord\n"),
+                998 => input.push('\n'),
+                _ => input.push_str(&format!("{}\n", dt)),
+            }
+        }
+
+        input
+    }
+
+    #[test]
+    fn batch_parallel_test() {
+        let input = batch_input(40_000);
+        let serial = run_with_input(&["--batch"], &input);
+
+        assert_eq!(1, serial.0);
+        assert_eq!(40, serial.1.matches("Could not parse").count());
+        for threads in &["--parallel", "--parallel=1", "--parallel=3"] {
+            assert_eq!(serial, run_with_input(&["--batch", threads], &input));
+        }
+        assert_eq!(
+            run_with_input(&["--batch", "--format=%a %b %d %Y %.", "--wrap=10"], &input),
+            run_with_input(
+                &[
+                    "--batch",
+                    "--parallel=5",
+                    "--format=%a %b %d %Y %.",
+                    "--wrap=10"
+                ],
+                &input
+            )
+        );
+    }
+
+    /// Times a million lines through batch mode, serially and in parallel;
+    /// run with `cargo test --release --features bin -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn batch_benchmark() {
+        let input = batch_input(1_000_000);
+        let mut outputs = Vec::new();
+
+        for args in &[&["--batch"][..], &["--batch", "--parallel"][..]] {
+            let started = std::time::Instant::now();
+            let output = run_with_input(args, &input);
+            let elapsed = started.elapsed();
+            println!(
+                "{}: {:?}, {:.0} lines per second",
+                args.join(" "),
+                elapsed,
+                1_000_000.0 / elapsed.as_secs_f64()
+            );
+            outputs.push(output);
+        }
+
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[test]
+    fn upcoming_test() {
+        let now = NaiveDate::from_ymd_opt(2016, 2, 18).unwrap();
+
+        assert_eq!(
+            vec![
+                "Chaoflux is tomorrow (Setting Orange, Chaos 50)".to_string(),
+                "St. Tib's Day is in 11 days".to_string(),
+            ],
+            upcoming(now, 11)
+        );
+        assert!(upcoming(now, 0).is_empty());
+        assert_eq!(
+            21,
+            upcoming(NaiveDate::from_ymd_opt(2016, 1, 1).unwrap(), 731).len()
+        );
+    }
+
+    #[test]
+    fn csv_test() {
+        let input = "id,\"when, roughly\",note\n\
+                     1,2017-11-04,\"fnord, \"\"hail\"\"\"\n\
+                     2,not a date,\n\
+                     3,,\"two\nlines\"\n\
+                     4\n";
+
+        assert_eq!(
+            (
+                0,
+                "id,\"when, roughly\",note,ddate\n\
+                 1,2017-11-04,\"fnord, \"\"hail\"\"\",Afm 16\n\
+                 2,not a date,,\n\
+                 3,,\"two\nlines\",\n\
+                 4,,,\n"
+                    .to_string()
+            ),
+            run_with_input(
+                &[
+                    "csv",
+                    "--column",
+                    "when, roughly",
+                    "--append=ddate",
+                    "--format=%b %d"
+                ],
+                input
+            )
+        );
+        assert_eq!(
+            (
+                0,
+                "id,\"when, roughly\",note\n\
+                 1,\"Pungenday, the 16th day of The Aftermath in the YOLD 3183\",\"fnord, \"\"hail\"\"\"\n\
+                 2,,\n\
+                 3,,\"two\nlines\"\n\
+                 4,,\n"
+                    .to_string()
+            ),
+            run_with_input(&["csv", "--column=2", "--replace"], input)
+        );
+
+        let args: Vec<String> = ["csv", "--column", "2"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let now = now();
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        assert_eq!(
+            0,
+            run(&args, now, &mut input.as_bytes(), false, &mut out, &mut err).unwrap()
+        );
+        assert_eq!(
+            "Warning: 1 cell could not be parsed as a date.\n",
+            String::from_utf8(err).unwrap()
+        );
+
+        // Ragged records: the appended column stays under its name.
+        let ragged = "id,date\n\
+                      1,2017-11-04,extra,cells\n\
+                      2\n\
+                      3,2017-09-26\n";
+        let (status, output) = run_with_input(&["csv", "--column=date", "--format=%b %d"], ragged);
+        assert_eq!(0, status);
+        assert_eq!(
+            "id,date,discordian\n\
+             1,2017-11-04,Afm 16,extra,cells\n\
+             2,,\n\
+             3,2017-09-26,Bcy 50\n",
+            output
+        );
+        let mut reader = ddate::csv::Reader::new(output.as_bytes());
+        let mut record = Vec::new();
+        let mut converted = Vec::new();
+        while reader.read_record(&mut record).unwrap() {
+            converted.push(record[2].clone());
+        }
+        assert_eq!(vec!["discordian", "Afm 16", "", "Bcy 50"], converted);
+
+        assert_eq!(
+            (0, String::new()),
+            run_with_input(&["csv", "--column=1"], "")
+        );
+        assert_eq!(1, run_with_input(&["csv", "--column=date"], input).0);
+        assert_eq!(1, run_with_input(&["csv", "--column=4"], input).0);
+        assert_eq!(2, run_with(&["csv"]).0);
+        assert_eq!(
+            2,
+            run_with(&["csv", "--column=1", "--replace", "--append=x"]).0
+        );
+        assert_eq!(2, run_with(&["csv", "--column=1", "2017-11-04"]).0);
+        assert_eq!(2, run_with(&["--column=1"]).0);
+    }
+
+    #[test]
+    fn test_vectors_test() {
+        assert_eq!(
+            (
+                0,
+                "gregorian,yold,season,day,weekday,holyday,st_tibs\n\
+                 2016-02-28,3182,Chaos,59,Prickle-Prickle,,false\n\
+                 2016-02-29,3182,,,,,true\n\
+                 2016-03-01,3182,Chaos,60,Setting Orange,,false\n"
+                    .to_string()
+            ),
+            run_with(&["--generate-test-vectors", "2016-02-28", "2016-03-01"])
+        );
+        assert_eq!(
+            (
+                0,
+                "gregorian,yold,season,day,weekday,holyday,st_tibs\n\
+                 2017-09-26,3183,Bureaucracy,50,Prickle-Prickle,Bureflux,false\n"
+                    .to_string()
+            ),
+            run_with(&["--generate-test-vectors", "2017-09-26", "2017-09-26"])
+        );
+        assert_eq!(
+            1,
+            run_with(&["--generate-test-vectors", "2017-09-26", "Fnord"]).0
+        );
+        assert_eq!(2, run_with(&["--generate-test-vectors", "2017-09-26"]).0);
+    }
+
+    #[test]
+    fn version_test() {
+        assert_eq!(
+            (0, format!("ddate {}\n{}\n", super::VERSION, super::ABOUT)),
+            run_with(&["--version"])
+        );
+        assert_eq!(0, run_with(&["--version", "2017-11-04"]).0);
+    }
+
+    #[test]
+    fn warn_range_test() {
+        let run_err = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            let (mut out, mut err) = (Vec::new(), Vec::new());
+            let status = run(&args, now(), &mut io::empty(), false, &mut out, &mut err).unwrap();
+            (
+                status,
+                String::from_utf8(out).unwrap(),
+                String::from_utf8(err).unwrap(),
+            )
+        };
+
+        let (status, out, err) = run_err(&["--warn-range", "2017-11-04", "-1200-01-01"]);
+        assert_eq!(0, status);
+        assert_eq!(run_with(&["2017-11-04", "-1200-01-01"]).1, out);
+        assert_eq!(
+            "Warning: -1200-01-01 is in YOLD -34, outside of YOLD 0 to 10000.\n",
+            err
+        );
+
+        let (_, _, err) = run_err(&["--warn-range", "2017-11-04", "-1166-01-01", "8834-12-31"]);
+        assert_eq!("", err);
+        let (_, _, err) = run_err(&["--warn-range", "8835-01-01"]);
+        assert!(err.contains("YOLD 10001"));
+        let (_, _, err) = run_err(&["-1200-01-01"]);
+        assert_eq!("", err);
+    }
+
+    #[test]
+    fn all_locales_test() {
+        assert_eq!(
+            (
+                0,
+                "en: Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183. Celebrate Bureflux\n\
+                 de: Stachel-Stachel, der fünfzigste Tag der Bürokratie im Jahr unserer Herrin der Zwietracht 3183. Feiert Bureflux\n"
+                    .to_string()
+            ),
+            run_with(&["--all-locales", "2017-09-26"])
+        );
+        assert_eq!(
+            run_with(&["--all-locales", "2017-11-04"]),
+            run_with(&["--all-locales"])
+        );
+
+        let (_, out) = run_with(&["--all-locales", "2016-02-29", "2017-11-04"]);
+        assert_eq!(4, out.lines().count());
+    }
+
+    #[test]
+    fn raw_ordinal_test() {
+        assert_eq!(
+            (0, "308\n60\n61\n366\n".to_string()),
+            run_with(&[
+                "--raw-ordinal",
+                "2017-11-04",
+                "2016-02-29",
+                "2016-03-01",
+                "2016-12-31"
+            ])
+        );
+        assert_eq!((0, "308\n".to_string()), run_with(&["--raw-ordinal"]));
+    }
+
+    #[test]
+    fn card_test() {
+        let rule = "\u{2500}".repeat(53);
+        assert_eq!(
+            (
+                0,
+                format!(
+                    "\u{250c}{rule}\u{2510}\n\
+                     \u{2502}     Prickle-Prickle, Bureaucracy 50, 3183 YOLD      \u{2502}\n\
+                     \u{2502}                    \u{2605} Bureflux \u{2605}                     \u{2502}\n\
+                     \u{2514}{rule}\u{2518}\n",
+                    rule = rule
+                )
+            ),
+            run_with(&["--card", "2017-09-26"])
+        );
+
+        // Every card is as wide as the longest date.
+        let (status, out) = run_with(&["--card"]);
+        assert_eq!(0, status);
+        assert_eq!(3, out.lines().count());
+        assert!(out.contains("Pungenday, The Aftermath 16, 3183 YOLD"));
+        let (_, out) = run_with(&["--card", "2016-02-29", "-262143-01-01"]);
+        assert_eq!(6, out.lines().count());
+        assert!(out.lines().all(|line| line.chars().count() == 55));
+    }
+
+    #[test]
+    fn ics_test() {
+        let (status, out) = run_with(&["--ics", "2017"]);
+        assert_eq!(0, status);
+        assert!(out.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(out.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(1, out.matches("BEGIN:VCALENDAR").count());
+        assert_eq!(10, out.matches("BEGIN:VEVENT").count());
+        assert_eq!(10, out.matches("END:VEVENT").count());
+        assert!(out.contains(
+            "BEGIN:VEVENT\r\n\
+             UID:bureflux-3183@ddate\r\n\
+             DTSTAMP:20171104T000000Z\r\n\
+             DTSTART;VALUE=DATE:20170926\r\n\
+             DTEND;VALUE=DATE:20170927\r\n\
+             SUMMARY:Bureflux\r\n\
+             DESCRIPTION:Prickle-Prickle\\, the 50th day of Bureaucracy in the YOLD 3183\r\n\
+             TRANSP:TRANSPARENT\r\n\
+             END:VEVENT\r\n"
+        ));
+        assert!(out.lines().all(|line| line.len() <= 76));
+
+        // Long YOLDs fold the description.
+        let (status, out) = run_with(&["--ics", "-262000"]);
+        assert_eq!(0, status);
+        assert!(out.contains(
+            "DESCRIPTION:Prickle-Prickle\\, the 5th day of Bureaucracy in the YOLD -26083\r\n 4\r\n"
+        ));
+        assert!(out.lines().all(|line| line.len() <= 76));
+
+        assert_eq!(1, run_with(&["--ics", "262143"]).0);
+        assert_eq!(2, run_with(&["--ics", "MMXVII"]).0);
+        assert_eq!(2, run_with(&["--ics"]).0);
+    }
+
+    #[test]
+    fn world_clock_test() {
+        let at = |moment: &str, args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            let moment = DateTime::parse_from_rfc3339(moment).unwrap();
+            let mut out = Vec::new();
+            let status = run(
+                &args,
+                moment,
+                &mut io::empty(),
+                false,
+                &mut out,
+                &mut io::sink(),
+            );
+            (status.unwrap(), String::from_utf8(out).unwrap())
+        };
+
+        // Half past eleven at night in Berlin is already tomorrow in
+        // Auckland, but still the afternoon in Los Angeles.
+        assert_eq!(
+            (
+                0,
+                "* +13:00: Prickle-Prickle, the 17th day of The Aftermath in the YOLD 3183\n  \
+                 +01:00: Pungenday, the 16th day of The Aftermath in the YOLD 3183\n  \
+                 UTC-8: Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"
+                    .to_string()
+            ),
+            at(
+                "2017-11-04T23:30:00+01:00",
+                &["--zones", "+13:00,+01:00,UTC-8"]
+            )
+        );
+        assert_eq!(
+            (
+                0,
+                "[\n  \
+                 {\"zone\": \"+1300\", \"date\": \"2017-11-05\", \"discordian\": \"Afm 17\", \"differs\": true},\n  \
+                 {\"zone\": \"UTC\", \"date\": \"2017-11-04\", \"discordian\": \"Afm 16\", \"differs\": false}\n\
+                 ]\n"
+                    .to_string()
+            ),
+            at(
+                "2017-11-04T23:30:00+01:00",
+                &["--zones=+1300, UTC", "--json", "--format=%b %d"]
+            )
+        );
+        // Holydays stay on the line of their zone.
+        assert_eq!(
+            (
+                0,
+                "* -10:00: Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183; \
+                 Celebrate Bureflux\n"
+                    .to_string()
+            ),
+            at("2017-09-27T01:00:00Z", &["--zones", "-10:00"])
+        );
+
+        assert_eq!(2, run_with(&["--zones", "Mars/Olympus_Mons"]).0);
+        assert_eq!(2, run_with(&["--zones"]).0);
+        assert_eq!(2, run_with(&["--json"]).0);
+    }
+
+    #[test]
+    fn parse_zone_test() {
+        use super::{parse_zone, Zone};
+
+        let offset = |zone| match parse_zone(zone) {
+            Ok(Zone::Fixed(offset)) => Ok(offset.local_minus_utc()),
+            other => Err(format!("{:?}", other)),
+        };
+        assert_eq!(Ok(0), offset("UTC"));
+        assert_eq!(Ok(0), offset("Z"));
+        assert_eq!(Ok(0), offset("GMT"));
+        assert_eq!(Ok(13 * 3600), offset("+13:00"));
+        assert_eq!(Ok(13 * 3600 + 45 * 60), offset("+1345"));
+        assert_eq!(Ok(-8 * 3600), offset("UTC-8"));
+        assert_eq!(Ok(5 * 3600 + 30 * 60), offset("GMT+05:30"));
+
+        for zone in [
+            "Mars/Olympus_Mons",
+            "+24:00",
+            "+01:60",
+            "13:00",
+            "+",
+            "UTC+x",
+            "",
+        ] {
+            assert!(parse_zone(zone).is_err(), "{}", zone);
+        }
+        assert!(parse_zone("Mars/Olympus_Mons")
+            .unwrap_err()
+            .starts_with("Unrecognized time zone Mars/Olympus_Mons: "));
+        #[cfg(not(feature = "tz"))]
+        assert!(parse_zone("Pacific/Auckland")
+            .unwrap_err()
+            .contains("no time zone data"));
+    }
 }
@@ -0,0 +1,137 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Table-driven conversion for converting many dates at once.
+//!
+//! Every Gregorian year has one of two shapes, leap or common, so the
+//! season, day, and weekday of each ordinal day are precomputed here; the
+//! conversion of each date is then a table index and a year add.
+
+use crate::{
+    DiscordianDateComponents, Season, Weekday, CURSE_OF_GREYFACE, SEASON_DAYS, ST_TIBS_DAY,
+    WEEK_DAYS,
+};
+
+/// The components of each zero-based ordinal day of a common year, with a
+/// YOLD of zero. The final entry is unused.
+const COMMON: [DiscordianDateComponents; 366] = table(false);
+/// The components of each zero-based ordinal day of a leap year, with a
+/// YOLD of zero.
+const LEAP: [DiscordianDateComponents; 366] = table(true);
+
+/// Builds the lookup table for a year of the given shape.
+const fn table(leap: bool) -> [DiscordianDateComponents; 366] {
+    let mut table = [DiscordianDateComponents {
+        yold: 0,
+        season: Season::Chaos,
+        day: 0,
+        st_tibs: false,
+        weekday: None,
+    }; 366];
+
+    let mut day = 0;
+    while day < 366 {
+        if leap && day == ST_TIBS_DAY {
+            table[day].day = ST_TIBS_DAY as u8;
+            table[day].st_tibs = true;
+        } else {
+            let day_offset = if leap && day > ST_TIBS_DAY {
+                day - 1
+            } else {
+                day
+            };
+
+            if day_offset < SEASON_DAYS * 5 {
+                table[day].season = Season::ALL[day_offset / SEASON_DAYS];
+                table[day].day = (day_offset % SEASON_DAYS + 1) as u8;
+                table[day].weekday = Some(Weekday::ALL[day_offset % WEEK_DAYS]);
+            }
+        }
+        day += 1;
+    }
+
+    table
+}
+
+/// Converts one-based ordinal days of a Gregorian year, appending their
+/// Discordian components to `out` in the same order.
+///
+/// # Panics
+///
+/// Panics if an ordinal is zero or greater than the number of days in the
+/// year, or if the year is so late that its YOLD does not fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{bulk, Season};
+///
+/// let mut out = Vec::new();
+/// bulk::convert_ordinals(2017, &[1, 308], &mut out);
+///
+/// assert_eq!((Season::Chaos, 1), (out[0].season, out[0].day));
+/// assert_eq!((Season::TheAftermath, 16), (out[1].season, out[1].day));
+/// ```
+pub fn convert_ordinals(year: i32, ordinals: &[u16], out: &mut Vec<DiscordianDateComponents>) {
+    let (table, days) = if crate::is_leap_year(year.into()) {
+        (&LEAP, 366)
+    } else {
+        (&COMMON, 365)
+    };
+    let yold = year
+        .checked_add(CURSE_OF_GREYFACE)
+        .expect("the YOLD of the year overflows an i32");
+
+    out.reserve(ordinals.len());
+    for &ordinal in ordinals {
+        assert!(
+            ordinal >= 1 && ordinal <= days,
+            "ordinal {} out of range for year {}",
+            ordinal,
+            year
+        );
+        out.push(DiscordianDateComponents {
+            yold,
+            ..table[ordinal as usize - 1]
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convert_ordinals;
+    use crate::DiscordianDate;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn table_equivalence_test() {
+        for &(year, days) in &[(2000, 366), (2001, 365), (1900, 365), (-1166, 365)] {
+            let ordinals: Vec<u16> = (1..=days).collect();
+            let mut out = Vec::new();
+            convert_ordinals(year, &ordinals, &mut out);
+
+            for (&ordinal, bulk) in ordinals.iter().zip(&out) {
+                let date = NaiveDate::from_yo_opt(year, ordinal as u32).unwrap();
+                assert_eq!(date.discordian_components(), *bulk, "{}", date);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_test() {
+        convert_ordinals(2001, &[366], &mut Vec::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn yold_overflow_test() {
+        convert_ordinals(i32::MAX - 1165, &[1], &mut Vec::new());
+    }
+}
@@ -0,0 +1,140 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::DiscordianDate;
+use chrono::NaiveDate;
+
+/// A formatter producing the string cached by a `PoeeCache`.
+type Formatter = Box<dyn Fn(&NaiveDate) -> String + Send>;
+
+/// Caches the formatted Discordian date for consumers, such as status bars,
+/// that ask for it far more often than it changes.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::PoeeCache;
+///
+/// let mut cache = PoeeCache::new();
+/// let today = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+///
+/// assert_eq!("Pungenday, the 16th day of The Aftermath in the YOLD 3183", cache.get(today));
+/// ```
+pub struct PoeeCache {
+    formatter: Formatter,
+    date: Option<NaiveDate>,
+    formatted: String,
+}
+
+impl PoeeCache {
+    /// Creates a cache of `to_poee` output.
+    pub fn new() -> Self {
+        PoeeCache::with_formatter(|date: &NaiveDate| date.to_poee())
+    }
+
+    /// Creates a cache of the output of a custom formatter.
+    pub fn with_formatter<F>(formatter: F) -> Self
+    where
+        F: Fn(&NaiveDate) -> String + Send + 'static,
+    {
+        PoeeCache {
+            formatter: Box::new(formatter),
+            date: None,
+            formatted: String::new(),
+        }
+    }
+
+    /// Replaces the formatter, invalidating the cached string.
+    pub fn set_formatter<F>(&mut self, formatter: F)
+    where
+        F: Fn(&NaiveDate) -> String + Send + 'static,
+    {
+        self.formatter = Box::new(formatter);
+        self.invalidate();
+    }
+
+    /// Discards the cached string, so that the next `get` reformats.
+    pub fn invalidate(&mut self) {
+        self.date = None;
+    }
+
+    /// Returns the formatted date for `today`, reformatting only if it
+    /// differs from the date last requested.
+    pub fn get(&mut self, today: NaiveDate) -> &str {
+        if self.date != Some(today) {
+            self.formatted = (self.formatter)(&today);
+            self.date = Some(today);
+        }
+
+        &self.formatted
+    }
+}
+
+impl Default for PoeeCache {
+    fn default() -> Self {
+        PoeeCache::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PoeeCache;
+    use crate::DiscordianDate;
+    use chrono::NaiveDate;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn counting_cache(counter: &Arc<AtomicUsize>) -> PoeeCache {
+        let counter = Arc::clone(counter);
+        PoeeCache::with_formatter(move |date| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            date.to_poee()
+        })
+    }
+
+    #[test]
+    fn reformat_on_change_test() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut cache = counting_cache(&counter);
+        let today = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+        let tomorrow = today.succ_opt().unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(today.to_poee(), cache.get(today));
+        }
+        assert_eq!(1, counter.load(Ordering::SeqCst));
+
+        assert_eq!(tomorrow.to_poee(), cache.get(tomorrow));
+        assert_eq!(2, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn invalidate_on_formatter_change_test() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut cache = counting_cache(&counter);
+        let today = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+
+        cache.get(today);
+        let recounter = Arc::clone(&counter);
+        cache.set_formatter(move |date| {
+            recounter.fetch_add(1, Ordering::SeqCst);
+            date.with_gregorian_weekday()
+        });
+
+        assert_eq!(today.with_gregorian_weekday(), cache.get(today));
+        assert_eq!(2, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn send_test() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PoeeCache>();
+    }
+}
@@ -0,0 +1,212 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Streaming reading and writing of CSV records, for converting date columns
+//! of spreadsheets.
+//!
+//! Records are read as RFC 4180 describes them: fields are separated by
+//! commas, and fields containing commas, quotes, or line breaks are quoted,
+//! with their quotes doubled. Records may end in `\n` or `\r\n`, and quoted
+//! fields may span lines. Records are read one at a time, so files of any
+//! size can be converted without being held in memory.
+
+use std::io::{self, BufRead, Write};
+
+/// Reads CSV records one at a time.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::csv::Reader;
+///
+/// let mut reader = Reader::new("date,event\n2017-11-04,\"Fnord, again\"\n".as_bytes());
+/// let mut record = Vec::new();
+///
+/// assert!(reader.read_record(&mut record).unwrap());
+/// assert_eq!(vec!["date", "event"], record);
+/// assert!(reader.read_record(&mut record).unwrap());
+/// assert_eq!(vec!["2017-11-04", "Fnord, again"], record);
+/// assert!(!reader.read_record(&mut record).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct Reader<R> {
+    input: R,
+    line: String,
+    number: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Creates a reader of the records in `input`.
+    pub fn new(input: R) -> Self {
+        Reader {
+            input,
+            line: String::new(),
+            number: 0,
+        }
+    }
+
+    /// Reads the next record into `record`, replacing its fields, and
+    /// returns whether there was one.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `InvalidData` if the input is not UTF-8, or if a quoted
+    /// field is still open at the end of the input, as well as with any
+    /// error reading the input.
+    pub fn read_record(&mut self, record: &mut Vec<String>) -> io::Result<bool> {
+        record.clear();
+        if !self.read_line()? {
+            return Ok(false);
+        }
+
+        let start = self.number;
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut offset = 0;
+
+        loop {
+            let mut chars = self.line[offset..].chars().peekable();
+
+            while let Some(c) = chars.next() {
+                match (quoted, c) {
+                    (true, '"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    (true, '"') => quoted = false,
+                    (true, c) => field.push(c),
+                    (false, '"') if field.is_empty() => quoted = true,
+                    (false, ',') => record.push(std::mem::take(&mut field)),
+                    (false, '\n') => {}
+                    (false, '\r') if chars.peek() == Some(&'\n') => {}
+                    (false, c) => field.push(c),
+                }
+            }
+
+            if !quoted {
+                record.push(field);
+                return Ok(true);
+            }
+
+            // The line break belongs to a quoted field, so the record goes
+            // on to the next line.
+            offset = self.line.len();
+            if !self.read_more()? {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("quoted field starting on line {} is never closed", start),
+                ));
+            }
+        }
+    }
+
+    /// Reads the next line, replacing the current one.
+    fn read_line(&mut self) -> io::Result<bool> {
+        self.line.clear();
+        self.read_more()
+    }
+
+    /// Reads the next line onto the end of the current one.
+    fn read_more(&mut self) -> io::Result<bool> {
+        let read = self.input.read_line(&mut self.line)?;
+        self.number += 1;
+        Ok(read > 0)
+    }
+}
+
+/// Writes a record, quoting those fields which need it, and ending it with
+/// `\n`.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = Vec::new();
+/// ddate::csv::write_record(&mut out, &["2017-11-04", "Fnord, \"again\""]).unwrap();
+///
+/// assert_eq!("2017-11-04,\"Fnord, \"\"again\"\"\"\n", String::from_utf8(out).unwrap());
+/// ```
+pub fn write_record<W: Write, S: AsRef<str>>(out: &mut W, record: &[S]) -> io::Result<()> {
+    for (n, field) in record.iter().enumerate() {
+        if n > 0 {
+            out.write_all(b",")?;
+        }
+
+        let field = field.as_ref();
+        if field.contains([',', '"', '\r', '\n']) {
+            out.write_all(b"\"")?;
+            out.write_all(field.replace('"', "\"\"").as_bytes())?;
+            out.write_all(b"\"")?;
+        } else {
+            out.write_all(field.as_bytes())?;
+        }
+    }
+
+    out.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_record, Reader};
+
+    fn records(input: &str) -> Vec<Vec<String>> {
+        let mut reader = Reader::new(input.as_bytes());
+        let mut record = Vec::new();
+        let mut records = Vec::new();
+
+        while reader.read_record(&mut record).unwrap() {
+            records.push(record.clone());
+        }
+
+        records
+    }
+
+    #[test]
+    fn read_test() {
+        assert_eq!(
+            vec![
+                vec!["a", "b,c", "d\"e"],
+                vec!["", "line\r\nbreak", ""],
+                vec!["x"],
+                vec![""],
+                vec!["trailing", "crlf"],
+            ],
+            records("a,\"b,c\",\"d\"\"e\"\n,\"line\r\nbreak\",\nx\n\ntrailing,crlf\r\n")
+        );
+        assert_eq!(vec![vec!["no", "newline"]], records("no,newline"));
+        assert_eq!(vec![vec!["a\"b\"", "c"]], records("a\"b\",c\n"));
+        assert!(records("").is_empty());
+
+        let mut reader = Reader::new("a,\"open\nfield\n".as_bytes());
+        let e = reader.read_record(&mut Vec::new()).unwrap_err();
+        assert_eq!(
+            "quoted field starting on line 1 is never closed",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let input = "date,\"event, or not\",notes\n\
+                     2017-11-04,\"Fnord\",\"He said \"\"hail\"\"\nand left\"\n\
+                     ,,\n";
+        let mut out = Vec::new();
+        for record in records(input) {
+            write_record(&mut out, &record).unwrap();
+        }
+        let output = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            "date,\"event, or not\",notes\n\
+             2017-11-04,Fnord,\"He said \"\"hail\"\"\nand left\"\n\
+             ,,\n",
+            output
+        );
+        assert_eq!(records(input), records(&output));
+    }
+}
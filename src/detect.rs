@@ -0,0 +1,394 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::{
+    is_leap_year, parse_discordian, DdateError, DiscordianDate, DiscordianDateComponents, Format,
+};
+use chrono::{NaiveDate, Weekday};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// The Gregorian formats understood by `parse_gregorian`.
+const GREGORIAN_FORMATS: [&str; 8] = [
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%d %B %Y",
+    "%B %d %Y",
+    "%B %d, %Y",
+    "%d %b %Y",
+    "%b %d %Y",
+    "%b %d, %Y",
+];
+
+/// A calendar in which a date may be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    Gregorian,
+    Discordian,
+}
+
+impl FromStr for Calendar {
+    type Err = DdateError;
+
+    /// Parses the name of a calendar, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "gregorian" => Ok(Calendar::Gregorian),
+            "discordian" => Ok(Calendar::Discordian),
+            _ => Err(DdateError::UnknownCalendar(s.to_string())),
+        }
+    }
+}
+
+/// A date, in whichever calendar `detect_date` found it to be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DetectedDate {
+    Gregorian(NaiveDate),
+    Discordian(DiscordianDateComponents),
+}
+
+impl DetectedDate {
+    /// Returns the calendar the date was written in.
+    pub fn calendar(&self) -> Calendar {
+        match self {
+            DetectedDate::Gregorian(_) => Calendar::Gregorian,
+            DetectedDate::Discordian(_) => Calendar::Discordian,
+        }
+    }
+}
+
+/// Parses a Gregorian date in one of a few common numeric and written
+/// formats, such as `2017-11-04`, `2017/11/04`, `4 November 2017`, or
+/// `Nov 4, 2017`, or as an ISO 8601 week date, such as `2017-W44-6`, or
+/// ordinal date, such as `2017-308`.
+///
+/// A week, weekday, or ordinal day out of range for its year is a
+/// `ComponentOutOfRange` error naming it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{parse_gregorian, DdateError};
+///
+/// let date = NaiveDate::from_ymd_opt(2017, 11, 4);
+///
+/// assert_eq!(date, parse_gregorian("Nov 4, 2017").ok());
+/// assert_eq!(date, parse_gregorian("2017-W44-6").ok());
+/// assert_eq!(date, parse_gregorian("2017-308").ok());
+/// assert_eq!(
+///     Err(DdateError::ComponentOutOfRange { component: "week", value: 54, max: 52 }),
+///     parse_gregorian("2017-W54-1")
+/// );
+/// ```
+pub fn parse_gregorian(input: &str) -> Result<NaiveDate, DdateError> {
+    let input = input.trim();
+
+    if let Some(date) = parse_iso_week_or_ordinal(input) {
+        return date;
+    }
+
+    GREGORIAN_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(input, format).ok())
+        .ok_or_else(|| DdateError::GregorianParse(format!("{:?} is in no known format", input)))
+}
+
+/// Parses a Gregorian date written in the `chrono` format `input_format`
+/// and returns it as a Discordian date in the format `output_format`, as
+/// understood by `Format::strict`.
+///
+/// Input which does not match its format is a `GregorianParse` error, and an output
+/// format with an unknown code is an `UnknownFormatCode` error.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{reformat, DdateError};
+///
+/// assert_eq!(
+///     Ok("Pungenday, the 16th day of The Aftermath, 3183".to_string()),
+///     reformat("04/11/2017", "%d/%m/%Y", "%A, the %e day of %B, %Y")
+/// );
+/// assert_eq!(
+///     Err(DdateError::UnknownFormatCode('Q')),
+///     reformat("04/11/2017", "%d/%m/%Y", "%Q")
+/// );
+/// ```
+pub fn reformat(
+    input: &str,
+    input_format: &str,
+    output_format: &str,
+) -> Result<String, DdateError> {
+    let format = Format::strict(output_format)?;
+    let date = NaiveDate::parse_from_str(input.trim(), input_format).map_err(|e| {
+        DdateError::GregorianParse(format!(
+            "{:?} is not in the format {:?}: {}",
+            input, input_format, e
+        ))
+    })?;
+
+    Ok(format.render(&date.discordian_components()))
+}
+
+/// Parses an ISO 8601 week date, `YYYY-Www-D`, or ordinal date, `YYYY-DDD`,
+/// returning `None` if the input is in neither form.
+fn parse_iso_week_or_ordinal(input: &str) -> Option<Result<NaiveDate, DdateError>> {
+    let digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+
+    let unsigned = input.strip_prefix('-').unwrap_or(input);
+    let (year, rest) = unsigned.split_once('-')?;
+    if year.len() < 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year: i32 = input[..input.len() - rest.len() - 1].parse().ok()?;
+    let invalid = || DdateError::GregorianParse(format!("{:?} is not a date", input));
+
+    if let Some((week, weekday)) = rest.strip_prefix('W').and_then(|rest| rest.split_once('-')) {
+        if !digits(week, 2) || !digits(weekday, 1) {
+            return None;
+        }
+        let (week, weekday): (u32, u32) = (week.parse().ok()?, weekday.parse().ok()?);
+        let weeks = if NaiveDate::from_isoywd_opt(year, 53, Weekday::Mon).is_some() {
+            53
+        } else {
+            52
+        };
+
+        Some(if weekday == 0 || weekday > 7 {
+            Err(DdateError::ComponentOutOfRange {
+                component: "weekday",
+                value: weekday,
+                max: 7,
+            })
+        } else if week == 0 || week > weeks {
+            Err(DdateError::ComponentOutOfRange {
+                component: "week",
+                value: week,
+                max: weeks,
+            })
+        } else {
+            Weekday::try_from(weekday as u8 - 1)
+                .ok()
+                .and_then(|weekday| NaiveDate::from_isoywd_opt(year, week, weekday))
+                .ok_or_else(invalid)
+        })
+    } else if digits(rest, 3) {
+        let ordinal: u32 = rest.parse().ok()?;
+        let days = if is_leap_year(year.into()) { 366 } else { 365 };
+
+        Some(if ordinal == 0 || ordinal > days {
+            Err(DdateError::ComponentOutOfRange {
+                component: "ordinal day",
+                value: ordinal,
+                max: days,
+            })
+        } else {
+            NaiveDate::from_yo_opt(year, ordinal).ok_or_else(invalid)
+        })
+    } else {
+        None
+    }
+}
+
+/// Parses a date written in either calendar; see `detect_date_with`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{detect_date, Calendar, DetectedDate, DiscordianDateComponents, Season};
+///
+/// assert_eq!(
+///     Ok(DetectedDate::Gregorian(NaiveDate::from_ymd_opt(2017, 11, 4).unwrap())),
+///     detect_date("2017-11-04", Calendar::Gregorian)
+/// );
+/// assert_eq!(
+///     DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).map(DetectedDate::Discordian),
+///     detect_date("The Aftermath 16, 3183 YOLD", Calendar::Gregorian)
+/// );
+/// ```
+pub fn detect_date(input: &str, prefer: Calendar) -> Result<DetectedDate, DdateError> {
+    detect_date_with(input, prefer, |input| {
+        parse_gregorian(input).map_err(|e| e.to_string())
+    })
+}
+
+/// Parses a date written in either calendar, using the given parser for
+/// Gregorian dates and `parse_discordian` for Discordian dates.
+///
+/// The Gregorian parser is tried first. Input which both parsers accept is
+/// taken to be in the `prefer`red calendar. Input which neither accepts is
+/// an `Undetected` error giving the reason each parser failed.
+pub fn detect_date_with<F>(
+    input: &str,
+    prefer: Calendar,
+    gregorian: F,
+) -> Result<DetectedDate, DdateError>
+where
+    F: FnOnce(&str) -> Result<NaiveDate, String>,
+{
+    let gregorian = gregorian(input);
+    let discordian = parse_discordian(input);
+
+    match (gregorian, discordian) {
+        (Ok(date), Ok(ddate)) => Ok(match prefer {
+            Calendar::Gregorian => DetectedDate::Gregorian(date),
+            Calendar::Discordian => DetectedDate::Discordian(ddate),
+        }),
+        (Ok(date), Err(_)) => Ok(DetectedDate::Gregorian(date)),
+        (Err(_), Ok(ddate)) => Ok(DetectedDate::Discordian(ddate)),
+        (Err(gregorian), Err(discordian)) => Err(DdateError::Undetected {
+            gregorian,
+            discordian: discordian.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_date, detect_date_with, parse_gregorian, reformat, Calendar, DetectedDate};
+    use crate::{DdateError, DiscordianDateComponents, Season};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn parse_gregorian_test() {
+        let date = NaiveDate::from_ymd_opt(2017, 11, 4);
+
+        for input in &[
+            "2017-11-04",
+            "2017/11/4",
+            " 4 November 2017 ",
+            "November 4, 2017",
+            "4 Nov 2017",
+            "Nov 4 2017",
+        ] {
+            assert_eq!(date, parse_gregorian(input).ok(), "{}", input);
+        }
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2000, 2, 29),
+            parse_gregorian("2000-02-29").ok()
+        );
+        assert!(parse_gregorian("2017-02-29").is_err());
+        assert!(parse_gregorian("2017").is_err());
+    }
+
+    #[test]
+    fn reformat_test() {
+        assert_eq!(
+            Ok("PD, Afm 16, 3183 YOLD".to_string()),
+            reformat("04/11/2017", "%d/%m/%Y", "%a, %b %d, %Y YOLD")
+        );
+        assert_eq!(
+            Ok("St. Tib's Day, 3182".to_string()),
+            reformat(" 29.02.2016 ", "%d.%m.%Y", "%{%A, %B %d%}, %Y")
+        );
+        assert!(matches!(
+            reformat("2017-11-04", "%d/%m/%Y", "%a"),
+            Err(DdateError::GregorianParse(_))
+        ));
+        assert_eq!(
+            Err(DdateError::UnknownFormatCode('Q')),
+            reformat("04/11/2017", "%d/%m/%Y", "%Q")
+        );
+    }
+
+    #[test]
+    fn parse_iso_test() {
+        let ymd = NaiveDate::from_ymd_opt;
+
+        assert_eq!(ymd(2017, 11, 4), parse_gregorian("2017-W44-6").ok());
+        assert_eq!(ymd(2017, 11, 4), parse_gregorian("2017-308").ok());
+        assert_eq!(ymd(2016, 12, 31), parse_gregorian("2016-366").ok());
+        assert_eq!(ymd(2016, 2, 29), parse_gregorian("2016-060").ok());
+        assert_eq!(ymd(-44, 3, 15), parse_gregorian("-0044-075").ok());
+
+        // ISO week years which begin and end in other calendar years.
+        assert_eq!(ymd(2018, 12, 31), parse_gregorian("2019-W01-1").ok());
+        assert_eq!(ymd(2021, 1, 1), parse_gregorian("2020-W53-5").ok());
+        assert_eq!(ymd(2010, 1, 3), parse_gregorian("2009-W53-7").ok());
+        assert_eq!(ymd(2008, 12, 29), parse_gregorian("2009-W01-1").ok());
+
+        assert_eq!(
+            Err(DdateError::ComponentOutOfRange {
+                component: "week",
+                value: 54,
+                max: 53
+            }),
+            parse_gregorian("2020-W54-1")
+        );
+        assert_eq!(
+            "week 53 is outside of 1 to 52",
+            parse_gregorian("2017-W53-1").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "weekday 8 is outside of 1 to 7",
+            parse_gregorian("2017-W44-8").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "ordinal day 367 is outside of 1 to 366",
+            parse_gregorian("2016-367").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "ordinal day 366 is outside of 1 to 365",
+            parse_gregorian("2017-366").unwrap_err().to_string()
+        );
+        assert!(parse_gregorian("2017-W4-6").is_err());
+        assert!(parse_gregorian("17-308").is_err());
+    }
+
+    #[test]
+    fn detect_test() {
+        let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+
+        assert_eq!(
+            Ok(Calendar::Gregorian),
+            detect_date("2017-11-04", Calendar::Discordian).map(|d| d.calendar())
+        );
+        assert_eq!(
+            Ok(DetectedDate::Discordian(ddate)),
+            detect_date(
+                "Pungenday, the 16th day of The Aftermath in the YOLD 3183",
+                Calendar::Gregorian
+            )
+        );
+
+        // Input both parsers accept goes to the preferred calendar.
+        let both = |_: &str| Ok(date);
+        assert_eq!(
+            Ok(DetectedDate::Gregorian(date)),
+            detect_date_with("Aftermath 16 3183", Calendar::Gregorian, both)
+        );
+        assert_eq!(
+            Ok(DetectedDate::Discordian(ddate)),
+            detect_date_with("Aftermath 16 3183", Calendar::Discordian, both)
+        );
+
+        match detect_date("3183", Calendar::Gregorian) {
+            Err(DdateError::Undetected {
+                gregorian,
+                discordian,
+            }) => {
+                assert_eq!(
+                    "could not parse Gregorian date: \"3183\" is in no known format",
+                    gregorian
+                );
+                assert!(discordian.contains("no season given"), "{}", discordian);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        assert_eq!(Ok(Calendar::Discordian), "Discordian".parse());
+        assert_eq!(
+            Err(DdateError::UnknownCalendar("Julian".to_string())),
+            "Julian".parse::<Calendar>()
+        );
+    }
+}
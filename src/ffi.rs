@@ -0,0 +1,183 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Functions callable from C, for bindings in other languages.
+//!
+//! These are built with the `ffi` feature. To link them from C, build the
+//! crate as a dynamic or static library, such as with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! No function panics; each returns a negative error code instead, one of
+//! the `DDATE_ERR_*` constants, and otherwise 0 or more.
+
+use crate::{DiscordianDate, DiscordianDateC, Format};
+use chrono::NaiveDate;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// A Gregorian date which does not exist, such as February 30th.
+pub const DDATE_ERR_INVALID_DATE: i32 = -1;
+/// A pointer argument which is null.
+pub const DDATE_ERR_NULL_POINTER: i32 = -2;
+/// A format which is not UTF-8, or contains an unknown code.
+pub const DDATE_ERR_INVALID_FORMAT: i32 = -3;
+/// A buffer too small for the formatted date and its terminating NUL.
+pub const DDATE_ERR_BUFFER_TOO_SMALL: i32 = -4;
+
+/// Writes the Discordian date of a Gregorian date to `out`, returning 0, or
+/// a negative error code if the date does not exist or `out` is null.
+///
+/// # Safety
+///
+/// `out` must be null, or valid for writing a `DiscordianDateC`.
+#[no_mangle]
+pub unsafe extern "C" fn ddate_from_ymd(
+    year: i32,
+    month: u32,
+    day: u32,
+    out: *mut DiscordianDateC,
+) -> i32 {
+    if out.is_null() {
+        return DDATE_ERR_NULL_POINTER;
+    }
+
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => {
+            out.write(date.discordian_components().to_ddate_c());
+            0
+        }
+        None => DDATE_ERR_INVALID_DATE,
+    }
+}
+
+/// Writes the Discordian date of a Gregorian date to `buf`, as a string in
+/// the NUL-terminated `format` as understood by `Format::strict`, returning
+/// its length in bytes, not counting the NUL it is terminated with, or a
+/// negative error code.
+///
+/// Nothing is written to `buf` unless the whole string fits in `len` bytes.
+///
+/// # Safety
+///
+/// `format` must be null, or a NUL-terminated string; `buf` must be null,
+/// or valid for writing `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ddate_format(
+    year: i32,
+    month: u32,
+    day: u32,
+    format: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> i32 {
+    if format.is_null() || buf.is_null() {
+        return DDATE_ERR_NULL_POINTER;
+    }
+
+    let format = match CStr::from_ptr(format).to_str().map(Format::strict) {
+        Ok(Ok(format)) => format,
+        _ => return DDATE_ERR_INVALID_FORMAT,
+    };
+    let date = match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => date,
+        None => return DDATE_ERR_INVALID_DATE,
+    };
+
+    let ddate = format.render(&date.discordian_components());
+    if ddate.len() >= len || ddate.len() > i32::MAX as usize {
+        return DDATE_ERR_BUFFER_TOO_SMALL;
+    }
+
+    std::ptr::copy_nonoverlapping(ddate.as_ptr(), buf.cast::<u8>(), ddate.len());
+    buf.add(ddate.len()).write(0);
+    ddate.len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr;
+
+    fn format(year: i32, month: u32, day: u32, format: &str, len: usize) -> (i32, String) {
+        let format = CString::new(format).unwrap();
+        let mut buf = vec![1 as c_char; len];
+        let written =
+            unsafe { ddate_format(year, month, day, format.as_ptr(), buf.as_mut_ptr(), len) };
+        let ddate = match buf.iter().position(|&c| c == 0) {
+            Some(end) => buf[..end].iter().map(|&c| c as u8 as char).collect(),
+            None => String::new(),
+        };
+
+        (written, ddate)
+    }
+
+    #[test]
+    fn ddate_from_ymd_test() {
+        let mut out = DiscordianDateC {
+            year: 0,
+            season: 0,
+            day: 0,
+            weekday: 0,
+            holyday: 0,
+            is_st_tibs: 0,
+        };
+
+        assert_eq!(0, unsafe { ddate_from_ymd(2017, 9, 26, &mut out) });
+        assert_eq!(
+            DiscordianDateC {
+                year: 3183,
+                season: 4,
+                day: 50,
+                weekday: 4,
+                holyday: 8,
+                is_st_tibs: 0,
+            },
+            out
+        );
+
+        assert_eq!(0, unsafe { ddate_from_ymd(2016, 2, 29, &mut out) });
+        assert_eq!((3182, 1), (out.year, out.is_st_tibs));
+
+        assert_eq!(DDATE_ERR_INVALID_DATE, unsafe {
+            ddate_from_ymd(2017, 2, 29, &mut out)
+        });
+        assert_eq!(DDATE_ERR_INVALID_DATE, unsafe {
+            ddate_from_ymd(2017, 13, 1, &mut out)
+        });
+        assert_eq!(DDATE_ERR_NULL_POINTER, unsafe {
+            ddate_from_ymd(2017, 11, 4, ptr::null_mut())
+        });
+    }
+
+    #[test]
+    fn ddate_format_test() {
+        assert_eq!(
+            (10, "PD Afm 16\n".to_string()),
+            format(2017, 11, 4, "%a %b %d%n", 11)
+        );
+        assert_eq!(
+            (DDATE_ERR_BUFFER_TOO_SMALL, String::new()),
+            format(2017, 11, 4, "%a %b %d%n", 10)
+        );
+        assert_eq!(
+            (DDATE_ERR_INVALID_DATE, String::new()),
+            format(2017, 2, 29, "%a", 64)
+        );
+        assert_eq!(
+            (DDATE_ERR_INVALID_FORMAT, String::new()),
+            format(2017, 11, 4, "%Q", 64)
+        );
+
+        let mut buf = [0 as c_char; 8];
+        assert_eq!(DDATE_ERR_NULL_POINTER, unsafe {
+            ddate_format(2017, 11, 4, ptr::null(), buf.as_mut_ptr(), buf.len())
+        });
+    }
+}
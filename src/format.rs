@@ -0,0 +1,387 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::{ordinalize, DdateError, DiscordianDateComponents, Names};
+use chrono::NaiveDate;
+use std::fmt::Write;
+
+/// The format `util-linux`'s ddate uses for the current date.
+pub const DEFAULT_FORMAT: &str = "Today is %{%A, the %e day of %B%} in the YOLD %Y%N%nCelebrate %H";
+/// The format `util-linux`'s ddate uses for a date given to it.
+pub const DATE_FORMAT: &str = "%{%A, %B %d%}, %Y YOLD";
+
+/// The exclamations of `util-linux`'s ddate, for `%.`.
+const EXCLAMATIONS: [&str; 20] = [
+    "Hail Eris!",
+    "All Hail Discordia!",
+    "Kallisti!",
+    "Fnord.",
+    "Or not.",
+    "Wibble.",
+    "Pzat!",
+    "P'tang!",
+    "Frink!",
+    "Slack!",
+    "Praise \"Bob\"!",
+    "Or kill me.",
+    "Grudnuk demand sustenance!",
+    "Keep the Lasagna flying!",
+    "You are what you see.",
+    "Or is it?",
+    "This statement is false.",
+    "Lies and slander, sire!",
+    "Hee hee hee!",
+    "Hail Eris, Hack Linux!",
+];
+
+/// One piece of a compiled format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Weekday,
+    WeekdayShort,
+    Season,
+    SeasonShort,
+    Day,
+    DayOrdinal,
+    Holyday,
+    HolydayOnly,
+    Yold,
+    XDay,
+    Exclamation,
+    TibsStart,
+    TibsEnd,
+}
+
+/// A compiled Discordian date format, as understood by `util-linux`'s ddate.
+///
+/// | Code | Meaning                                                        |
+/// |------|----------------------------------------------------------------|
+/// | `%A` | The weekday, such as `Pungenday`                               |
+/// | `%a` | The abbreviated weekday, such as `PD`                          |
+/// | `%B` | The season, such as `The Aftermath`                            |
+/// | `%b` | The abbreviated season, such as `Afm`                          |
+/// | `%d` | The day of the season, such as `16`                            |
+/// | `%e` | The ordinalized day of the season, such as `16th`              |
+/// | `%H` | The holyday, if any                                            |
+/// | `%N` | Ends the output here unless the date is a holyday              |
+/// | `%n` | A newline                                                      |
+/// | `%t` | A tab                                                          |
+/// | `%X` | The number of days remaining until X-Day                       |
+/// | `%Y` | The YOLD                                                       |
+/// | `%.` | An exclamation, chosen by the date                             |
+/// | `%{` | Begins text to be replaced by `St. Tib's Day` on that day      |
+/// | `%}` | Ends text to be replaced by `St. Tib's Day` on that day        |
+///
+/// Any other character following a `%` is output as it is, along with the
+/// `%`, so that `%%` is output as `%%`, unless the format is compiled with
+/// `Format::strict`.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{DiscordianDateComponents, Format, Season};
+///
+/// let format = Format::new("%{%A, %B %d%}, %Y YOLD");
+/// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+///
+/// assert_eq!("Pungenday, The Aftermath 16, 3183 YOLD", format.render(&ddate));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Format {
+    items: Vec<Item>,
+}
+
+impl Format {
+    /// Compiles a format string.
+    pub fn new(format: &str) -> Format {
+        match Format::compile(format, false) {
+            Ok(format) => format,
+            Err(_) => unreachable!("lenient formats always compile"),
+        }
+    }
+
+    /// Compiles a format string, rejecting any `%` not followed by one of
+    /// the codes above. A `%` ending the format is rejected as the code `%`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DdateError, Format};
+    ///
+    /// assert!(Format::strict("%A, %B %d").is_ok());
+    /// assert_eq!(Err(DdateError::UnknownFormatCode('Q')), Format::strict("%Q"));
+    /// ```
+    pub fn strict(format: &str) -> Result<Format, DdateError> {
+        Format::compile(format, true)
+    }
+
+    fn compile(format: &str, strict: bool) -> Result<Format, DdateError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = format.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+
+            let item = match chars.next() {
+                Some('A') => Item::Weekday,
+                Some('a') => Item::WeekdayShort,
+                Some('B') => Item::Season,
+                Some('b') => Item::SeasonShort,
+                Some('d') => Item::Day,
+                Some('e') => Item::DayOrdinal,
+                Some('H') => Item::Holyday,
+                Some('N') => Item::HolydayOnly,
+                Some('X') => Item::XDay,
+                Some('Y') => Item::Yold,
+                Some('.') => Item::Exclamation,
+                Some('{') => Item::TibsStart,
+                Some('}') => Item::TibsEnd,
+                Some('n') => {
+                    literal.push('\n');
+                    continue;
+                }
+                Some('t') => {
+                    literal.push('\t');
+                    continue;
+                }
+                Some(other) if strict => return Err(DdateError::UnknownFormatCode(other)),
+                Some(other) => {
+                    literal.push('%');
+                    literal.push(other);
+                    continue;
+                }
+                None if strict => return Err(DdateError::UnknownFormatCode('%')),
+                None => {
+                    literal.push('%');
+                    continue;
+                }
+            };
+
+            if !literal.is_empty() {
+                items.push(Item::Literal(std::mem::take(&mut literal)));
+            }
+            items.push(item);
+        }
+
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        Ok(Format { items })
+    }
+
+    /// Renders date components in this format.
+    pub fn render(&self, date: &DiscordianDateComponents) -> String {
+        self.render_with_exclamation(date, exclamation(date))
+    }
+
+    /// Returns the built-in exclamation `render` uses for `%.` on a date.
+    pub fn exclamation(date: &DiscordianDateComponents) -> &'static str {
+        exclamation(date)
+    }
+
+    /// Whether this format contains the `%.` code, and so would render an
+    /// exclamation.
+    pub fn uses_exclamation(&self) -> bool {
+        self.items.contains(&Item::Exclamation)
+    }
+
+    /// Renders date components in this format, with the given exclamation,
+    /// such as one drawn from a `FortuneFile`, in place of the built-in
+    /// exclamations for `%.`.
+    pub fn render_with_exclamation(
+        &self,
+        date: &DiscordianDateComponents,
+        exclamation: &str,
+    ) -> String {
+        self.render_with_names(date, exclamation, &Names::default())
+    }
+
+    /// Renders date components in this format, with the given exclamation
+    /// for `%.`, and the given names in place of the built-in ones.
+    pub fn render_with_names(
+        &self,
+        date: &DiscordianDateComponents,
+        exclamation: &str,
+        names: &Names,
+    ) -> String {
+        let mut out = String::new();
+        self.render_into(date, exclamation, names, &mut out);
+        out
+    }
+
+    /// Renders date components as `render_with_names` does, appending to
+    /// `out`, so that a buffer may be reused across many dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Format, Names, Season};
+    ///
+    /// let format = Format::new("%a %b %d");
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// let mut out = String::from("> ");
+    /// format.render_into(&ddate, "", &Names::default(), &mut out);
+    /// assert_eq!("> PD Afm 16", out);
+    /// ```
+    pub fn render_into(
+        &self,
+        date: &DiscordianDateComponents,
+        exclamation: &str,
+        names: &Names,
+        out: &mut String,
+    ) {
+        let mut in_tibs = false;
+
+        for item in &self.items {
+            if in_tibs && *item != Item::TibsEnd {
+                continue;
+            }
+
+            match item {
+                Item::Literal(text) => out.push_str(text),
+                Item::Weekday => out.push_str(date.weekday.map_or("", |w| names.weekday(w))),
+                Item::WeekdayShort => {
+                    out.push_str(date.weekday.map_or("", |w| names.weekday_abbreviation(w)))
+                }
+                Item::Season if !date.st_tibs => out.push_str(names.season(date.season)),
+                Item::SeasonShort if !date.st_tibs => {
+                    out.push_str(names.season_abbreviation(date.season))
+                }
+                Item::Day if !date.st_tibs => {
+                    let _ = write!(out, "{}", date.day);
+                }
+                Item::DayOrdinal if !date.st_tibs => out.push_str(&ordinalize(date.day as usize)),
+                Item::Season | Item::SeasonShort | Item::Day | Item::DayOrdinal => {}
+                Item::Holyday => {
+                    if let Some(holyday) = date.holyday() {
+                        out.push_str(names.holyday(holyday));
+                    }
+                }
+                Item::HolydayOnly => {
+                    if date.holyday().is_none() {
+                        break;
+                    }
+                }
+                Item::Yold => {
+                    let _ = write!(out, "{}", date.yold);
+                }
+                Item::XDay => {
+                    if let Some(days) = days_until_x_day(date) {
+                        let _ = write!(out, "{}", days);
+                    }
+                }
+                Item::Exclamation => out.push_str(exclamation),
+                Item::TibsStart => {
+                    if date.st_tibs {
+                        out.push_str(names.st_tibs());
+                        in_tibs = true;
+                    }
+                }
+                Item::TibsEnd => in_tibs = false,
+            }
+        }
+    }
+}
+
+/// Returns the number of days until X-Day, July 5th, 8661 CE.
+fn days_until_x_day(date: &DiscordianDateComponents) -> Option<i64> {
+    let x_day = NaiveDate::from_ymd_opt(8661, 7, 5)?;
+    Some(
+        x_day
+            .signed_duration_since(date.to_naive_date()?)
+            .num_days(),
+    )
+}
+
+/// Chooses an exclamation for a date, the same for every use of that date.
+fn exclamation(date: &DiscordianDateComponents) -> &'static str {
+    let day = date.season as i64 * 73 + date.day as i64 + date.st_tibs as i64;
+    let index = (i64::from(date.yold) * 366 + day).rem_euclid(EXCLAMATIONS.len() as i64);
+
+    EXCLAMATIONS[index as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, DATE_FORMAT, DEFAULT_FORMAT, EXCLAMATIONS};
+    use crate::{DdateError, DiscordianDateComponents, Season};
+
+    #[test]
+    fn default_format_test() {
+        let format = Format::new(DEFAULT_FORMAT);
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 50).unwrap();
+        assert_eq!(
+            "Today is Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux",
+            format.render(&ddate)
+        );
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+        assert_eq!(
+            "Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183",
+            format.render(&ddate)
+        );
+
+        let tibs = DiscordianDateComponents::st_tibs_day(3166).unwrap();
+        assert_eq!(
+            "Today is St. Tib's Day in the YOLD 3166",
+            format.render(&tibs)
+        );
+        assert_eq!(
+            "St. Tib's Day, 3166 YOLD",
+            Format::new(DATE_FORMAT).render(&tibs)
+        );
+    }
+
+    #[test]
+    fn codes_test() {
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+
+        assert_eq!("PD Afm\t%Q %", Format::new("%a %b%t%Q %").render(&ddate));
+        assert_eq!("2426549", Format::new("%X").render(&ddate));
+        assert!(EXCLAMATIONS.contains(&Format::new("%.").render(&ddate).as_str()));
+        assert_eq!(
+            "PD: Fnord.",
+            Format::new("%a: %.").render_with_exclamation(&ddate, "Fnord.")
+        );
+        assert!(Format::new("%a: %.").uses_exclamation());
+        assert!(!Format::new("%a: %%.").uses_exclamation());
+    }
+
+    #[test]
+    fn strict_test() {
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+
+        assert_eq!("%Q", Format::new("%Q").render(&ddate));
+        assert_eq!(
+            Err(DdateError::UnknownFormatCode('Q')),
+            Format::strict("%Q")
+        );
+        assert_eq!(
+            Err(DdateError::UnknownFormatCode('%')),
+            Format::strict("%%")
+        );
+        assert_eq!(
+            Err(DdateError::UnknownFormatCode('%')),
+            Format::strict("%a %")
+        );
+        assert_eq!(
+            Ok(Format::new(DEFAULT_FORMAT)),
+            Format::strict(DEFAULT_FORMAT)
+        );
+        assert_eq!("", Format::new("%H").render(&ddate));
+    }
+}
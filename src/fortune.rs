@@ -0,0 +1,387 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::DiscordianDateComponents;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Errors arising from fortune files which could not be read or are
+/// malformed.
+#[derive(Debug)]
+pub enum FortuneError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// An entry, numbered from 1, starting on the given line, is empty.
+    EmptyEntry { entry: usize, line: usize },
+    /// An entry, numbered from 1, contains a line which is not UTF-8.
+    InvalidUtf8 { entry: usize, line: usize },
+    /// The file contains no entries.
+    NoEntries,
+}
+
+impl fmt::Display for FortuneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FortuneError::Io(e) => write!(f, "{}", e),
+            FortuneError::EmptyEntry { entry, line } => {
+                write!(f, "entry {} (line {}) is empty", entry, line)
+            }
+            FortuneError::InvalidUtf8 { entry, line } => {
+                write!(f, "entry {} (line {}) is not valid UTF-8", entry, line)
+            }
+            FortuneError::NoEntries => write!(f, "there are no entries"),
+        }
+    }
+}
+
+impl std::error::Error for FortuneError {}
+
+impl From<io::Error> for FortuneError {
+    fn from(e: io::Error) -> Self {
+        FortuneError::Io(e)
+    }
+}
+
+/// How the entries of a fortune file are separated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    /// Entries separated by lines holding only `%`, as read by fortune(1).
+    Separated,
+    /// One entry on each line, ignoring blank lines.
+    Lines,
+}
+
+/// A validated file of fortunes, from which entries are drawn for the `%.`
+/// format code in place of the built-in exclamations.
+///
+/// The file is either in the format of fortune(1), with entries separated by
+/// lines holding only `%`, or holds one entry on each line. Entries are
+/// never all held in memory at once: opening a file reads it through once to
+/// validate and count its entries, and each draw reads it again only as far
+/// as the chosen entry.
+///
+/// Draws are deterministic, so the same date always draws the same entry
+/// from the same file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ddate::{DiscordianDateComponents, FortuneFile, Season};
+///
+/// let fortunes = FortuneFile::open("discordia.fortunes").unwrap();
+/// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+///
+/// println!("{}", fortunes.choose(&ddate, false).unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct FortuneFile {
+    path: PathBuf,
+    layout: Layout,
+    entries: usize,
+    weight: u64,
+}
+
+impl FortuneFile {
+    /// Opens and validates a fortune file.
+    ///
+    /// An entry which is blank, other than after the final `%` of the file,
+    /// or which is not valid UTF-8 is an error naming that entry.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<FortuneFile, FortuneError> {
+        let path = path.as_ref().to_path_buf();
+
+        let mut layout = Layout::Lines;
+        for line in BufReader::new(File::open(&path)?).split(b'\n') {
+            if is_separator(&line?) {
+                layout = Layout::Separated;
+                break;
+            }
+        }
+
+        let mut entries = 0;
+        let mut weight = 0;
+        for entry in Entries::new(BufReader::new(File::open(&path)?), layout) {
+            let entry = entry?;
+            entries += 1;
+            weight += entry.len() as u64;
+        }
+
+        if entries == 0 {
+            return Err(FortuneError::NoEntries);
+        }
+
+        Ok(FortuneFile {
+            path,
+            layout,
+            entries,
+            weight,
+        })
+    }
+
+    /// Returns the number of entries in the file.
+    pub fn len(&self) -> usize {
+        self.entries
+    }
+
+    /// Whether the file has no entries, which is never the case for a file
+    /// which opened successfully.
+    pub fn is_empty(&self) -> bool {
+        self.entries == 0
+    }
+
+    /// Draws the entry for a date, either uniformly or, if `weighted`, in
+    /// proportion to the length of each entry.
+    pub fn choose(
+        &self,
+        date: &DiscordianDateComponents,
+        weighted: bool,
+    ) -> Result<String, FortuneError> {
+        let seed = i64::from(date.yold) * 366 + i64::from(date.erisian_day_number());
+        self.choose_seeded(seed as u64, weighted)
+    }
+
+    /// Draws an entry for an arbitrary seed, either uniformly or, if
+    /// `weighted`, in proportion to the length of each entry.
+    pub fn choose_seeded(&self, seed: u64, weighted: bool) -> Result<String, FortuneError> {
+        let target = if weighted {
+            mix(seed) % self.weight
+        } else {
+            mix(seed) % self.entries as u64
+        };
+
+        let mut position = 0;
+        for entry in Entries::new(BufReader::new(File::open(&self.path)?), self.layout) {
+            let entry = entry?;
+            position += if weighted { entry.len() as u64 } else { 1 };
+
+            if position > target {
+                return Ok(entry);
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "fortune file changed after it was opened",
+        )
+        .into())
+    }
+}
+
+/// Whether a line of a fortune file separates its entries.
+fn is_separator(line: &[u8]) -> bool {
+    line == b"%" || line == b"%\r"
+}
+
+/// Scrambles a seed, so that neighbouring seeds draw unrelated entries.
+fn mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Reads the entries of a fortune file one at a time, validating each.
+struct Entries<R> {
+    lines: io::Split<R>,
+    layout: Layout,
+    line: usize,
+    entry: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Entries<R> {
+    fn new(reader: R, layout: Layout) -> Self {
+        Entries {
+            lines: reader.split(b'\n'),
+            layout,
+            line: 0,
+            entry: 0,
+            done: false,
+        }
+    }
+
+    /// Reads the next line, without its line ending.
+    fn next_line(&mut self) -> Option<io::Result<Vec<u8>>> {
+        let mut line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+        self.line += 1;
+
+        if line.last() == Some(&b'\r') && !is_separator(&line) {
+            line.pop();
+        }
+        Some(Ok(line))
+    }
+
+    fn next_entry(&mut self) -> Result<Option<String>, FortuneError> {
+        match self.layout {
+            Layout::Lines => loop {
+                let line = match self.next_line() {
+                    Some(line) => line?,
+                    None => return Ok(None),
+                };
+                let line = String::from_utf8(line).map_err(|_| FortuneError::InvalidUtf8 {
+                    entry: self.entry + 1,
+                    line: self.line,
+                })?;
+
+                if !line.trim().is_empty() {
+                    self.entry += 1;
+                    return Ok(Some(line));
+                }
+            },
+            Layout::Separated => {
+                let start = self.line + 1;
+                let mut text = String::new();
+                let mut last = true;
+
+                while let Some(line) = self.next_line() {
+                    let line = line?;
+                    if is_separator(&line) {
+                        last = false;
+                        break;
+                    }
+
+                    let line = String::from_utf8(line).map_err(|_| FortuneError::InvalidUtf8 {
+                        entry: self.entry + 1,
+                        line: self.line,
+                    })?;
+                    if !text.is_empty() {
+                        text.push('\n');
+                    }
+                    text.push_str(&line);
+                }
+
+                if !text.trim().is_empty() {
+                    self.entry += 1;
+                    Ok(Some(text))
+                } else if last {
+                    Ok(None)
+                } else {
+                    Err(FortuneError::EmptyEntry {
+                        entry: self.entry + 1,
+                        line: start,
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Entries<R> {
+    type Item = Result<String, FortuneError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let entry = self.next_entry();
+        if !matches!(entry, Ok(Some(_))) {
+            self.done = true;
+        }
+        entry.transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FortuneError, FortuneFile};
+    use crate::{DiscordianDateComponents, Season};
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Writes a fortune file for a test, named for the test.
+    fn fortunes(name: &str, contents: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("ddate-{}-{}.fortunes", name, std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn separated_test() {
+        let path = fortunes(
+            "separated",
+            b"Hail Eris!\n%\nAll Hail Discordia!\nKallisti!\n%\r\nFnord.\r\n%\n",
+        );
+        let file = FortuneFile::open(&path).unwrap();
+        assert_eq!(3, file.len());
+
+        let mut drawn: Vec<String> = (0..64)
+            .map(|seed| file.choose_seeded(seed, false).unwrap())
+            .collect();
+        drawn.sort();
+        drawn.dedup();
+        assert_eq!(
+            vec!["All Hail Discordia!\nKallisti!", "Fnord.", "Hail Eris!"],
+            drawn
+        );
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+        assert_eq!(
+            file.choose(&ddate, true).unwrap(),
+            file.choose(&ddate, true).unwrap()
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn lines_test() {
+        let path = fortunes("lines", b"Hail Eris!\n\nFnord.\n   \nPzat!");
+        let file = FortuneFile::open(&path).unwrap();
+        assert_eq!(3, file.len());
+        assert!((0..16).all(|seed| !file.choose_seeded(seed, true).unwrap().is_empty()));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn weighted_test() {
+        let long = "Fnord. ".repeat(99);
+        let path = fortunes("weighted", format!("Pzat!\n%\n{}\n%\n", long).as_bytes());
+        let file = FortuneFile::open(&path).unwrap();
+
+        let long_draws = (0..1000)
+            .filter(|&seed| file.choose_seeded(seed, true).unwrap().len() > 5)
+            .count();
+        assert!(long_draws > 950, "{}", long_draws);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn malformed_test() {
+        let path = fortunes("empty-entry", b"Hail Eris!\n%\n\n%\nFnord.\n");
+        match FortuneFile::open(&path) {
+            Err(FortuneError::EmptyEntry { entry: 2, line: 3 }) => {}
+            other => panic!("{:?}", other),
+        }
+        fs::remove_file(path).unwrap();
+
+        let path = fortunes("invalid-utf8", b"Hail Eris!\n%\nFnord.\nPzat\xff\n%\n");
+        match FortuneFile::open(&path) {
+            Err(FortuneError::InvalidUtf8 { entry: 2, line: 4 }) => {}
+            other => panic!("{:?}", other),
+        }
+        fs::remove_file(path).unwrap();
+
+        let path = fortunes("no-entries", b"\n  \n");
+        assert!(matches!(
+            FortuneFile::open(&path),
+            Err(FortuneError::NoEntries)
+        ));
+        fs::remove_file(path).unwrap();
+
+        assert!(matches!(
+            FortuneFile::open("/nonexistent/ddate.fortunes"),
+            Err(FortuneError::Io(_))
+        ));
+    }
+}
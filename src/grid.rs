@@ -0,0 +1,443 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Calendar grids of Discordian seasons, for renderers drawing their own.
+//!
+//! A season is laid out in rows of five columns, one for each weekday from
+//! Sweetmorn to Setting Orange. Seasons are 73 days long, so only Chaos
+//! begins on Sweetmorn; the first row of every other season, and the last
+//! row of every season, has empty cells before or after its days.
+//!
+//! St. Tib's Day belongs to no week, so it is given a row of its own in the
+//! Chaos grid. The week it interrupts is split around it: the row before it
+//! ends with the 59th of Chaos in the Prickle-Prickle column, and the row
+//! after it holds only the 60th of Chaos in the Setting Orange column.
+
+use crate::{
+    is_leap_yold, DiscordianDateComponents, DiscordianYear, Holyday, Season, Weekday,
+    HOLYDAY_GLYPH, SEASON_DAYS, ST_TIBS_DAY, WEEK_DAYS,
+};
+use chrono::NaiveDate;
+
+/// A day in a calendar grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DayCell {
+    /// The day of the season, or 59 on St. Tib's Day, as with
+    /// `DiscordianDateComponents`.
+    pub day: u8,
+    /// The Gregorian date of the day, if chrono can represent it.
+    pub gregorian: Option<NaiveDate>,
+    /// The holyday falling on the day, if any.
+    pub holyday: Option<Holyday>,
+    /// Whether the day is St. Tib's Day.
+    pub st_tibs: bool,
+}
+
+impl DayCell {
+    fn new(date: DiscordianDateComponents) -> Self {
+        DayCell {
+            day: date.day,
+            gregorian: date.to_naive_date(),
+            holyday: date.holyday(),
+            st_tibs: date.st_tibs,
+        }
+    }
+}
+
+/// A row of a calendar grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Row {
+    /// Days of a week, indexed by weekday, with `None` for columns before the
+    /// season begins, after it ends, or across St. Tib's Day.
+    Week([Option<DayCell>; 5]),
+    /// St. Tib's Day, which is in no column.
+    Intercalary(DayCell),
+}
+
+/// The calendar grid of a season of a YOLD.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SeasonGrid {
+    /// The YOLD of the season.
+    pub yold: i32,
+    /// The season the grid lays out.
+    pub season: Season,
+    /// The rows of the grid, in calendar order.
+    pub rows: Vec<Row>,
+}
+
+/// Lays out a season of a YOLD as a calendar grid.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::grid::{season_grid, Row};
+/// use ddate::Season;
+///
+/// let grid = season_grid(3183, Season::Discord);
+///
+/// // The Discord of every YOLD begins on Prickle-Prickle...
+/// match grid.rows[0] {
+///     Row::Week(days) => assert_eq!(None, days[2]),
+///     Row::Intercalary(_) => unreachable!(),
+/// }
+/// // ...and ends on Sweetmorn.
+/// assert_eq!(16, grid.rows.len());
+/// ```
+pub fn season_grid(yold: i32, season: Season) -> SeasonGrid {
+    let tibs = season == Season::Chaos && is_leap_yold(yold);
+    let first = season as usize * SEASON_DAYS;
+
+    let mut rows = Vec::new();
+    let mut week = [None; 5];
+
+    for day in 1..=SEASON_DAYS as u8 {
+        let column = (first + day as usize - 1) % WEEK_DAYS;
+        let date = DiscordianDateComponents::from_parts(yold, season, day)
+            .expect("days of the season are in range");
+
+        if tibs && day as usize == ST_TIBS_DAY + 1 {
+            rows.push(Row::Week(week));
+            week = [None; 5];
+
+            let tibs = DiscordianDateComponents::st_tibs_day(yold)
+                .expect("St. Tib's Day is in a leap YOLD");
+            rows.push(Row::Intercalary(DayCell::new(tibs)));
+        }
+
+        week[column] = Some(DayCell::new(date));
+        if column == WEEK_DAYS - 1 {
+            rows.push(Row::Week(week));
+            week = [None; 5];
+        }
+    }
+
+    if week.iter().any(Option::is_some) {
+        rows.push(Row::Week(week));
+    }
+
+    SeasonGrid { yold, season, rows }
+}
+
+/// Lays out every season of a YOLD as calendar grids, in calendar order.
+pub fn yold_grid(yold: i32) -> [SeasonGrid; 5] {
+    Season::ALL.map(|season| season_grid(yold, season))
+}
+
+/// The width of a column of `render_year_calendar`.
+const COLUMN_WIDTH: usize = 4;
+
+/// Renders every season of a YOLD as a plain text calendar for printing,
+/// from its grids. Each season is headed by its name and the abbreviations
+/// of the weekdays, and followed by its holydays; the days of holydays are
+/// marked with `*`, and St. Tib's Day has a line of its own.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::render_year_calendar;
+///
+/// let calendar = render_year_calendar(3183);
+/// let lines: Vec<&str> = calendar.lines().collect();
+///
+/// assert_eq!(
+///     vec![
+///         "YOLD 3183",
+///         "",
+///         "Chaos",
+///         " SM  BT  PD  PP  SO",
+///         "  1   2   3   4   5*",
+///     ],
+///     lines[..5]
+/// );
+/// assert_eq!("* 5 Mungday, 50 Chaoflux", lines[19]);
+/// ```
+pub fn render_year_calendar(yold: i32) -> String {
+    let width = COLUMN_WIDTH * WEEK_DAYS;
+    let mut out = format!("YOLD {}\n", yold);
+
+    for grid in &yold_grid(yold) {
+        let header: String = Weekday::ALL
+            .iter()
+            .map(|weekday| format!("{:>3} ", weekday.abbreviation()))
+            .collect();
+        out.push_str(&format!("\n{}\n{}\n", grid.season, header.trim_end()));
+
+        for row in &grid.rows {
+            let line = match row {
+                Row::Week(days) => days
+                    .iter()
+                    .map(|cell| match cell {
+                        Some(cell) if cell.holyday.is_some() => format!("{:>3}*", cell.day),
+                        Some(cell) => format!("{:>3} ", cell.day),
+                        None => " ".repeat(COLUMN_WIDTH),
+                    })
+                    .collect(),
+                Row::Intercalary(_) => format!("{:^1$}", "St. Tib's Day", width),
+            };
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+
+        let holydays: Vec<String> = Holyday::ALL
+            .iter()
+            .filter(|holyday| holyday.season() == grid.season)
+            .map(|holyday| format!("{} {}", holyday.day(), holyday))
+            .collect();
+        out.push_str(&format!("* {}\n", holydays.join(", ")));
+    }
+
+    out
+}
+
+/// The glyph marking St. Tib's Day in `year_sparkline`.
+const ST_TIBS_GLYPH: char = '\u{25c6}';
+/// The glyph marking an ordinary day in `year_sparkline`.
+const DAY_GLYPH: char = '\u{b7}';
+/// The glyph between seasons in `year_sparkline`.
+const SEASON_SEPARATOR: char = '|';
+
+/// Renders a YOLD as a strip of one character for each day, for an
+/// at-a-glance view of the year such as in a status bar. The legend is:
+///
+/// - `★` for a holyday, as in compact output;
+/// - `◆` for St. Tib's Day;
+/// - `·` for any other day;
+/// - `|` between seasons.
+///
+/// Each season is 73 characters long, and the strip 369, or 370 with St.
+/// Tib's Day, which falls after the 59th of Chaos.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::grid::year_sparkline;
+///
+/// let strip = year_sparkline(3183);
+/// let chaos: String = strip.chars().take(10).collect();
+///
+/// assert_eq!("····★·····", chaos);
+/// assert_eq!(369, strip.chars().count());
+/// ```
+pub fn year_sparkline(yold: i32) -> String {
+    let mut strip = String::new();
+
+    for date in DiscordianYear::new(yold) {
+        if date.day == 1 && date.season != Season::Chaos {
+            strip.push(SEASON_SEPARATOR);
+        }
+        strip.push(if date.st_tibs {
+            ST_TIBS_GLYPH
+        } else if date.holyday().is_some() {
+            HOLYDAY_GLYPH
+        } else {
+            DAY_GLYPH
+        });
+    }
+
+    strip
+}
+
+/// Returns the weekday of each of the 73 days of a season of a YOLD, so
+/// that a renderer can map days to columns in one call. Seasons are not a
+/// whole number of weeks long, so each begins on the weekday after the one
+/// the season before it ended on, three columns to the right of where that
+/// season began. St. Tib's Day belongs to no week, and so is not in the
+/// pattern of Chaos.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::grid::season_weekday_pattern;
+/// use ddate::{Season, Weekday};
+///
+/// let pattern = season_weekday_pattern(3183, Season::TheAftermath);
+///
+/// assert_eq!(73, pattern.len());
+/// assert_eq!(Weekday::Pungenday, pattern[0]);
+/// assert_eq!(Weekday::SettingOrange, pattern[72]);
+/// ```
+pub fn season_weekday_pattern(yold: i32, season: Season) -> Vec<Weekday> {
+    (1..=SEASON_DAYS as u8)
+        .map(|day| {
+            DiscordianDateComponents::from_parts(yold, season, day)
+                .expect("days of the season are in range")
+                .weekday
+                .expect("days of seasons have weekdays")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        render_year_calendar, season_grid, season_weekday_pattern, year_sparkline, yold_grid, Row,
+    };
+    use crate::{Holyday, Season, Weekday};
+    use chrono::NaiveDate;
+
+    /// The days of a row, with 0 for empty cells and St. Tib's Day.
+    fn days(row: &Row) -> Vec<u8> {
+        match row {
+            Row::Week(days) => days
+                .iter()
+                .map(|day| day.map_or(0, |day| day.day))
+                .collect(),
+            Row::Intercalary(_) => vec![0],
+        }
+    }
+
+    #[test]
+    fn chaos_test() {
+        let common = season_grid(3183, Season::Chaos);
+        assert_eq!(15, common.rows.len());
+        assert_eq!(vec![1, 2, 3, 4, 5], days(&common.rows[0]));
+        assert_eq!(vec![56, 57, 58, 59, 60], days(&common.rows[11]));
+        assert_eq!(vec![71, 72, 73, 0, 0], days(&common.rows[14]));
+
+        let leap = season_grid(3182, Season::Chaos);
+        assert_eq!(17, leap.rows.len());
+        assert_eq!(vec![56, 57, 58, 59, 0], days(&leap.rows[11]));
+        match leap.rows[12] {
+            Row::Intercalary(tibs) => {
+                assert!(tibs.st_tibs);
+                assert_eq!(NaiveDate::from_ymd_opt(2016, 2, 29), tibs.gregorian);
+            }
+            Row::Week(_) => panic!("St. Tib's Day is not in its own row"),
+        }
+        assert_eq!(vec![0, 0, 0, 0, 60], days(&leap.rows[13]));
+        assert_eq!(vec![71, 72, 73, 0, 0], days(&leap.rows[16]));
+    }
+
+    #[test]
+    fn yold_test() {
+        let grids = yold_grid(3183);
+        let mut holydays = Vec::new();
+
+        for grid in &grids {
+            let mut count = 0;
+            for row in &grid.rows {
+                if let Row::Week(days) = row {
+                    for (column, day) in days.iter().enumerate() {
+                        if let Some(day) = day {
+                            let date = day.gregorian.unwrap();
+                            assert_eq!(
+                                column,
+                                crate::DiscordianDate::discordian_weekday(&date).unwrap() as usize
+                            );
+                            holydays.extend(day.holyday);
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            assert_eq!(73, count);
+        }
+
+        assert_eq!(Holyday::ALL.to_vec(), holydays);
+        assert_eq!(vec![0, 0, 0, 1, 2], days(&grids[1].rows[0]));
+        assert_eq!(vec![0, 0, 1, 2, 3], days(&grids[4].rows[0]));
+        assert_eq!(vec![71, 72, 73, 0, 0], days(&grids[0].rows[14]));
+    }
+
+    #[test]
+    fn season_weekday_pattern_test() {
+        let chaos = season_weekday_pattern(3182, Season::Chaos);
+        assert_eq!(Weekday::Sweetmorn, chaos[0]);
+        assert_eq!(Weekday::Pungenday, chaos[72]);
+
+        // Discord picks up the day after Chaos leaves off.
+        let discord = season_weekday_pattern(3182, Season::Discord);
+        assert_eq!(Weekday::PricklePrickle, discord[0]);
+        assert_eq!(Weekday::Sweetmorn, discord[72]);
+        assert_ne!(chaos[0], discord[0]);
+        assert_ne!(chaos[72], discord[72]);
+
+        // The pattern agrees with the columns of the grid.
+        for season in Season::ALL {
+            let pattern = season_weekday_pattern(3182, season);
+            assert_eq!(73, pattern.len());
+            for row in &season_grid(3182, season).rows {
+                if let Row::Week(days) = row {
+                    for (column, day) in days.iter().enumerate() {
+                        if let Some(day) = day {
+                            assert_eq!(column, pattern[day.day as usize - 1] as usize);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn year_sparkline_test() {
+        let glyphs = |strip: &str, glyph: char| -> Vec<usize> {
+            strip
+                .chars()
+                .enumerate()
+                .filter(|&(_, c)| c == glyph)
+                .map(|(i, _)| i)
+                .collect()
+        };
+
+        // Each season is 73 days and a separator, with holydays on its 5th
+        // and 50th days.
+        let common = year_sparkline(3183);
+        let holydays: Vec<usize> = (0..5).flat_map(|s| vec![s * 74 + 4, s * 74 + 49]).collect();
+        assert_eq!(369, common.chars().count());
+        assert_eq!(holydays, glyphs(&common, '\u{2605}'));
+        assert_eq!(vec![73, 147, 221, 295], glyphs(&common, '|'));
+        assert!(glyphs(&common, '\u{25c6}').is_empty());
+        assert_eq!(369 - 10 - 4, glyphs(&common, '\u{b7}').len());
+
+        // St. Tib's Day follows the 59th of Chaos, and every later season is
+        // one day further along.
+        let leap = year_sparkline(3182);
+        let holydays: Vec<usize> = (0..5)
+            .flat_map(|s| vec![s * 74 + 4, s * 74 + 49])
+            .map(|i| if i > 59 { i + 1 } else { i })
+            .collect();
+        assert_eq!(370, leap.chars().count());
+        assert_eq!(holydays, glyphs(&leap, '\u{2605}'));
+        assert_eq!(vec![59], glyphs(&leap, '\u{25c6}'));
+        assert_eq!(vec![74, 148, 222, 296], glyphs(&leap, '|'));
+
+        for season in leap.split('|') {
+            assert!(season.chars().count() == 73 || season.chars().count() == 74);
+        }
+    }
+
+    #[test]
+    fn render_year_calendar_test() {
+        let leap = render_year_calendar(3182);
+        let lines: Vec<&str> = leap.lines().collect();
+
+        for season in &Season::ALL {
+            assert_eq!(
+                1,
+                lines.iter().filter(|&&line| line == season.name()).count()
+            );
+        }
+        assert_eq!(
+            vec![" 56  57  58  59", "   St. Tib's Day", "                 60"],
+            lines[15..18]
+        );
+        assert_eq!(1, leap.matches("St. Tib's Day").count());
+        assert_eq!(10, leap.matches('*').count() - 5);
+        assert!(lines.iter().all(|line| line == &line.trim_end()));
+        assert!(lines
+            .iter()
+            .filter(|line| !line.starts_with('*'))
+            .all(|line| line.len() <= 20));
+
+        let common = render_year_calendar(3183);
+        assert!(!common.contains("St. Tib's Day"));
+        assert_eq!(leap.lines().count(), common.lines().count() + 2);
+        assert_eq!(common, render_year_calendar(3183));
+    }
+}
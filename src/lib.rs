@@ -4,7 +4,7 @@
 // large and to the detriment of our heirs and successors. We intend this
 // dedication to be an overt act of relinquishment in perpetuity of all
 // present and future rights to this software under copyright law.
-// 
+//
 // For more information, see the file UNLICENSE at this repository's root.
 
 //! # ddate 0.4.0
@@ -17,8 +17,59 @@
 //! Also included is a `ddate` binary with output similar to the tool of the
 //! same name originally included in `util-linux` (though no features beyond
 //! simple output of the current date).
+//!
+//! ## Panics
+//!
+//! No function or method of this crate panics for any date chrono can
+//! represent, from `NaiveDate::MIN` to `NaiveDate::MAX`, nor for any YOLD
+//! an `i32` can hold; dates beyond chrono's range are `None` instead. The
+//! few exceptions, all of which need input no chrono date can produce, are
+//! listed under the Panics heading of the items concerned.
+
+use chrono::{Datelike, NaiveDate};
+use std::fmt;
+use std::io;
+
+pub mod bulk;
+mod cache;
+pub mod csv;
+mod detect;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod format;
+mod fortune;
+pub mod grid;
+mod locale;
+mod lore;
+mod names;
+mod parse;
+#[cfg(feature = "tz")]
+mod tz;
+mod wrap;
 
-use chrono::Datelike;
+pub use cache::PoeeCache;
+pub use detect::{
+    detect_date, detect_date_with, parse_gregorian, reformat, Calendar, DetectedDate,
+};
+pub use format::{Format, DATE_FORMAT, DEFAULT_FORMAT};
+pub use fortune::{FortuneError, FortuneFile};
+pub use grid::render_year_calendar;
+pub use locale::Locale;
+pub use lore::{
+    reduce_to_life_path, BLESSING_TEMPLATES, CHAOS_ORACLE, CRYSTAL_GUIDE, CURSE_TEMPLATE,
+    DAILY_AFFIRMATIONS, DISCORDIAN_FACTS, EPITAPH_TEMPLATES, FORTUNES, HEADLINES,
+    HOLYDAY_CELEBRATIONS, HOLYDAY_DISHES, HOLYDAY_ERROR_CODES, HOLYDAY_HEADLINES, HOLYDAY_MANTRAS,
+    HOLYDAY_OMENS, HOLYDAY_PROPHECIES, MANTRAS, NUMEROLOGY_KEYWORDS, OBSERVANCES, PENTABARF_TONES,
+    PROPHECY_QUATRAINS, SEASON_ANIMALS, SEASON_APOSTLES, SEASON_CONTEMPLATIONS, SEASON_DISHES,
+    SEASON_ELEMENTS, SEASON_ERROR_CODES, SEASON_HERBS, SEASON_HOROSCOPES, SEASON_LAMENTS,
+    SEASON_TREES, SEASON_WEATHER, WARNING_SIDE_EFFECTS, WEEKDAY_AFFIRMATIONS, WEEKDAY_DRINKS,
+    WEEKDAY_HERBS, WEEKDAY_MODIFIERS, WEEKDAY_TREES, WEEKDAY_WEATHER,
+};
+pub use names::{Names, NamesError};
+pub use parse::{from_permalink, parse_discordian};
+#[cfg(feature = "tz")]
+pub use tz::{today_in_zone, today_in_zone_at, Zone};
+pub use wrap::wrap;
 
 /// The apostolic holydays of the Discordian calendar.
 const APOSTLES: [&str; 5] = ["Mungday", "Mojoday", "Syaday", "Zaraday", "Maladay"];
@@ -40,11 +91,17 @@ const WEEKDAYS: [&str; 5] = [
     "Prickle-Prickle",
     "Setting Orange",
 ];
+/// The abbreviated seasons, as used by `util-linux`.
+const SEASON_ABBREVIATIONS: [&str; 5] = ["Chs", "Dsc", "Cfn", "Bcy", "Afm"];
+/// The abbreviated days of the week, as used by `util-linux`.
+const WEEKDAY_ABBREVIATIONS: [&str; 5] = ["SM", "BT", "PD", "PP", "SO"];
 
 /// The day of the season that an apostolic holyday occurs on.
 const APOSTLE_HOLYDAY: usize = 5;
 /// The day of the year that St. Tib's Day occurs on (in leap years).
 const ST_TIBS_DAY: usize = 59;
+/// The Erisian day number of St. Tib's Day, in every year.
+const ST_TIBS_ERISIAN_DAY: u16 = ST_TIBS_DAY as u16 + 1;
 /// The number of days in each season.
 const SEASON_DAYS: usize = 73;
 /// The day of the season that a seasonal holyday occurs on.
@@ -53,30 +110,511 @@ const SEASON_HOLYDAY: usize = 50;
 const WEEK_DAYS: usize = 5;
 /// The Curse of Greyface occurred in 1166 B.C.E.
 const CURSE_OF_GREYFACE: i32 = 1166;
+/// The lengths of the Gregorian months of a common year.
+const GREGORIAN_MONTHS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
-/// Extends chrono's
-/// [`Datelike`](https://docs.rs/chrono/0.4/chrono/trait.Datelike.html) to
-/// display Discordian calendar dates.
-pub trait DiscordianDate: Datelike {
-    /// Returns a Discordian calendar date string.
+/// The earliest YOLD which `DiscordianDateComponents::to_packed` can pack.
+pub const PACKED_YOLD_MIN: i32 = -(1 << 20);
+/// The latest YOLD which `DiscordianDateComponents::to_packed` can pack.
+pub const PACKED_YOLD_MAX: i32 = (1 << 20) - 1;
+
+/// The maximum length, in bytes, of the short form of a Discordian date as
+/// produced by `to_poee_short`, for any YOLD an `i32` can hold.
+pub const POEE_SHORT_MAX_LEN: usize = 51;
+
+/// The width, in columns, of the Discordian date as produced by
+/// `to_fixed_column`.
+pub const FIXED_COLUMN_WIDTH: usize = 20;
+
+/// How names are abbreviated in compact output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Abbreviation {
+    /// The abbreviations of `util-linux`, such as `PD` and `Afm`.
+    Short,
+    /// The full names, such as `Pungenday` and `The Aftermath`.
+    Full,
+}
+
+/// The order in which the components of a Discordian date are rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DdateStyle {
+    /// From the weekday to the YOLD, as `to_poee` renders them:
+    /// `Pungenday, the 16th day of The Aftermath in the YOLD 3183`.
+    Default,
+    /// From the YOLD to the weekday, for languages and layouts reading the
+    /// other way: `YOLD 3183, The Aftermath, 16th day, Pungenday`.
+    Reverse,
+}
+
+/// The glyph marking a holyday in compact output.
+const HOLYDAY_GLYPH: char = '\u{2605}';
+
+thread_local! {
+    /// The buffer each thread renders dates into for `with_poee_buffered`,
+    /// kept between calls so that its allocation is reused.
+    static POEE_BUFFER: std::cell::RefCell<String> =
+        const { std::cell::RefCell::new(String::new()) };
+}
+
+/// Errors arising from Discordian dates which do not exist or could not be
+/// understood.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DdateError {
+    /// A day of the season outside of 1 to 73.
+    DayOutOfRange(u8),
+    /// St. Tib's Day in a YOLD without one.
+    NoStTibsDay(i32),
+    /// An Erisian day number outside of 1 to 366.
+    ErisianDayOutOfRange(u16),
+    /// An ordinal day outside of the days of a YOLD.
+    OrdinalOutOfRange { yold: i32, ordinal: u16 },
+    /// A YOLD outside of `PACKED_YOLD_MIN` to `PACKED_YOLD_MAX`, which
+    /// cannot be packed.
+    YoldOutOfRange(i32),
+    /// A packed date which does not decode to a date.
+    InvalidPacked(u32),
+    /// Indices, as returned by `to_indices`, which are not those of a date.
+    InvalidIndices((i32, u8, u8, bool)),
+    /// A component of a Gregorian date outside of its range, such as week
+    /// 54 of an ISO week date, or day 367 of an ordinal date.
+    ComponentOutOfRange {
+        component: &'static str,
+        value: u32,
+        max: u32,
+    },
+    /// A `%` followed by a character which is not a format code.
+    UnknownFormatCode(char),
+    /// A Discordian date string which could not be parsed.
+    Parse(String),
+    /// A Gregorian date string which could not be parsed.
+    GregorianParse(String),
+    /// The name of a calendar other than Gregorian or Discordian.
+    UnknownCalendar(String),
+    /// A language tag of a locale which is not supported.
+    UnknownLocale(String),
+    /// The name of a time zone which could not be loaded.
+    UnknownZone(String),
+    /// A date string which is in neither calendar, with the reasons it could
+    /// not be parsed as each.
+    Undetected {
+        gregorian: String,
+        discordian: String,
+    },
+}
+
+impl fmt::Display for DdateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DdateError::DayOutOfRange(day) => {
+                write!(f, "day {} is outside of the 73 days of a season", day)
+            }
+            DdateError::NoStTibsDay(yold) => {
+                write!(f, "there is no St. Tib's Day in YOLD {}", yold)
+            }
+            DdateError::ErisianDayOutOfRange(day) => {
+                write!(f, "Erisian day {} is outside of 1 to 366", day)
+            }
+            DdateError::OrdinalOutOfRange { yold, ordinal } => write!(
+                f,
+                "day {} is outside of the {} days of YOLD {}",
+                ordinal,
+                days_in_yold(*yold),
+                yold
+            ),
+            DdateError::YoldOutOfRange(yold) => write!(
+                f,
+                "YOLD {} is outside of {} to {}",
+                yold, PACKED_YOLD_MIN, PACKED_YOLD_MAX
+            ),
+            DdateError::InvalidPacked(packed) => {
+                write!(f, "{:#010x} is not a packed Discordian date", packed)
+            }
+            DdateError::InvalidIndices(indices) => {
+                write!(f, "{:?} are not the indices of a Discordian date", indices)
+            }
+            DdateError::ComponentOutOfRange {
+                component,
+                value,
+                max,
+            } => write!(f, "{} {} is outside of 1 to {}", component, value, max),
+            DdateError::UnknownFormatCode(code) => write!(f, "unknown format code %{}", code),
+            DdateError::Parse(reason) => write!(f, "could not parse Discordian date: {}", reason),
+            DdateError::GregorianParse(reason) => {
+                write!(f, "could not parse Gregorian date: {}", reason)
+            }
+            DdateError::UnknownCalendar(name) => write!(f, "unknown calendar {:?}", name),
+            DdateError::UnknownLocale(tag) => write!(f, "unknown locale {:?}", tag),
+            DdateError::UnknownZone(name) => write!(f, "unknown time zone {:?}", name),
+            DdateError::Undetected {
+                gregorian,
+                discordian,
+            } => write!(
+                f,
+                "not a Gregorian date ({}), nor a Discordian date ({})",
+                gregorian, discordian
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DdateError {}
+
+/// A season of the Discordian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Season {
+    Chaos,
+    Discord,
+    Confusion,
+    Bureaucracy,
+    TheAftermath,
+}
+
+impl Season {
+    /// Every season, in calendar order.
+    pub const ALL: [Season; 5] = [
+        Season::Chaos,
+        Season::Discord,
+        Season::Confusion,
+        Season::Bureaucracy,
+        Season::TheAftermath,
+    ];
+
+    /// Returns the name of the season.
+    pub fn name(self) -> &'static str {
+        SEASONS[self as usize]
+    }
+
+    /// Returns the abbreviated name of the season.
+    pub fn abbreviation(self) -> &'static str {
+        SEASON_ABBREVIATIONS[self as usize]
+    }
+
+    /// Returns the index of the season from 0 for Chaos to 4 for The
+    /// Aftermath, its position in `Season::ALL` and in tables indexed by
+    /// season, such as `SEASON_ANIMALS`. This is the quotient of the zero-based
+    /// day of the YOLD, skipping St. Tib's Day, by the 73 days of a season.
+    ///
+    /// Days of seasons are instead counted from 1, as in
+    /// `DiscordianDateComponents::day`, so mind the difference when mixing
+    /// the two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{Season, SEASON_ANIMALS};
+    ///
+    /// assert_eq!(0, Season::Chaos.index0());
+    /// assert_eq!("Raven", SEASON_ANIMALS[Season::TheAftermath.index0()]);
+    /// ```
+    pub fn index0(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the season at a zero-based index, as given by `index0`, or
+    /// `None` if the index is 5 or more.
+    pub fn from_index0(index: usize) -> Option<Season> {
+        Season::ALL.get(index).copied()
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A day of the Discordian week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Sweetmorn,
+    Boomtime,
+    Pungenday,
+    PricklePrickle,
+    SettingOrange,
+}
+
+impl Weekday {
+    /// Every weekday, in calendar order.
+    pub const ALL: [Weekday; 5] = [
+        Weekday::Sweetmorn,
+        Weekday::Boomtime,
+        Weekday::Pungenday,
+        Weekday::PricklePrickle,
+        Weekday::SettingOrange,
+    ];
+
+    /// Returns the name of the weekday.
+    pub fn name(self) -> &'static str {
+        WEEKDAYS[self as usize]
+    }
+
+    /// Returns the abbreviated name of the weekday.
+    pub fn abbreviation(self) -> &'static str {
+        WEEKDAY_ABBREVIATIONS[self as usize]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A holyday of the Discordian calendar: the apostolic holyday on the 5th
+/// day and the seasonal holyday on the 50th day of each season.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Holyday {
+    Mungday,
+    Chaoflux,
+    Mojoday,
+    Discoflux,
+    Syaday,
+    Confuflux,
+    Zaraday,
+    Bureflux,
+    Maladay,
+    Afflux,
+}
+
+impl Holyday {
+    /// Every holyday, in calendar order.
+    pub const ALL: [Holyday; 10] = [
+        Holyday::Mungday,
+        Holyday::Chaoflux,
+        Holyday::Mojoday,
+        Holyday::Discoflux,
+        Holyday::Syaday,
+        Holyday::Confuflux,
+        Holyday::Zaraday,
+        Holyday::Bureflux,
+        Holyday::Maladay,
+        Holyday::Afflux,
+    ];
+
+    /// Returns the holyday falling on a day of a season, if any.
+    pub fn on(season: Season, day: u8) -> Option<Holyday> {
+        match day as usize {
+            APOSTLE_HOLYDAY => Some(Holyday::ALL[season as usize * 2]),
+            SEASON_HOLYDAY => Some(Holyday::ALL[season as usize * 2 + 1]),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the holyday.
+    pub fn name(self) -> &'static str {
+        let index = self as usize;
+
+        if self.is_apostolic() {
+            APOSTLES[index / 2]
+        } else {
+            HOLYDAYS[index / 2]
+        }
+    }
+
+    /// Whether this is an apostolic holyday, rather than a seasonal one.
+    pub fn is_apostolic(self) -> bool {
+        matches!(
+            self,
+            Holyday::Mungday
+                | Holyday::Mojoday
+                | Holyday::Syaday
+                | Holyday::Zaraday
+                | Holyday::Maladay
+        )
+    }
+
+    /// Returns the season in which the holyday falls.
+    pub fn season(self) -> Season {
+        Season::ALL[self as usize / 2]
+    }
+
+    /// Returns the day of the season on which the holyday falls.
+    pub fn day(self) -> u8 {
+        if self.is_apostolic() {
+            APOSTLE_HOLYDAY as u8
+        } else {
+            SEASON_HOLYDAY as u8
+        }
+    }
+
+    /// Returns the Gregorian date of the holyday in a YOLD, if chrono can
+    /// represent it.
     ///
     /// # Examples
     ///
     /// ```
     /// use chrono::NaiveDate;
-    /// use ddate::DiscordianDate;
+    /// use ddate::Holyday;
     ///
-    /// let ddate = NaiveDate::from_ymd(2017, 11, 4).to_poee();
+    /// assert_eq!(NaiveDate::from_ymd_opt(2017, 9, 26), Holyday::Bureflux.date_in_year(3183));
+    /// ```
+    pub fn date_in_year(self, yold: i32) -> Option<NaiveDate> {
+        DiscordianDateComponents::from_parts(yold, self.season(), self.day())
+            .ok()?
+            .to_naive_date()
+    }
+
+    /// Returns a few ideas for celebrating the holyday, from
+    /// `HOLYDAY_CELEBRATIONS`.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!("Pungenday, the 16th day of The Aftermath in the YOLD 3183", ddate);
     /// ```
-    fn to_poee(&self) -> String {
-        let day = self.ordinal0() as usize;
-        let leap = self.year() % 4 == 0 && self.year() % 100 != 0 || self.year() % 400 == 0;
-        let year = self.year() + CURSE_OF_GREYFACE;
+    /// use ddate::Holyday;
+    ///
+    /// assert_eq!(
+    ///     "Eat a hot dog, without a bun, in honour of Hung Mung",
+    ///     Holyday::Mungday.celebration_ideas()[0]
+    /// );
+    /// ```
+    pub fn celebration_ideas(self) -> &'static [&'static str] {
+        HOLYDAY_CELEBRATIONS[self as usize]
+    }
+
+    /// Returns the number of days from the holyday to another in the same
+    /// YOLD, counting St. Tib's Day where it falls between them. The span is
+    /// negative if the other holyday comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::Holyday;
+    ///
+    /// assert_eq!(45, Holyday::Mungday.span_to(Holyday::Chaoflux, 3183));
+    /// assert_eq!(-45, Holyday::Chaoflux.span_to(Holyday::Mungday, 3183));
+    /// ```
+    pub fn span_to(self, holyday: Holyday, yold: i32) -> i64 {
+        holyday.day_of_year(yold) - self.day_of_year(yold)
+    }
+
+    /// Returns the one-based day of the YOLD on which the holyday falls.
+    fn day_of_year(self, yold: i32) -> i64 {
+        let date = DiscordianDateComponents::from_parts(yold, self.season(), self.day())
+            .expect("holydays fall within their seasons");
+
+        i64::from(date.day_of_year())
+    }
+}
+
+impl std::str::FromStr for Holyday {
+    type Err = DdateError;
+
+    /// Parses the name of a holyday, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Holyday::ALL
+            .iter()
+            .find(|holyday| holyday.name().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| DdateError::Parse(format!("no such holyday {:?}", s)))
+    }
+}
+
+impl fmt::Display for Holyday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The components of a Discordian calendar date.
+///
+/// St. Tib's Day belongs to no season and no week; it is represented as
+/// falling after the 59th day of Chaos, with `st_tibs` set and no weekday,
+/// so that the derived ordering remains chronological.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DiscordianDateComponents {
+    /// The Year of Our Lady of Discord.
+    pub yold: i32,
+    /// The season (Chaos on St. Tib's Day).
+    pub season: Season,
+    /// The day of the season, from 1 to 73 (59 on St. Tib's Day).
+    pub day: u8,
+    /// Whether this is St. Tib's Day.
+    pub st_tibs: bool,
+    /// The day of the week (none on St. Tib's Day).
+    pub weekday: Option<Weekday>,
+}
+
+/// The components of a Discordian calendar date as plain integers, laid out
+/// as a C struct, for passing across a foreign function interface.
+///
+/// Every field but `year` counts from 1, so that 0 can mean none: on St.
+/// Tib's Day `season`, `day`, and `weekday` are 0, and `is_st_tibs` is 1.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiscordianDateC {
+    /// The Year of Our Lady of Discord.
+    pub year: i32,
+    /// The season, from 1 for Chaos to 5 for The Aftermath, or 0 on St.
+    /// Tib's Day.
+    pub season: u8,
+    /// The day of the season, from 1 to 73, or 0 on St. Tib's Day.
+    pub day: u8,
+    /// The day of the week, from 1 for Sweetmorn to 5 for Setting Orange,
+    /// or 0 on St. Tib's Day.
+    pub weekday: u8,
+    /// The holyday, from 1 for Mungday to 10 for Afflux, or 0 if none.
+    pub holyday: u8,
+    /// 1 on St. Tib's Day, and 0 otherwise.
+    pub is_st_tibs: u8,
+}
+
+/// The components of a Discordian calendar date, with St. Tib's Day given as
+/// in no season, rather than as after the 59th day of Chaos.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiscordianDateOptional {
+    /// The day of the week (none on St. Tib's Day).
+    pub weekday: Option<Weekday>,
+    /// The day of the season, from 1 to 73 (none on St. Tib's Day).
+    pub day_of_season: Option<u8>,
+    /// The season (none on St. Tib's Day).
+    pub season: Option<Season>,
+    /// The Year of Our Lady of Discord.
+    pub yold: i32,
+    /// Whether this is St. Tib's Day.
+    pub st_tibs: bool,
+}
+
+/// The values derived on the way from a Gregorian date to its Discordian
+/// date, for testing and teaching; see `DiscordianDate::debug_breakdown`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Breakdown {
+    /// The day of the Gregorian year, from 1 to 366.
+    pub gregorian_ordinal: u32,
+    /// Whether the Gregorian year is a leap year, with a St. Tib's Day.
+    pub leap: bool,
+    /// The day of the YOLD from 0 to 364, not counting St. Tib's Day (none
+    /// on St. Tib's Day).
+    pub day_offset: Option<u16>,
+    /// The index of the season, from 0 for Chaos to 4 for The Aftermath.
+    pub season_index: u8,
+    /// The index of the day of the week, from 0 for Sweetmorn to 4 for
+    /// Setting Orange (none on St. Tib's Day).
+    pub weekday_index: Option<u8>,
+    /// The day of the season, from 1 to 73, or 59 on St. Tib's Day.
+    pub day_of_season: u8,
+    /// Whether the day is a holyday.
+    pub is_holyday: bool,
+    /// Whether the day is St. Tib's Day.
+    pub is_st_tibs: bool,
+}
+
+impl DiscordianDateComponents {
+    /// Computes the components for a Gregorian year and zero-based ordinal
+    /// day of that year.
+    fn new(year: i32, day: usize) -> Self {
+        let leap = is_leap_year(year.into());
+        let yold = year
+            .checked_add(CURSE_OF_GREYFACE)
+            .expect("the YOLD of the year overflows an i32");
 
         if leap && day == ST_TIBS_DAY {
-            return format!("St. Tib's Day, in the YOLD {}", year);
+            return DiscordianDateComponents {
+                yold,
+                season: Season::Chaos,
+                day: ST_TIBS_DAY as u8,
+                st_tibs: true,
+                weekday: None,
+            };
         }
 
         let day_offset = if leap && day > ST_TIBS_DAY {
@@ -85,116 +623,7427 @@ pub trait DiscordianDate: Datelike {
             day
         };
 
-        let day_of_season = day_offset % SEASON_DAYS + 1;
+        // Only a `Datelike` other than chrono's can give a day past the end
+        // of its year; it falls on the last day of the year instead.
+        debug_assert!(
+            day_offset < SEASON_DAYS * 5,
+            "day {} is past the end of year {}",
+            day,
+            year
+        );
+        let season = Season::from_index0(day_offset / SEASON_DAYS);
+        let (season, day) = match season {
+            Some(season) => (season, (day_offset % SEASON_DAYS + 1) as u8),
+            None => (Season::TheAftermath, SEASON_DAYS as u8),
+        };
+        let day_offset = season as usize * SEASON_DAYS + day as usize - 1;
+
+        DiscordianDateComponents {
+            yold,
+            season,
+            day,
+            st_tibs: false,
+            weekday: Weekday::ALL.get(day_offset % WEEK_DAYS).copied(),
+        }
+    }
 
-        let season = SEASONS[day_offset / SEASON_DAYS];
-        let weekday = WEEKDAYS[day_offset % WEEK_DAYS];
+    /// Computes the components for a Gregorian year and zero-based ordinal
+    /// day of that year, counting St. Tib's Day as the 60th of Chaos, an
+    /// ordinary day; see `DiscordianDate::to_poee_styled_with`.
+    fn linear(year: i32, day: usize) -> Self {
+        let regular = DiscordianDateComponents::new(year, day);
+        if !is_leap_year(year.into()) || day < ST_TIBS_DAY {
+            return regular;
+        }
 
-        let holiday = if day_of_season == APOSTLE_HOLYDAY {
-            format!("\nCelebrate {}", APOSTLES[day_offset / SEASON_DAYS])
-        } else if day_of_season == SEASON_HOLYDAY {
-            format!("\nCelebrate {}", HOLYDAYS[day_offset / SEASON_DAYS])
+        let weekday = Some(Weekday::ALL[day % WEEK_DAYS]);
+        if day <= SEASON_DAYS {
+            DiscordianDateComponents {
+                season: Season::Chaos,
+                day: day as u8 + 1,
+                st_tibs: false,
+                weekday,
+                ..regular
+            }
         } else {
-            String::with_capacity(0)
-        };
+            DiscordianDateComponents { weekday, ..regular }
+        }
+    }
 
-        format!(
-            "{}, the {} day of {} in the YOLD {}{}",
-            weekday,
-            ordinalize(day_of_season),
+    /// Creates the components for a day of a season in a YOLD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season, Weekday};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!(Some(Weekday::Pungenday), ddate.weekday);
+    /// ```
+    pub fn from_parts(yold: i32, season: Season, day: u8) -> Result<Self, DdateError> {
+        if day == 0 || day as usize > SEASON_DAYS {
+            return Err(DdateError::DayOutOfRange(day));
+        }
+
+        let day_offset = season as usize * SEASON_DAYS + day as usize - 1;
+
+        Ok(DiscordianDateComponents {
+            yold,
             season,
-            year,
-            holiday
-        )
+            day,
+            st_tibs: false,
+            weekday: Some(Weekday::ALL[day_offset % WEEK_DAYS]),
+        })
     }
-}
-
-impl<T: Datelike> DiscordianDate for T {}
 
-/// A helper function to ordinalize a numeral.
-fn ordinalize(num: usize) -> String {
-    let s = num.to_string();
+    /// Creates the components for St. Tib's Day in a YOLD, if it has one.
+    pub fn st_tibs_day(yold: i32) -> Result<Self, DdateError> {
+        if !is_leap_yold(yold) {
+            return Err(DdateError::NoStTibsDay(yold));
+        }
 
-    let suffix = if s.ends_with('1') && !s.ends_with("11") {
-        "st"
-    } else if s.ends_with('2') && !s.ends_with("12") {
-        "nd"
-    } else if s.ends_with('3') && !s.ends_with("13") {
-        "rd"
-    } else {
-        "th"
-    };
+        Ok(DiscordianDateComponents {
+            yold,
+            season: Season::Chaos,
+            day: ST_TIBS_DAY as u8,
+            st_tibs: true,
+            weekday: None,
+        })
+    }
 
-    s + suffix
-}
+    /// Returns the Erisian day number of this date; see `from_erisian_day`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::Chaos, 60).unwrap();
+    ///
+    /// assert_eq!(61, ddate.erisian_day_number());
+    /// ```
+    pub fn erisian_day_number(&self) -> u16 {
+        if self.st_tibs {
+            return ST_TIBS_ERISIAN_DAY;
+        }
 
+        let day = self.season as u16 * SEASON_DAYS as u16 + u16::from(self.day);
+        if day < ST_TIBS_ERISIAN_DAY {
+            day
+        } else {
+            day + 1
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::DiscordianDate;
-    use chrono::{TimeZone, Utc};
+    /// Returns this date packed into an integer which sorts in calendar
+    /// order, for indexing and storage.
+    ///
+    /// The key is the YOLD times 1000, plus the season times 100, plus the
+    /// day of the season. St. Tib's Day packs as the 60th of Chaos, and the
+    /// days of Chaos from the 60th onwards pack one higher than their day, so
+    /// that the 73rd of Chaos packs as 74.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!(3_183_416, ddate.sort_key());
+    /// assert_eq!(3_182_060, DiscordianDateComponents::st_tibs_day(3182).unwrap().sort_key());
+    /// ```
+    pub fn sort_key(&self) -> i64 {
+        let day = if self.st_tibs {
+            ST_TIBS_ERISIAN_DAY
+        } else if self.season == Season::Chaos && self.day as usize > ST_TIBS_DAY {
+            u16::from(self.day) + 1
+        } else {
+            u16::from(self.day)
+        };
 
-    #[test]
-    fn day_one_test() {
-        assert_eq!(
-            "Sweetmorn, the 1st day of Chaos in the YOLD 0",
-            Utc.ymd(-1166, 1, 1).to_poee()
-        );
+        i64::from(self.yold) * 1000 + self.season as i64 * 100 + i64::from(day)
     }
 
-    #[test]
-    fn ante_tibs_test() {
-        assert_eq!(
-            "Prickle-Prickle, the 59th day of Chaos in the YOLD 3166",
-            Utc.ymd(2000, 2, 28).to_poee()
-        );
+    /// Returns these components as an `i64` for storage, which sorts in
+    /// calendar order and can be read at a glance: the YOLD times 1000, plus
+    /// the ordinal day of the YOLD, as given to `from_yold_ordinal`. St.
+    /// Tib's Day is ordinal 60, and the ordinal, being at most 366, never
+    /// reaches into the YOLD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!(3_183_308, ddate.to_i64());
+    /// assert_eq!(Some(ddate), DiscordianDateComponents::from_i64(3_183_308));
+    /// ```
+    pub fn to_i64(&self) -> i64 {
+        i64::from(self.yold) * 1000 + i64::from(self.day_of_year())
     }
 
-    #[test]
-    fn tibs_test() {
-        assert_eq!(
-            "St. Tib's Day, in the YOLD 3166",
-            Utc.ymd(2000, 2, 29).to_poee()
-        );
+    /// Creates components from an `i64` given by `to_i64`, or returns `None`
+    /// if it is not one: if its YOLD does not fit in an `i32`, or its
+    /// ordinal is not a day of that YOLD.
+    pub fn from_i64(id: i64) -> Option<Self> {
+        let yold = id.div_euclid(1000);
+        let ordinal = id.rem_euclid(1000) as u16;
+
+        if yold < i64::from(i32::MIN) || yold > i64::from(i32::MAX) {
+            return None;
+        }
+        from_yold_ordinal(yold as i32, ordinal).ok()
     }
 
-    #[test]
-    fn post_tibs_test() {
-        assert_eq!(
-            "Setting Orange, the 60th day of Chaos in the YOLD 3166",
-            Utc.ymd(2000, 3, 1).to_poee()
-        );
+    /// Returns the one-based day of the YOLD, counting St. Tib's Day; see
+    /// `from_yold_ordinal`.
+    fn day_of_year(&self) -> u16 {
+        let day = self.erisian_day_number();
+
+        if day > ST_TIBS_ERISIAN_DAY && !is_leap_yold(self.yold) {
+            day - 1
+        } else {
+            day
+        }
     }
 
-    #[test]
-    fn ante_anti_tibs_test() {
-        assert_eq!(
-            "Prickle-Prickle, the 59th day of Chaos in the YOLD 2232",
-            Utc.ymd(1066, 2, 28).to_poee()
-        );
+    /// Returns the number of days in the YOLD.
+    fn days_in_year(&self) -> u16 {
+        days_in_yold(self.yold)
     }
 
-    #[test]
-    fn post_anti_tibs_test() {
-        assert_eq!(
-            "Setting Orange, the 60th day of Chaos in the YOLD 2232",
-            Utc.ymd(1066, 3, 1).to_poee()
-        );
+    /// Returns the holyday falling on this date, if any.
+    pub fn holyday(&self) -> Option<Holyday> {
+        if self.st_tibs {
+            None
+        } else {
+            Holyday::on(self.season, self.day)
+        }
     }
 
-    #[test]
-    fn holy_test() {
-        assert_eq!(
-            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux",
-            Utc.ymd(2017, 9, 26).to_poee()
-        );
+    /// Returns the Gregorian date these components describe, if chrono can
+    /// represent it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDateComponents;
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2000, 2, 29),
+    ///     DiscordianDateComponents::st_tibs_day(3166).unwrap().to_naive_date()
+    /// );
+    /// ```
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        let year = self.yold.checked_sub(CURSE_OF_GREYFACE)?;
+
+        let day = if self.st_tibs {
+            ST_TIBS_DAY
+        } else {
+            if self.day == 0 || self.day as usize > SEASON_DAYS {
+                return None;
+            }
+            let day_offset = self.season as usize * SEASON_DAYS + self.day as usize - 1;
+            if is_leap_year(year.into()) && day_offset >= ST_TIBS_DAY {
+                day_offset + 1
+            } else {
+                day_offset
+            }
+        };
+
+        NaiveDate::from_yo_opt(year, day as u32 + 1)
     }
 
-    #[test]
-    fn apos_test() {
-        assert_eq!(
-            "Boomtime, the 5th day of The Aftermath in the YOLD 3183\nCelebrate Maladay",
-            Utc.ymd(2017, 10, 24).to_poee()
-        );
+    /// Returns the Gregorian month and day of these components, which are
+    /// the same in every YOLD, and so need no `NaiveDate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the day is past the end of the YOLD, which is only possible
+    /// for components built field by field rather than by this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::DiscordianDateComponents;
+    ///
+    /// assert_eq!((2, 29), DiscordianDateComponents::st_tibs_day(3182).unwrap().gregorian_md());
+    /// ```
+    pub fn gregorian_md(&self) -> (u32, u32) {
+        if self.st_tibs {
+            return (2, 29);
+        }
+
+        let mut day = (self.season as usize * SEASON_DAYS + self.day as usize) as u32;
+        for (month, &length) in GREGORIAN_MONTHS.iter().enumerate() {
+            if day <= length {
+                return (month as u32 + 1, day);
+            }
+            day -= length;
+        }
+        unreachable!("seasons fit within a common year")
+    }
+
+    /// Packs these components into a `u32`, for dense storage.
+    ///
+    /// The layout is stable, from the most significant bit:
+    ///
+    /// | Bits     | Field                                                  |
+    /// |----------|--------------------------------------------------------|
+    /// | 31 to 11 | The YOLD, as a 21-bit two's complement integer         |
+    /// | 10 to 8  | The season, from 0 for Chaos to 4 for The Aftermath    |
+    /// | 7 to 1   | The day of the season, from 1 to 73 (59 on St. Tib's)  |
+    /// | 0        | Whether this is St. Tib's Day                          |
+    ///
+    /// YOLDs outside of `PACKED_YOLD_MIN` to `PACKED_YOLD_MAX` are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!(Ok(3183 << 11 | 4 << 8 | 16 << 1), ddate.to_packed());
+    /// assert_eq!(Ok(ddate), DiscordianDateComponents::from_packed(3183 << 11 | 4 << 8 | 16 << 1));
+    /// ```
+    pub fn to_packed(&self) -> Result<u32, DdateError> {
+        if self.yold < PACKED_YOLD_MIN || self.yold > PACKED_YOLD_MAX {
+            return Err(DdateError::YoldOutOfRange(self.yold));
+        }
+
+        Ok((self.yold as u32) << 11
+            | (self.season as u32) << 8
+            | u32::from(self.day) << 1
+            | self.st_tibs as u32)
+    }
+
+    /// Unpacks components packed by `to_packed`, validating that they are a
+    /// date: the season and day must be in range, and St. Tib's Day must be
+    /// the 59th of Chaos in a YOLD which has one.
+    pub fn from_packed(packed: u32) -> Result<Self, DdateError> {
+        // Shifting the signed value sign-extends the YOLD.
+        let yold = packed as i32 >> 11;
+        let season = (packed >> 8 & 0b111) as usize;
+        let day = (packed >> 1 & 0b111_1111) as u8;
+        let invalid = || DdateError::InvalidPacked(packed);
+
+        if packed & 1 == 1 {
+            if season != Season::Chaos as usize || day as usize != ST_TIBS_DAY {
+                return Err(invalid());
+            }
+            DiscordianDateComponents::st_tibs_day(yold).map_err(|_| invalid())
+        } else {
+            let season = *Season::ALL.get(season).ok_or_else(invalid)?;
+            DiscordianDateComponents::from_parts(yold, season, day).map_err(|_| invalid())
+        }
+    }
+
+    /// Returns these components as a tuple of the YOLD, the season from 0 for
+    /// Chaos to 4 for The Aftermath, the day of the season from 1 to 73 (59
+    /// on St. Tib's Day), and whether this is St. Tib's Day, for wire formats
+    /// which would rather not parse strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!((3183, 4, 16, false), ddate.to_indices());
+    /// assert_eq!(Ok(ddate), DiscordianDateComponents::from_indices((3183, 4, 16, false)));
+    /// ```
+    pub fn to_indices(&self) -> (i32, u8, u8, bool) {
+        (self.yold, self.season as u8, self.day, self.st_tibs)
+    }
+
+    /// Returns these components as a `DiscordianDateC`, for C and other
+    /// languages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateC, DiscordianDateComponents, Season};
+    ///
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DiscordianDateC { year: 3183, season: 5, day: 16, weekday: 3, holyday: 0, is_st_tibs: 0 },
+    ///     ddate.to_ddate_c()
+    /// );
+    /// ```
+    pub fn to_ddate_c(&self) -> DiscordianDateC {
+        if self.st_tibs {
+            return DiscordianDateC {
+                year: self.yold,
+                season: 0,
+                day: 0,
+                weekday: 0,
+                holyday: 0,
+                is_st_tibs: 1,
+            };
+        }
+
+        DiscordianDateC {
+            year: self.yold,
+            season: self.season as u8 + 1,
+            day: self.day,
+            weekday: self.weekday.map_or(0, |weekday| weekday as u8 + 1),
+            holyday: self.holyday().map_or(0, |holyday| holyday as u8 + 1),
+            is_st_tibs: 0,
+        }
+    }
+
+    /// Returns these components as a `DiscordianDateOptional`, with no
+    /// season, day, or weekday on St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::DiscordianDateComponents;
+    ///
+    /// let tibs = DiscordianDateComponents::st_tibs_day(3182).unwrap().to_discordian_optional();
+    ///
+    /// assert_eq!((None, None, None), (tibs.season, tibs.day_of_season, tibs.weekday));
+    /// ```
+    pub fn to_discordian_optional(&self) -> DiscordianDateOptional {
+        DiscordianDateOptional {
+            weekday: self.weekday,
+            day_of_season: Some(self.day).filter(|_| !self.st_tibs),
+            season: Some(self.season).filter(|_| !self.st_tibs),
+            yold: self.yold,
+            st_tibs: self.st_tibs,
+        }
+    }
+
+    /// Creates components from indices returned by `to_indices`, validating
+    /// them as `from_packed` does.
+    pub fn from_indices(indices: (i32, u8, u8, bool)) -> Result<Self, DdateError> {
+        let (yold, season, day, st_tibs) = indices;
+        let invalid = || DdateError::InvalidIndices(indices);
+
+        if st_tibs {
+            if season != Season::Chaos as u8 || day as usize != ST_TIBS_DAY {
+                return Err(invalid());
+            }
+            DiscordianDateComponents::st_tibs_day(yold).map_err(|_| invalid())
+        } else {
+            let season = Season::from_index0(season.into()).ok_or_else(invalid)?;
+            DiscordianDateComponents::from_parts(yold, season, day).map_err(|_| invalid())
+        }
+    }
+}
+
+impl<D: Datelike> From<&D> for DiscordianDateComponents {
+    fn from(date: &D) -> Self {
+        DiscordianDateComponents::new(date.year(), date.ordinal0() as usize)
+    }
+}
+
+/// Extends chrono's
+/// [`Datelike`](https://docs.rs/chrono/0.4/chrono/trait.Datelike.html) to
+/// display Discordian calendar dates.
+pub trait DiscordianDate: Datelike {
+    /// Returns a Discordian calendar date string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee();
+    ///
+    /// assert_eq!("Pungenday, the 16th day of The Aftermath in the YOLD 3183", ddate);
+    /// ```
+    fn to_poee(&self) -> String {
+        poee(&self.discordian_components(), None, &Names::default())
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, which is
+    /// guaranteed to be ASCII; see `Names::to_poee_ascii_only` for rendering
+    /// names which may not be.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_ascii_only();
+    ///
+    /// assert_eq!("Pungenday, the 16th day of The Aftermath in the YOLD 3183", ddate);
+    /// ```
+    fn to_poee_ascii_only(&self) -> String {
+        Names::default().to_poee_ascii_only(&self.discordian_components())
+    }
+
+    /// Returns a Discordian calendar date string with the Gregorian weekday
+    /// noted alongside the Discordian one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().with_gregorian_weekday();
+    ///
+    /// assert_eq!("Pungenday (Sat), the 16th day of The Aftermath in the YOLD 3183", ddate);
+    /// ```
+    fn with_gregorian_weekday(&self) -> String {
+        poee(
+            &self.discordian_components(),
+            Some(self.weekday()),
+            &Names::default(),
+        )
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, with the
+    /// Gregorian date after it in parentheses, in ISO 8601 form, for
+    /// annotated lists. A holyday to celebrate stays on the line after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_annotated();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183 (2017-11-04)",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_annotated(&self) -> String {
+        let mut poee = self.to_poee();
+        // Years are written as chrono writes them, with a sign and at least
+        // four digits outside of 0 to 9999.
+        let year = self.year();
+        let year = if (0..=9999).contains(&year) {
+            format!("{:04}", year)
+        } else {
+            format!("{:+05}", year)
+        };
+        let gregorian = format!(" ({}-{:02}-{:02})", year, self.month(), self.day());
+
+        let end = poee.find('\n').unwrap_or(poee.len());
+        poee.insert_str(end, &gregorian);
+        poee
+    }
+
+    /// Returns the components of the Discordian calendar date.
+    ///
+    /// # Panics
+    ///
+    /// Panics for a `Datelike` type other than chrono's whose year is so
+    /// late that its YOLD overflows an `i32`, or, in debug builds only, whose
+    /// `ordinal0` is past the end of its year; release builds take such a
+    /// day as the last of the year. Every other method of this trait panics
+    /// likewise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Season, Weekday};
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().discordian_components();
+    ///
+    /// assert_eq!(3183, ddate.yold);
+    /// assert_eq!(Season::TheAftermath, ddate.season);
+    /// assert_eq!(16, ddate.day);
+    /// assert_eq!(Some(Weekday::Pungenday), ddate.weekday);
+    /// ```
+    fn discordian_components(&self) -> DiscordianDateComponents {
+        DiscordianDateComponents::from(self)
+    }
+
+    /// Returns the tree ruling the date: the sacred tree of its season, or
+    /// the Golden Apple Tree on St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("Yew", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().ruling_tree());
+    /// ```
+    fn ruling_tree(&self) -> &'static str {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            lore::ST_TIBS_TREE
+        } else {
+            date.season.sacred_tree()
+        }
+    }
+
+    /// Returns a Discordian calendar date string followed by the trees of
+    /// the season and weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_tree();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nUnder the Yew and the Alder",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_discordian_tree(&self) -> String {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(weekday) => format!(
+                "{}\nUnder the {} and the {}",
+                self.to_poee(),
+                self.ruling_tree(),
+                weekday.tree()
+            ),
+            None => format!("{}\nUnder {}", self.to_poee(), self.ruling_tree()),
+        }
+    }
+
+    /// Returns the number of days left in the YOLD after this date, down to 0
+    /// on the 73rd of The Aftermath, for year-end countdowns. St. Tib's Day
+    /// is no day of the Discordian year, so it is not counted, and on that
+    /// day the days from the 60th of Chaos on are left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(57, NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().days_left_in_yold());
+    /// assert_eq!(364, NaiveDate::from_ymd_opt(2016, 1, 1).unwrap().days_left_in_yold());
+    /// ```
+    fn days_left_in_yold(&self) -> u16 {
+        let date = self.discordian_components();
+        let day = date.season as usize * SEASON_DAYS + date.day as usize;
+
+        // St. Tib's Day falls after the 59th of Chaos, so that day is past.
+        (Season::ALL.len() * SEASON_DAYS - day) as u16
+    }
+
+    /// Returns the number of holydays, apostolic and seasonal, remaining in
+    /// the year after this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(1, NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().holydays_remaining());
+    /// ```
+    fn holydays_remaining(&self) -> u8 {
+        let date = self.discordian_components();
+
+        Season::ALL
+            .iter()
+            .flat_map(|&season| {
+                [APOSTLE_HOLYDAY, SEASON_HOLYDAY]
+                    .iter()
+                    .map(move |&day| (season, day as u8))
+            })
+            .filter(|&holyday| holyday > (date.season, date.day))
+            .count() as u8
+    }
+
+    /// Returns the herb ruling the date: the sacred herb of its season, or
+    /// Mandrake on St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("Wormwood", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().ruling_herb());
+    /// ```
+    fn ruling_herb(&self) -> &'static str {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            lore::ST_TIBS_HERB
+        } else {
+            date.season.herb()
+        }
+    }
+
+    /// Returns a Discordian calendar date string followed by the herbs of
+    /// the season and weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_herb();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nHerbs: Wormwood and Peppermint",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_discordian_herb(&self) -> String {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(weekday) => format!(
+                "{}\nHerbs: {} and {}",
+                self.to_poee(),
+                self.ruling_herb(),
+                weekday.herb()
+            ),
+            None => format!("{}\nHerb: {}", self.to_poee(), self.ruling_herb()),
+        }
+    }
+
+    /// Returns the holyday falling on this date, if any.
+    fn holyday(&self) -> Option<Holyday> {
+        self.discordian_components().holyday()
+    }
+
+    /// Whether this date is a holyday.
+    fn is_holyday(&self) -> bool {
+        self.holyday().is_some()
+    }
+
+    /// Whether this date is the Discordian New Year, the 1st of Chaos, which
+    /// is always January 1st.
+    fn is_discordian_new_year(&self) -> bool {
+        let date = self.discordian_components();
+
+        date.season == Season::Chaos && date.day == 1
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, wishing
+    /// a happy new year on the Discordian New Year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Sweetmorn, the 1st day of Chaos in the YOLD 3183\nHail Eris! Happy Discordian New Year!",
+    ///     NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().to_poee_with_new_year()
+    /// );
+    /// ```
+    fn to_poee_with_new_year(&self) -> String {
+        if self.is_discordian_new_year() {
+            format!("{}\nHail Eris! Happy Discordian New Year!", self.to_poee())
+        } else {
+            self.to_poee()
+        }
+    }
+
+    /// Whether this is an aligned day, on which the weekday is in the same
+    /// place in the week as the season is in the YOLD: a Sweetmorn in Chaos,
+    /// a Boomtime in Discord, a Pungenday in Confusion, a Prickle-Prickle in
+    /// Bureaucracy, or a Setting Orange in The Aftermath. St. Tib's Day,
+    /// which has no weekday, is never aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2017, 10, 22).unwrap().is_aligned_day());
+    /// assert!(!NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().is_aligned_day());
+    /// ```
+    fn is_aligned_day(&self) -> bool {
+        let date = self.discordian_components();
+
+        date.weekday
+            .is_some_and(|weekday| weekday as usize == date.season as usize)
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, flagging
+    /// an aligned day; see `is_aligned_day`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Setting Orange, the 3rd day of The Aftermath in the YOLD 3183\n\
+    ///      Setting Orange aligns with The Aftermath",
+    ///     NaiveDate::from_ymd_opt(2017, 10, 22).unwrap().to_poee_with_alignment()
+    /// );
+    /// ```
+    fn to_poee_with_alignment(&self) -> String {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(weekday) if self.is_aligned_day() => {
+                format!(
+                    "{}\n{} aligns with {}",
+                    self.to_poee(),
+                    weekday,
+                    date.season
+                )
+            }
+            _ => self.to_poee(),
+        }
+    }
+
+    /// Returns the day of the season, from 1 to 73, or `None` on St. Tib's
+    /// Day, which belongs to no season; see `day_of_season0` for indexing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(Some(16), NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().day_of_season());
+    /// assert_eq!(None, NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().day_of_season());
+    /// ```
+    fn day_of_season(&self) -> Option<u8> {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            None
+        } else {
+            Some(date.day)
+        }
+    }
+
+    /// Returns the zero-based day of the season, from 0 to 72, or `None` on
+    /// St. Tib's Day, for indexing tables of the days of a season such as
+    /// `CHAOS_ORACLE`. This is always one less than `day_of_season`, as
+    /// `Season::index0` is for seasons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, CHAOS_ORACLE};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap();
+    ///
+    /// assert_eq!(Some(0), date.day_of_season0());
+    /// assert_eq!(CHAOS_ORACLE[0], CHAOS_ORACLE[date.day_of_season0().unwrap() as usize]);
+    /// ```
+    fn day_of_season0(&self) -> Option<u8> {
+        self.day_of_season().map(|day| day - 1)
+    }
+
+    /// Returns the day of the season counted back from its end, 1 on the
+    /// 73rd and 73 on the 1st, or `None` on St. Tib's Day, such as to find
+    /// the days before a season ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(Some(58), NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().day_from_season_end());
+    /// assert_eq!(None, NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().day_from_season_end());
+    /// ```
+    fn day_from_season_end(&self) -> Option<u8> {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            None
+        } else {
+            Some(SEASON_DAYS as u8 + 1 - date.day)
+        }
+    }
+
+    /// Whether this date falls in the given season. St. Tib's Day is in no
+    /// season.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Season};
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().in_season(Season::TheAftermath));
+    /// assert!(!NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().in_season(Season::Chaos));
+    /// ```
+    fn in_season(&self, season: Season) -> bool {
+        let date = self.discordian_components();
+        !date.st_tibs && date.season == season
+    }
+
+    /// Returns the nearest holyday within `window` days of this date, before
+    /// or after it and across the turn of the year. Where holydays before and
+    /// after are equally near, the upcoming one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Holyday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 9, 24).unwrap();
+    ///
+    /// assert_eq!(Some(Holyday::Bureflux), date.near_holyday(3));
+    /// assert_eq!(None, date.near_holyday(1));
+    /// ```
+    fn near_holyday(&self, window: u8) -> Option<Holyday> {
+        let date = NaiveDate::from_yo_opt(self.year(), self.ordinal())?;
+        let yold = self.discordian_components().yold;
+
+        (yold.saturating_sub(1)..=yold.saturating_add(1))
+            .flat_map(|yold| Holyday::ALL.iter().map(move |&h| (h, h.date_in_year(yold))))
+            .filter_map(|(holyday, day)| {
+                let distance = day?.signed_duration_since(date).num_days();
+                Some(((distance.abs(), distance < 0), holyday))
+            })
+            .filter(|&((distance, _), _)| distance <= i64::from(window))
+            .min()
+            .map(|(_, holyday)| holyday)
+    }
+
+    /// Returns the nearest holyday to this date and the date it falls on,
+    /// whether it has passed or is still to come, across the turn of the
+    /// year, such as for the closest flux. A holyday is nearest to itself.
+    /// Where holydays before and after are equally near, the upcoming one is
+    /// returned, as with `near_holyday`. This is `None` only at the ends of
+    /// chrono's range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Holyday};
+    ///
+    /// // Bureflux was 14 days ago, and Maladay is 14 days away.
+    /// let date = NaiveDate::from_ymd_opt(2017, 10, 10).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some((NaiveDate::from_ymd_opt(2017, 10, 24).unwrap(), Holyday::Maladay)),
+    ///     date.nearest_holyday()
+    /// );
+    /// ```
+    fn nearest_holyday(&self) -> Option<(NaiveDate, Holyday)> {
+        let date = NaiveDate::from_yo_opt(self.year(), self.ordinal())?;
+
+        // Holydays are never more than 45 days apart, so one is always
+        // within 23 days.
+        self.holyday_window(23).into_iter().min_by_key(|&(day, _)| {
+            let distance = day.signed_duration_since(date).num_days();
+            (distance.abs(), distance < 0)
+        })
+    }
+
+    /// Returns every holyday within `radius` days of this date, before or
+    /// after it and across the turns of YOLDs, in calendar order, such as to
+    /// announce the holydays coming up. A negative radius finds none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Holyday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 2, 16).unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![(NaiveDate::from_ymd_opt(2017, 2, 19).unwrap(), Holyday::Chaoflux)],
+    ///     date.holyday_window(3)
+    /// );
+    /// assert!(date.holyday_window(2).is_empty());
+    /// ```
+    fn holyday_window(&self, radius: i64) -> Vec<(NaiveDate, Holyday)> {
+        let date = match NaiveDate::from_yo_opt(self.year(), self.ordinal()) {
+            Some(date) if radius >= 0 => date,
+            _ => return Vec::new(),
+        };
+        let yold = self.discordian_components().yold;
+
+        // A YOLD is at least 365 days long, and no holyday chrono cannot
+        // represent need be looked for.
+        let years = (radius / 365 + 1).min(i64::from(i32::MAX)) as i32;
+        let first = yold
+            .saturating_sub(years)
+            .max(NaiveDate::MIN.discordian_components().yold);
+        let last = yold
+            .saturating_add(years)
+            .min(NaiveDate::MAX.discordian_components().yold);
+
+        (first..=last)
+            .flat_map(holydays_in_year)
+            .filter(|(day, _)| day.signed_duration_since(date).num_days().abs() <= radius)
+            .collect()
+    }
+
+    /// Returns the Discordian day of the week, or `None` on St. Tib's Day.
+    fn discordian_weekday(&self) -> Option<Weekday> {
+        self.discordian_components().weekday
+    }
+
+    /// Returns the first date strictly after this one falling on the given
+    /// Discordian weekday, such as the next Setting Orange, or `None` past
+    /// the end of chrono's range. St. Tib's Day is in no week, so the week
+    /// it interrupts takes six days to come round.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2016, 2, 27).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2016, 3, 1), date.next_weekday(Weekday::SettingOrange));
+    /// assert_eq!(NaiveDate::from_ymd_opt(2016, 3, 4), date.next_weekday(Weekday::Pungenday));
+    /// ```
+    fn next_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        let date = NaiveDate::from_yo_opt(self.year(), self.ordinal())?;
+
+        std::iter::successors(date.succ_opt(), |date| date.succ_opt())
+            .take(WEEK_DAYS + 1)
+            .find(|date| date.discordian_weekday() == Some(weekday))
+    }
+
+    /// Returns the Sweetmorn beginning the Discordian week of this date, for
+    /// grouping dates by week, or `None` outside of chrono's range. St.
+    /// Tib's Day is in no week, but falls within the week from the 56th to
+    /// the 60th of Chaos, and so is taken to begin with the 56th, as is the
+    /// 60th across it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2017, 11, 2),
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().week_start()
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2016, 2, 25),
+    ///     NaiveDate::from_ymd_opt(2016, 3, 1).unwrap().week_start()
+    /// );
+    /// ```
+    fn week_start(&self) -> Option<NaiveDate> {
+        let date = NaiveDate::from_yo_opt(self.year(), self.ordinal())?;
+        let ddate = date.discordian_components();
+
+        let days = match ddate.weekday {
+            None => ST_TIBS_DAY - 55,
+            Some(weekday) => {
+                let after_st_tibs = ddate.season == Season::Chaos
+                    && ddate.day as usize == ST_TIBS_DAY + 1
+                    && days_in_yold(ddate.yold) == 366;
+                weekday as usize + after_st_tibs as usize
+            }
+        };
+
+        date.checked_sub_signed(chrono::Duration::days(days as i64))
+    }
+
+    /// Returns the Discordian date half a YOLD away in the same YOLD, for
+    /// displays of the opposite point of the year.
+    ///
+    /// Half of the 365 days outside of St. Tib's Day is 182½, which is
+    /// rounded down: the antipode is always 182 days later, wrapping from
+    /// The Aftermath back around to Chaos, so that the antipode of an
+    /// antipode is the day before the date it started from. St. Tib's Day
+    /// is counted as the 59th of Chaos, and is never itself an antipode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Season};
+    ///
+    /// // The 73rd of Chaos is opposite the 36th of Bureaucracy.
+    /// let date = NaiveDate::from_ymd_opt(2017, 3, 14).unwrap();
+    /// let antipode = date.year_antipode();
+    ///
+    /// assert_eq!((3183, Season::Bureaucracy, 36), (antipode.yold, antipode.season, antipode.day));
+    /// ```
+    fn year_antipode(&self) -> DiscordianDateComponents {
+        let date = self.discordian_components();
+        let offset =
+            (date.season as usize * SEASON_DAYS + date.day as usize - 1 + 182) % (SEASON_DAYS * 5);
+
+        DiscordianDateComponents::from_parts(
+            date.yold,
+            Season::ALL[offset / SEASON_DAYS],
+            (offset % SEASON_DAYS) as u8 + 1,
+        )
+        .expect("an offset within the YOLD is a day of a season")
+    }
+
+    /// Returns the date in the following season, after The Aftermath the
+    /// Chaos of the next YOLD, on the same Discordian weekday, for
+    /// scheduling across seasons, or `None` on St. Tib's Day, which has no
+    /// weekday, or outside of chrono's range.
+    ///
+    /// A season is 73 days, three more than a whole number of weeks, so the
+    /// day of the season shifts: the date is fifteen weeks later, on the day
+    /// two after this one in the following season, or, from the 72nd and
+    /// 73rd, which have no such day, fourteen weeks later, on the day three
+    /// before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// // Pungenday, the 16th of Bureaucracy, to the 18th of The Aftermath.
+    /// let date = NaiveDate::from_ymd_opt(2017, 8, 23).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2017, 11, 6), date.same_weekday_next_season());
+    /// ```
+    fn same_weekday_next_season(&self) -> Option<NaiveDate> {
+        let date = self.discordian_components();
+        date.weekday?;
+
+        let (yold, season) = match Season::ALL.get(date.season as usize + 1) {
+            Some(&season) => (date.yold, season),
+            None => (date.yold.checked_add(1)?, Season::Chaos),
+        };
+        let day = if date.day as usize + 2 <= SEASON_DAYS {
+            date.day + 2
+        } else {
+            date.day - 3
+        };
+
+        DiscordianDateComponents::from_parts(yold, season, day)
+            .ok()?
+            .to_naive_date()
+    }
+
+    /// Returns the date `n` seasons after this one, or before it if `n` is
+    /// negative, across YOLDs, on the same Discordian weekday, for meetings
+    /// recurring each season on a Pungenday, say. `None` is returned on St.
+    /// Tib's Day, which has no weekday, or outside of chrono's range.
+    ///
+    /// Each season moves the weekday of a day of the season on by three, so
+    /// the date snaps forwards to the first day from the same day of the
+    /// target season with the same weekday, up to four days later, unless
+    /// that would leave the season, when it snaps back five days from there
+    /// instead. A single season forwards is `same_weekday_next_season`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// // Pungenday, the 16th of The Aftermath, to the 17th of Confusion in
+    /// // the next YOLD.
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2018, 6, 12), date.plus_seasons_keep_weekday(3));
+    /// ```
+    fn plus_seasons_keep_weekday(&self, n: i64) -> Option<NaiveDate> {
+        use std::convert::TryFrom;
+
+        let date = self.discordian_components();
+        date.weekday?;
+
+        let seasons = (date.season as i64).checked_add(n)?;
+        let yold = i64::from(date.yold).checked_add(seasons.div_euclid(5))?;
+        let season = Season::ALL[seasons.rem_euclid(5) as usize];
+
+        // The day the same weekday falls on, from the same day of the season.
+        let shift = (2 * n.rem_euclid(5)) as usize % WEEK_DAYS;
+        let day = if date.day as usize + shift <= SEASON_DAYS {
+            date.day as usize + shift
+        } else {
+            date.day as usize + shift - WEEK_DAYS
+        };
+
+        DiscordianDateComponents::from_parts(i32::try_from(yold).ok()?, season, day as u8)
+            .ok()?
+            .to_naive_date()
+    }
+
+    /// Returns the Discordian date `weeks` Gregorian weeks after this one,
+    /// or before it if `weeks` is negative, or `None` outside of chrono's
+    /// range. A Gregorian week is seven days, so it lands two Discordian
+    /// weekdays on, or one across St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    /// let ddate = date.gregorian_week_shift(1).unwrap();
+    ///
+    /// assert_eq!((23, Some(Weekday::SettingOrange)), (ddate.day, ddate.weekday));
+    /// ```
+    fn gregorian_week_shift(&self, weeks: i64) -> Option<DiscordianDateComponents> {
+        let date = NaiveDate::from_yo_opt(self.year(), self.ordinal())?;
+        let shift = chrono::Duration::try_weeks(weeks)?;
+
+        Some(date.checked_add_signed(shift)?.discordian_components())
+    }
+
+    /// Returns the Discordian date of the Monday beginning the ISO 8601 week
+    /// of this date, as with `to_poee`, to set the two weeks side by side,
+    /// or `None` if chrono cannot represent that Monday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// // Week 44 of 2017 began on Monday, October 30th.
+    /// assert_eq!(
+    ///     Some("Pungenday, the 11th day of The Aftermath in the YOLD 3183".to_string()),
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().iso_week_monday_poee()
+    /// );
+    /// ```
+    fn iso_week_monday_poee(&self) -> Option<String> {
+        let week = self.iso_week();
+        let monday = NaiveDate::from_isoywd_opt(week.year(), week.week(), chrono::Weekday::Mon)?;
+
+        Some(monday.to_poee())
+    }
+
+    /// Returns the Gregorian day of the week; this is chrono's `weekday`,
+    /// named to sit beside `discordian_weekday`.
+    fn gregorian_weekday(&self) -> chrono::Weekday {
+        self.weekday()
+    }
+
+    /// Returns the Gregorian and Discordian days of the week together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Weekday};
+    ///
+    /// assert_eq!(
+    ///     (chrono::Weekday::Sat, Some(Weekday::Pungenday)),
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().both_weekdays()
+    /// );
+    /// ```
+    fn both_weekdays(&self) -> (chrono::Weekday, Option<Weekday>) {
+        (self.gregorian_weekday(), self.discordian_weekday())
+    }
+
+    /// Returns the Gregorian ISO week and the Discordian week of the YOLD
+    /// together, as `week_of_year` counts it, for dual-calendar planners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    /// use ddate::DiscordianDate;
+    ///
+    /// let (iso, week) = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().both_weeks();
+    ///
+    /// assert_eq!((2017, 44, Some(62)), (iso.year(), iso.week(), week));
+    /// ```
+    fn both_weeks(&self) -> (chrono::IsoWeek, Option<u8>) {
+        (self.iso_week(), self.week_of_year())
+    }
+
+    /// Returns the Gregorian and Discordian days of the week, as
+    /// `both_weekdays` does, and whether they align: whether they are in the
+    /// same place in their weeks, counting Gregorian weeks from Monday, so
+    /// that Monday aligns with Sweetmorn, and so on to Friday with Setting
+    /// Orange. Weekends and St. Tib's Day never align.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Weekday};
+    ///
+    /// assert_eq!(
+    ///     (chrono::Weekday::Mon, Some(Weekday::Sweetmorn), true),
+    ///     NaiveDate::from_ymd_opt(2017, 1, 16).unwrap().weekday_alignment()
+    /// );
+    /// ```
+    fn weekday_alignment(&self) -> (chrono::Weekday, Option<Weekday>, bool) {
+        let (gregorian, discordian) = self.both_weekdays();
+        let aligned =
+            discordian.is_some_and(|weekday| weekday as u32 == gregorian.num_days_from_monday());
+
+        (gregorian, discordian, aligned)
+    }
+
+    /// Returns the name of the Discordian day of the week, or
+    /// `St. Tib's Day` on that day, which belongs to no week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("Pungenday", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().day_label());
+    /// assert_eq!("St. Tib's Day", NaiveDate::from_ymd_opt(2000, 2, 29).unwrap().day_label());
+    /// ```
+    fn day_label(&self) -> String {
+        self.discordian_weekday()
+            .map_or("St. Tib's Day", Weekday::name)
+            .to_string()
+    }
+
+    /// Returns a short Discordian calendar date string, in the style of the
+    /// `util-linux` tool's output for a given date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_short();
+    ///
+    /// assert_eq!("Pungenday, The Aftermath 16, 3183 YOLD", ddate);
+    /// ```
+    fn to_poee_short(&self) -> String {
+        Short(&self.discordian_components()).to_string()
+    }
+
+    /// Returns the short Discordian calendar date string of `to_poee_short`
+    /// in a fixed buffer, along with its length, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let (len, buf) = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_short_array();
+    ///
+    /// assert_eq!(b"Pungenday, The Aftermath 16, 3183 YOLD", &buf[..len]);
+    /// ```
+    fn to_poee_short_array(&self) -> (usize, [u8; POEE_SHORT_MAX_LEN]) {
+        use std::io::Write;
+
+        let mut buf = [0; POEE_SHORT_MAX_LEN];
+        let mut cursor = &mut buf[..];
+        write!(cursor, "{}", Short(&self.discordian_components()))
+            .expect("POEE_SHORT_MAX_LEN is exceeded");
+        let len = POEE_SHORT_MAX_LEN - cursor.len();
+
+        (len, buf)
+    }
+
+    /// Returns a compact, single-line Discordian date suitable for embedding
+    /// in a shell prompt, with a star marking holydays. Names are abbreviated
+    /// as by `util-linux`; see `prompt_segment_with` for full names.
+    ///
+    /// The segment contains no newlines and no characters with special
+    /// meaning to common shells' prompt expansion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("PD 16 Afm 3183", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().prompt_segment());
+    /// assert_eq!("BT 5 Afm 3183 \u{2605}", NaiveDate::from_ymd_opt(2017, 10, 24).unwrap().prompt_segment());
+    /// ```
+    fn prompt_segment(&self) -> String {
+        self.prompt_segment_with(Abbreviation::Short)
+    }
+
+    /// Returns a compact, single-line Discordian date as `prompt_segment`
+    /// does, with names abbreviated as given.
+    fn prompt_segment_with(&self, abbreviation: Abbreviation) -> String {
+        let date = self.discordian_components();
+
+        let mut segment = match (date.weekday, abbreviation) {
+            (None, Abbreviation::Short) => format!("Tib {}", date.yold),
+            (None, Abbreviation::Full) => format!("St. Tib's Day {}", date.yold),
+            (Some(weekday), Abbreviation::Short) => format!(
+                "{} {} {} {}",
+                weekday.abbreviation(),
+                date.day,
+                date.season.abbreviation(),
+                date.yold
+            ),
+            (Some(weekday), Abbreviation::Full) => {
+                format!("{} {} {} {}", weekday, date.day, date.season, date.yold)
+            }
+        };
+
+        if date.holyday().is_some() {
+            segment.push(' ');
+            segment.push(HOLYDAY_GLYPH);
+        }
+
+        segment
+    }
+
+    /// Returns a compact Discordian date, as `prompt_segment` does, fitted to
+    /// exactly `FIXED_COLUMN_WIDTH` columns for aligned log prefixes, padded
+    /// on the right with spaces. Every date chrono can represent fits; the
+    /// date of another `Datelike` too long to fit is cut short at a
+    /// character boundary with an ellipsis, as by `to_poee_cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("PD 16 Afm 3183      ", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_fixed_column());
+    /// ```
+    fn to_fixed_column(&self) -> String {
+        wrap::fit(&self.prompt_segment(), FIXED_COLUMN_WIDTH)
+    }
+
+    /// Returns the crystal ruling the date: that of its holyday, if any, or
+    /// else that of its weekday in its season from `CRYSTAL_GUIDE`. St.
+    /// Tib's Day, having no weekday, is ruled by Amorphous Quartz.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("Rose Quartz", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().ruling_crystal());
+    /// assert_eq!("Kunzite", NaiveDate::from_ymd_opt(2017, 10, 24).unwrap().ruling_crystal());
+    /// ```
+    fn ruling_crystal(&self) -> &'static str {
+        let date = self.discordian_components();
+
+        match (date.holyday(), date.weekday) {
+            (Some(holyday), _) => holyday.crystal(),
+            (None, Some(weekday)) => CRYSTAL_GUIDE[date.season as usize][weekday as usize],
+            (None, None) => lore::ST_TIBS_CRYSTAL,
+        }
+    }
+
+    /// Returns a Discordian calendar date string followed by the crystal
+    /// ruling the date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_crystal();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nCrystal: Rose Quartz",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_discordian_crystal(&self) -> String {
+        format!("{}\nCrystal: {}", self.to_poee(), self.ruling_crystal())
+    }
+
+    /// Returns the phase of the season the date falls in: `"early"` for days
+    /// 1 to 24, `"mid"` for days 25 to 49, and `"late"` for days 50 to 73,
+    /// so that the seasonal holyday opens the late phase. St. Tib's Day,
+    /// outside of any season, has no phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(Some("late"), NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().season_phase());
+    /// assert_eq!(None, NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().season_phase());
+    /// ```
+    fn season_phase(&self) -> Option<&'static str> {
+        let date = self.discordian_components();
+
+        match date.day {
+            _ if date.st_tibs => None,
+            1..=24 => Some("early"),
+            25..=49 => Some("mid"),
+            _ => Some("late"),
+        }
+    }
+
+    /// Returns a Discordian calendar date string in a format understood by
+    /// `util-linux`'s ddate; see `Format` for the codes it may contain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(1995, 9, 26)
+    ///     .unwrap()
+    ///     .to_poee_format("It's %{%A, the %e of %B%}, %Y. %N%nCelebrate %H");
+    ///
+    /// assert_eq!("It's Prickle-Prickle, the 50th of Bureaucracy, 3161. \nCelebrate Bureflux", ddate);
+    /// ```
+    fn to_poee_format(&self, format: &str) -> String {
+        Format::new(format).render(&self.discordian_components())
+    }
+
+    /// Returns a Discordian calendar date string in a format understood by
+    /// `util-linux`'s ddate, or an error if the format contains an unknown
+    /// code rather than outputting it as it is; see `Format::strict`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DdateError, DiscordianDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(Ok("PD Afm 16".to_string()), date.to_poee_format_strict("%a %b %d"));
+    /// assert_eq!(Err(DdateError::UnknownFormatCode('Q')), date.to_poee_format_strict("%Q"));
+    /// ```
+    fn to_poee_format_strict(&self, format: &str) -> Result<String, DdateError> {
+        Ok(Format::strict(format)?.render(&self.discordian_components()))
+    }
+
+    /// Returns a Discordian calendar date string followed by a divination
+    /// of the weather: that of the season modified by the weekday, or the
+    /// prophecy of the holyday, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().to_poee_with_weather_divination();
+    ///
+    /// assert_eq!(
+    ///     "Sweetmorn, the 1st day of Chaos in the YOLD 3183\nExpected weather: Chaos reigns (cloudy with a chance of fnords)",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_with_weather_divination(&self) -> String {
+        let date = self.discordian_components();
+
+        match (date.holyday(), date.weekday) {
+            (Some(holyday), _) => {
+                format!("{}\nHolyday weather: {}", self.to_poee(), holyday.weather())
+            }
+            (None, Some(weekday)) => format!(
+                "{}\nExpected weather: {} ({})",
+                self.to_poee(),
+                date.season.weather(),
+                weekday.weather()
+            ),
+            (None, None) => format!(
+                "{}\nSt. Tib's weather: {}",
+                self.to_poee(),
+                lore::ST_TIBS_WEATHER
+            ),
+        }
+    }
+
+    /// Returns the Erisian day number of the date, from 1 to 366, with St.
+    /// Tib's Day always day 60; see `from_erisian_day`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(59, NaiveDate::from_ymd_opt(2017, 2, 28).unwrap().erisian_day_number());
+    /// assert_eq!(61, NaiveDate::from_ymd_opt(2017, 3, 1).unwrap().erisian_day_number());
+    /// ```
+    fn erisian_day_number(&self) -> u16 {
+        self.discordian_components().erisian_day_number()
+    }
+
+    /// Returns the fraction of the YOLD elapsed before the date, from 0 on
+    /// the 1st of Chaos to just under 1 on the 73rd of The Aftermath, out of
+    /// the 366 days of a leap YOLD or the 365 of any other. St. Tib's Day
+    /// falls between the 59th and 60th of Chaos, as it does in the calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(0.0, NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().year_fraction());
+    /// assert_eq!(59.0 / 366.0, NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().year_fraction());
+    /// ```
+    fn year_fraction(&self) -> f64 {
+        let days = days_in_yold(self.discordian_components().yold);
+
+        f64::from(self.ordinal0()) / f64::from(days)
+    }
+
+    /// Returns which fifth of the YOLD the date falls in, for summaries.
+    /// Five seasons do not divide into quarters, so the YOLD is divided into
+    /// fifths, one to a season: "the first fifth" is Chaos, and so on to
+    /// "the last fifth", The Aftermath. St. Tib's Day, falling in Chaos, is
+    /// in the first fifth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!("the last fifth", NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().year_segment());
+    /// ```
+    fn year_segment(&self) -> &'static str {
+        match self.discordian_components().season {
+            Season::Chaos => "the first fifth",
+            Season::Discord => "the second fifth",
+            Season::Confusion => "the third fifth",
+            Season::Bureaucracy => "the fourth fifth",
+            Season::TheAftermath => "the last fifth",
+        }
+    }
+
+    /// Returns the decade of the YOLD of the date, as its first YOLD, such
+    /// as 3180 for YOLD 3183, for grouping dates in archives. Decades are
+    /// floored, so YOLD -5 is in the decade from -10 to -1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(3180, NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().yold_decade());
+    /// assert_eq!(-10, NaiveDate::from_ymd_opt(-1171, 11, 4).unwrap().yold_decade());
+    /// ```
+    fn yold_decade(&self) -> i32 {
+        let yold = self.discordian_components().yold;
+        yold - yold.rem_euclid(10)
+    }
+
+    /// Returns the century of the YOLD of the date, as its first YOLD, such
+    /// as 3100 for YOLD 3183, floored as `yold_decade` is.
+    fn yold_century(&self) -> i32 {
+        let yold = self.discordian_components().yold;
+        yold - yold.rem_euclid(100)
+    }
+
+    /// Returns the YOLD of the date as though each YOLD began on the given
+    /// Erisian day number rather than on the 1st of Chaos, such as for
+    /// those who count YOLDs from a holyday. Days before the epoch belong to
+    /// the previous YOLD. An epoch of 1, or 0, gives the usual YOLD.
+    ///
+    /// The epoch is an Erisian day number, on which St. Tib's Day is always
+    /// 60, so that a holyday falls on the same number in every YOLD. An epoch
+    /// of 60 begins common YOLDs on the 60th of Chaos, the day after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Holyday};
+    ///
+    /// let bureflux = Holyday::Bureflux.date_in_year(3183).unwrap();
+    /// let epoch = bureflux.erisian_day_number();
+    ///
+    /// assert_eq!(3183, bureflux.yold_with_epoch(epoch));
+    /// assert_eq!(3182, bureflux.pred_opt().unwrap().yold_with_epoch(epoch));
+    /// ```
+    fn yold_with_epoch(&self, epoch_ordinal: u16) -> i32 {
+        let date = self.discordian_components();
+
+        if date.erisian_day_number() < epoch_ordinal {
+            date.yold - 1
+        } else {
+            date.yold
+        }
+    }
+
+    /// Returns a verbose Discordian calendar date string, giving the season
+    /// as an ordinal as well as by name, followed by the holyday to
+    /// celebrate, if any, as with `to_poee`. St. Tib's Day falls between the
+    /// 59th and 60th days of the 1st season.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_with_ordinal_season_day();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of the 5th season (The Aftermath), YOLD 3183",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_with_ordinal_season_day(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => {
+                return format!(
+                "St. Tib's Day, between the 59th and 60th days of the 1st season (Chaos), YOLD {}",
+                date.yold
+            )
+            }
+        };
+
+        let mut out = format!(
+            "{}, the {} day of the {} season ({}), YOLD {}",
+            weekday,
+            ordinalize(date.day as usize),
+            ordinalize(date.season as usize + 1),
+            date.season,
+            date.yold
+        );
+        if let Some(holyday) = date.holyday() {
+            out.push_str("\nCelebrate ");
+            out.push_str(holyday.name());
+        }
+
+        out
+    }
+
+    /// Returns a Discordian calendar date string giving the weekday by its
+    /// position in the week rather than by name, followed by the holyday to
+    /// celebrate, if any, as with `to_poee`. St. Tib's Day is outside of the
+    /// week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_numeric_weekday();
+    ///
+    /// assert_eq!("Day 3 of the week, the 16th of The Aftermath, YOLD 3183", ddate);
+    /// ```
+    fn to_poee_numeric_weekday(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return format!("St. Tib's Day, outside of the week, YOLD {}", date.yold),
+        };
+
+        let mut out = format!(
+            "Day {} of the week, the {} of {}, YOLD {}",
+            weekday as usize + 1,
+            ordinalize(date.day as usize),
+            date.season,
+            date.yold
+        );
+        if let Some(holyday) = date.holyday() {
+            out.push_str("\nCelebrate ");
+            out.push_str(holyday.name());
+        }
+
+        out
+    }
+
+    /// Returns a Discordian calendar date string with the day of the YOLD
+    /// and the number of days in it, counting St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_with_doy();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183 (day 308 of 365)",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_with_doy(&self) -> String {
+        let date = self.discordian_components();
+        let poee = self.to_poee();
+        let (line, rest) = poee.split_at(poee.find('\n').unwrap_or(poee.len()));
+
+        format!(
+            "{} (day {} of {}){}",
+            line,
+            date.day_of_year(),
+            date.days_in_year(),
+            rest
+        )
+    }
+
+    /// Returns a Discordian calendar date string followed by the life path
+    /// number of the date: the sum of the day of the season, the one-based
+    /// number of the season, and the digits of the YOLD, reduced by
+    /// `reduce_to_life_path`. St. Tib's Day has no life path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_with_numerology();
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nNumerology: Life Path 9 (The Humanitarian)",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_with_numerology(&self) -> String {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            return format!(
+                "{}\nNumerology: Path 0 ({})",
+                self.to_poee(),
+                lore::ST_TIBS_NUMEROLOGY
+            );
+        }
+
+        let yold_digits: u32 = date
+            .yold
+            .unsigned_abs()
+            .to_string()
+            .bytes()
+            .map(|digit| u32::from(digit - b'0'))
+            .sum();
+        let path = reduce_to_life_path(u32::from(date.day) + date.season as u32 + 1 + yold_digits);
+
+        format!(
+            "{}\nNumerology: Life Path {} ({})",
+            self.to_poee(),
+            path,
+            lore::life_path_keyword(path)
+        )
+    }
+
+    /// Returns the Discordian calendar date as rendered by a closure given
+    /// its components, for formats this crate does not provide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 11, 4)
+    ///     .unwrap()
+    ///     .to_poee_custom(|date| format!("{}/{}/{}", date.yold, date.season as u8 + 1, date.day));
+    ///
+    /// assert_eq!("3183/5/16", ddate);
+    /// ```
+    fn to_poee_custom<F: Fn(&DiscordianDateComponents) -> String>(&self, f: F) -> String {
+        f(&self.discordian_components())
+    }
+
+    /// Returns a Discordian calendar date string followed by the reading of
+    /// the chaos oracle for the day of the season, from `CHAOS_ORACLE`,
+    /// preceded by the phrase of its holyday, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let ddate = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().to_poee_with_chaos_reading();
+    ///
+    /// assert_eq!(
+    ///     "Sweetmorn, the 1st day of Chaos in the YOLD 3183\nThe oracle says: The apple rolls where it will.",
+    ///     ddate
+    /// );
+    /// ```
+    fn to_poee_with_chaos_reading(&self) -> String {
+        let date = self.discordian_components();
+
+        let reading = if date.st_tibs {
+            lore::ST_TIBS_ORACLE.to_string()
+        } else {
+            let phrase = CHAOS_ORACLE[date.day as usize - 1];
+            match date.holyday() {
+                Some(holyday) => format!("{} {}", holyday.oracle(), phrase),
+                None => phrase.to_string(),
+            }
+        };
+
+        format!("{}\nThe oracle says: {}", self.to_poee(), reading)
+    }
+
+    /// Returns the mantra of the date, from `MANTRAS` by its weekday and
+    /// season, and the mantra of its holyday, if any, from
+    /// `HOLYDAY_MANTRAS`. St. Tib's Day has a mantra of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     ("The queue moves when I stop watching.", Some("Let bureaucracy flow until it drowns itself.")),
+    ///     NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().mantra_words()
+    /// );
+    /// ```
+    fn mantra_words(&self) -> (&'static str, Option<&'static str>) {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(weekday) => (
+                MANTRAS[date.season as usize][weekday as usize],
+                date.holyday()
+                    .map(|holyday| HOLYDAY_MANTRAS[holyday as usize]),
+            ),
+            None => (lore::ST_TIBS_MANTRA, None),
+        }
+    }
+
+    /// Returns the mantra of the date, followed by that of its holyday, if
+    /// any; see `mantra_words`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "I rest in the rubble, smiling.",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_mantra()
+    /// );
+    /// ```
+    fn to_discordian_mantra(&self) -> String {
+        match self.mantra_words() {
+            (mantra, Some(holyday)) => format!("{} {}", mantra, holyday),
+            (mantra, None) => mantra.to_string(),
+        }
+    }
+
+    /// Returns the tone of the date's weekday from `PENTABARF_TONES`, or
+    /// `None` on St. Tib's Day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(Some("Pungent"), NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().pentabarf_tone());
+    /// ```
+    fn pentabarf_tone(&self) -> Option<&'static str> {
+        self.discordian_weekday().map(Weekday::pentabarf_tone)
+    }
+
+    /// Returns a Discordian calendar date string with its components in the
+    /// order of the given style, followed by the holyday to celebrate, if
+    /// any, as with `to_poee`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DdateStyle, DiscordianDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(date.to_poee(), date.to_poee_styled(DdateStyle::Default));
+    /// assert_eq!(
+    ///     "YOLD 3183, The Aftermath, 16th day, Pungenday",
+    ///     date.to_poee_styled(DdateStyle::Reverse)
+    /// );
+    /// ```
+    fn to_poee_styled(&self, style: DdateStyle) -> String {
+        styled(&self.discordian_components(), style)
+    }
+
+    /// Returns a Discordian calendar date string as `to_poee_styled` does,
+    /// or, unless `st_tibs_as_special`, with St. Tib's Day counted as an
+    /// ordinary day.
+    ///
+    /// Counted as an ordinary day, St. Tib's Day is the 60th of Chaos, with
+    /// the weekday following that of the 59th, and the rest of Chaos shifts
+    /// one day later, so that Chaos is 74 days long in leap YOLDs. The other
+    /// seasons and their holydays keep their days, but every weekday after
+    /// St. Tib's Day in the YOLD shifts one later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DdateStyle, DiscordianDate};
+    ///
+    /// let tibs = NaiveDate::from_ymd_opt(2016, 2, 29).unwrap();
+    ///
+    /// assert_eq!(tibs.to_poee(), tibs.to_poee_styled_with(DdateStyle::Default, true));
+    /// assert_eq!(
+    ///     "Setting Orange, the 60th day of Chaos in the YOLD 3182",
+    ///     tibs.to_poee_styled_with(DdateStyle::Default, false)
+    /// );
+    /// ```
+    fn to_poee_styled_with(&self, style: DdateStyle, st_tibs_as_special: bool) -> String {
+        let date = if st_tibs_as_special {
+            self.discordian_components()
+        } else {
+            DiscordianDateComponents::linear(self.year(), self.ordinal0() as usize)
+        };
+
+        styled(&date, style)
+    }
+
+    /// Returns the observances kept on the date, from `OBSERVANCES`, by its
+    /// Erisian day number; most days have none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     vec!["Partake of no hot dog buns, in memory of the Original Snub."],
+    ///     NaiveDate::from_ymd_opt(2017, 1, 5).unwrap().observances()
+    /// );
+    /// ```
+    fn observances(&self) -> Vec<&'static str> {
+        self.observances_from(&OBSERVANCES)
+    }
+
+    /// Returns the observances kept on the date as `observances` does, but
+    /// from a table of one's own, in the form of `OBSERVANCES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let table = [(60, "Sleep in."), (60, "Sleep in again.")];
+    ///
+    /// assert_eq!(
+    ///     vec!["Sleep in.", "Sleep in again."],
+    ///     NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().observances_from(&table)
+    /// );
+    /// ```
+    fn observances_from<'a>(&self, table: &[(u16, &'a str)]) -> Vec<&'a str> {
+        let day = self.erisian_day_number();
+
+        table
+            .iter()
+            .filter(|&&(observed, _)| observed == day)
+            .map(|&(_, observance)| observance)
+            .collect()
+    }
+
+    /// Returns the affirmation of the date from `DAILY_AFFIRMATIONS`, by its
+    /// Erisian day number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, DAILY_AFFIRMATIONS};
+    ///
+    /// assert_eq!(DAILY_AFFIRMATIONS[59], NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().affirmation());
+    /// ```
+    fn affirmation(&self) -> &'static str {
+        DAILY_AFFIRMATIONS[self.erisian_day_number() as usize - 1]
+    }
+
+    /// Returns the affirmation of the date; see `affirmation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "My chaos is perfectly organized, so all is well.",
+    ///     NaiveDate::from_ymd_opt(2017, 1, 2).unwrap().to_discordian_affirmation()
+    /// );
+    /// ```
+    fn to_discordian_affirmation(&self) -> String {
+        self.affirmation().to_string()
+    }
+
+    /// Returns the date packed into an integer which sorts in calendar
+    /// order; see `DiscordianDateComponents::sort_key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(3_183_416, NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().sort_key());
+    /// ```
+    fn sort_key(&self) -> i64 {
+        self.discordian_components().sort_key()
+    }
+
+    /// Returns a name for a file of the date, such as a daily journal, which
+    /// sorts in calendar order with those of other dates: the YOLD, zero
+    /// padded to four digits, the season from 1 to 5, the day of the season,
+    /// zero padded to two, and the weekday, such as `3183-5-16-pungenday`.
+    ///
+    /// St. Tib's Day is named as the 59th of Chaos, `3182-1-59-st-tibs-day`,
+    /// which sorts after the Prickle-Prickle that the 59th always is, and
+    /// before the 60th. Names sort in calendar order for YOLDs 0 to 9999.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!("3183-5-16-pungenday", date.to_sortable_filename());
+    /// ```
+    fn to_sortable_filename(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday.name().to_lowercase().replace(' ', "-"),
+            None => "st-tibs-day".to_string(),
+        };
+
+        format!(
+            "{:04}-{}-{:02}-{}",
+            date.yold,
+            date.season as usize + 1,
+            date.day,
+            weekday
+        )
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, wrapped
+    /// at word boundaries to at most `width` columns; see `wrap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The\nAftermath in the YOLD 3183",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_wrapped(30)
+    /// );
+    /// ```
+    fn to_poee_wrapped(&self, width: usize) -> String {
+        wrap(&self.to_poee(), width)
+    }
+
+    /// Returns the probability of chaos on the date, as a percentage: 100 on
+    /// holydays, 23 on St. Tib's Day, and otherwise the day of the season
+    /// times the season's one-based ordinal, plus the YOLD modulo 100, all
+    /// modulo 101.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(62, NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().chaos_probability());
+    /// assert_eq!(100, NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().chaos_probability());
+    /// ```
+    fn chaos_probability(&self) -> u8 {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            23
+        } else if date.holyday().is_some() {
+            100
+        } else {
+            let ordinal = date.season as i64 + 1;
+            let yold = i64::from(date.yold).rem_euclid(100);
+            ((i64::from(date.day) * ordinal + yold) % 101) as u8
+        }
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, followed
+    /// by the probability of chaos on the date; see `chaos_probability`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nProbability of Chaos today: 62%",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_with_probability()
+    /// );
+    /// ```
+    fn to_poee_with_probability(&self) -> String {
+        format!(
+            "{}\nProbability of Chaos today: {}%",
+            self.to_poee(),
+            self.chaos_probability()
+        )
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, but
+    /// without the YOLD, for dates which recur every year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the 16th day of The Aftermath",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_poee_without_year()
+    /// );
+    /// assert_eq!("St. Tib's Day", NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().to_poee_without_year());
+    /// ```
+    fn to_poee_without_year(&self) -> String {
+        let date = self.discordian_components();
+
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return "St. Tib's Day".to_string(),
+        };
+
+        let mut poee = format!(
+            "{}, the {} day of {}",
+            weekday,
+            ordinalize(date.day as usize),
+            date.season
+        );
+        if let Some(holyday) = date.holyday() {
+            poee.push_str(&format!("\nCelebrate {}", holyday));
+        }
+        poee
+    }
+
+    /// Returns the number of days since the Curse of Greyface, the 1st of
+    /// Chaos in the YOLD 1, which is negative for earlier dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(0, NaiveDate::from_ymd_opt(-1165, 1, 1).unwrap().days_since_curse_of_greyface());
+    /// ```
+    fn days_since_curse_of_greyface(&self) -> i64 {
+        let date = self.discordian_components();
+        let years = i64::from(date.yold) - 1;
+        // Leap years before the YOLD, counted in proleptic Gregorian years
+        // from 1 - CURSE_OF_GREYFACE.
+        let leaps = |year: i64| year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+        let start = i64::from(1 - CURSE_OF_GREYFACE);
+        let end = start + years;
+
+        years * 365 + leaps(end - 1) - leaps(start - 1) + i64::from(date.day_of_year()) - 1
+    }
+
+    /// Returns the number of complete five-day Discordian weeks from this
+    /// date to `other`, which is negative if `other` is earlier, such as for
+    /// "3 weeks ago". St. Tib's Day is in no week and does not advance the
+    /// count; it is counted as though it were the 59th of Chaos. Unlike
+    /// `complete_weeks_between`, this counts the days elapsed between the
+    /// dates, not the days of a span including both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(2, date(2017, 1, 1).weeks_between(&date(2017, 1, 11)));
+    /// assert_eq!(-1, date(2017, 11, 4).weeks_between(&date(2017, 10, 28)));
+    /// ```
+    fn weeks_between(&self, other: &impl Datelike) -> i64 {
+        // Days counted in weeks, skipping St. Tib's Day, from an arbitrary
+        // epoch.
+        let week_days = |date: DiscordianDateComponents| {
+            i64::from(date.yold) * (SEASON_DAYS * 5) as i64
+                + (date.season as usize * SEASON_DAYS) as i64
+                + i64::from(date.day)
+        };
+        let days =
+            week_days(other.discordian_components()) - week_days(self.discordian_components());
+
+        days / WEEK_DAYS as i64
+    }
+
+    /// Returns the fortune of the date from `FORTUNES`, chosen by the number
+    /// of days since the Curse of Greyface.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, FORTUNES};
+    ///
+    /// assert!(FORTUNES.contains(&NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().fortune()));
+    /// ```
+    fn fortune(&self) -> &'static str {
+        FORTUNES[self
+            .days_since_curse_of_greyface()
+            .rem_euclid(FORTUNES.len() as i64) as usize]
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`,
+    /// followed by the fortune of the date; see `fortune`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(format!("{}\nFortune: {}", date.to_poee(), date.fortune()), date.to_discordian_fortune());
+    /// ```
+    fn to_discordian_fortune(&self) -> String {
+        format!("{}\nFortune: {}", self.to_poee(), self.fortune())
+    }
+
+    /// Returns the five lucky numbers of the date: the day of the season
+    /// modulo 23, the YOLD modulo 19, the season's one-based ordinal times
+    /// five, the weekday's one-based ordinal times seven, and the day plus
+    /// the YOLD modulo 73. St. Tib's Day is the 59th of Chaos on no weekday,
+    /// whose ordinal is taken to be zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!([16, 10, 25, 21, 60], NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().lucky_numbers());
+    /// ```
+    fn lucky_numbers(&self) -> [u32; 5] {
+        let date = self.discordian_components();
+        let day = i64::from(date.day);
+        let yold = i64::from(date.yold);
+        let weekday = date.weekday.map_or(0, |weekday| weekday as i64 + 1);
+
+        [
+            day % 23,
+            yold.rem_euclid(19),
+            (date.season as i64 + 1) * 5,
+            weekday * 7,
+            (day + yold).rem_euclid(73),
+        ]
+        .map(|n| n as u32)
+    }
+
+    /// Returns `to_discordian_fortune`, followed by the lucky numbers of the
+    /// date; see `lucky_numbers`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert!(date.fortune_with_lucky_numbers().ends_with("\nLucky numbers: 16, 10, 25, 21, 60"));
+    /// ```
+    fn fortune_with_lucky_numbers(&self) -> String {
+        let numbers: Vec<String> = self.lucky_numbers().iter().map(u32::to_string).collect();
+        format!(
+            "{}\nLucky numbers: {}",
+            self.to_discordian_fortune(),
+            numbers.join(", ")
+        )
+    }
+
+    /// Returns the week of the YOLD, from 1 to 73, counting the five-day
+    /// weeks continuously across seasons, or `None` on St. Tib's Day, which
+    /// belongs to no week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(Some(62), NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().week_of_year());
+    /// assert_eq!(None, NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().week_of_year());
+    /// ```
+    fn week_of_year(&self) -> Option<u8> {
+        let date = self.discordian_components();
+        date.weekday?;
+
+        let day = date.season as usize * SEASON_DAYS + date.day as usize - 1;
+        Some((day / WEEK_DAYS + 1) as u8)
+    }
+
+    /// Returns the horoscope of the date: the horoscope of its season with
+    /// the modifier of its weekday, preceded by the omen of its holyday, if
+    /// any. St. Tib's Day has a horoscope of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Season, Weekday};
+    ///
+    /// assert_eq!(
+    ///     format!("{} {}", Season::TheAftermath.horoscope(), Weekday::Pungenday.horoscope_modifier()),
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().daily_horoscope()
+    /// );
+    /// ```
+    fn daily_horoscope(&self) -> String {
+        let date = self.discordian_components();
+
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return lore::ST_TIBS_HOROSCOPE.to_string(),
+        };
+
+        let horoscope = format!(
+            "{} {}",
+            date.season.horoscope(),
+            weekday.horoscope_modifier()
+        );
+        match date.holyday() {
+            Some(holyday) => format!("{} {}", holyday.omen(), horoscope),
+            None => horoscope,
+        }
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`,
+    /// followed by the horoscope of the date; see `daily_horoscope`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(
+    ///     format!("{}\nHoroscope: {}", date.to_poee(), date.daily_horoscope()),
+    ///     date.to_discordian_horoscope()
+    /// );
+    /// ```
+    fn to_discordian_horoscope(&self) -> String {
+        format!("{}\nHoroscope: {}", self.to_poee(), self.daily_horoscope())
+    }
+
+    /// Writes the Discordian calendar date string of `to_poee` to a
+    /// `fmt::Write`, without allocating a `String` for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let mut out = String::from("Today is ");
+    /// NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().write_poee(&mut out).unwrap();
+    ///
+    /// assert_eq!("Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183", out);
+    /// ```
+    fn write_poee<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        let names = Names::default();
+        let poee = Poee {
+            date: &self.discordian_components(),
+            gregorian: None,
+            names: &names,
+        };
+        write!(out, "{}", poee)
+    }
+
+    /// Writes the Discordian calendar date string of `to_poee` to an
+    /// `io::Write`, such as a file or socket, without allocating a `String`
+    /// for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let mut out = Vec::new();
+    /// NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().write_poee_io(&mut out).unwrap();
+    ///
+    /// assert_eq!(b"Pungenday, the 16th day of The Aftermath in the YOLD 3183", &out[..]);
+    /// ```
+    fn write_poee_io<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let names = Names::default();
+        let poee = Poee {
+            date: &self.discordian_components(),
+            gregorian: None,
+            names: &names,
+        };
+        write!(out, "{}", poee)
+    }
+
+    /// Calls `f` with the Discordian calendar date string of `to_poee`,
+    /// rendered into a buffer kept for each thread, and returns what it
+    /// returns, so that hot paths such as loggers need not allocate a
+    /// `String` for every date.
+    ///
+    /// The string is borrowed from the buffer only for the call, and is
+    /// overwritten by the next one; anything kept beyond it must be copied
+    /// out. A call made from within `f` cannot reuse the buffer while it is
+    /// borrowed, and allocates a `String` of its own instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(57, date.with_poee_buffered(str::len));
+    /// assert_eq!(date.to_poee(), date.with_poee_buffered(str::to_string));
+    /// ```
+    fn with_poee_buffered<R, F: FnOnce(&str) -> R>(&self, f: F) -> R {
+        POEE_BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+            Ok(mut buffer) => {
+                buffer.clear();
+                self.write_poee(&mut *buffer)
+                    .expect("a String can always be written to");
+                f(&buffer)
+            }
+            Err(_) => f(&self.to_poee()),
+        })
+    }
+
+    /// Returns the Discordian calendar date string of `to_poee` as UTF-8
+    /// bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(date.to_poee().into_bytes(), date.to_poee_bytes());
+    /// ```
+    fn to_poee_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_poee_io(&mut out)
+            .expect("writing to a Vec never fails");
+        out
+    }
+
+    /// Returns the fact of the date from `DISCORDIAN_FACTS`, chosen by the
+    /// day of the season, the season's one-based ordinal, and the YOLD,
+    /// such that consecutive days of a season cycle through every fact
+    /// every 23 days. St. Tib's Day is taken to be the 59th of Chaos.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, DISCORDIAN_FACTS};
+    ///
+    /// // Pungenday, the 16th day of The Aftermath in the YOLD 3183.
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(DISCORDIAN_FACTS[(16 + 5 + 3183) % 23], date.random_fact());
+    /// ```
+    fn random_fact(&self) -> &'static str {
+        let date = self.discordian_components();
+        let index = i64::from(date.day) + date.season as i64 + 1 + i64::from(date.yold);
+
+        DISCORDIAN_FACTS[index.rem_euclid(DISCORDIAN_FACTS.len() as i64) as usize]
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`,
+    /// followed by the fact of the date; see `random_fact`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(format!("{}\nFact: {}", date.to_poee(), date.random_fact()), date.to_poee_with_random_fact());
+    /// ```
+    fn to_poee_with_random_fact(&self) -> String {
+        format!("{}\nFact: {}", self.to_poee(), self.random_fact())
+    }
+
+    /// Returns a meditation for the date, in five lines:
+    ///
+    /// 1. The affirmation of its weekday, or the mantra of its holyday, if
+    ///    any, from `HOLYDAY_MANTRAS`.
+    /// 2. The contemplation of its season.
+    /// 3. An acknowledgment of its holyday, or of its having none.
+    /// 4. A closing invocation.
+    /// 5. The date, as with `to_poee_short`.
+    ///
+    /// St. Tib's Day, being in no week or season, has lines of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let meditation = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_meditation();
+    ///
+    /// assert_eq!(
+    ///     "I sniff out the absurd and greet it by name.\n\
+    ///      Contemplate the wreckage, and what it might yet become.\n\
+    ///      No holyday falls today, so every moment is free to be one.\n\
+    ///      All Hail Discordia!\n\
+    ///      Pungenday, The Aftermath 16, 3183 YOLD",
+    ///     meditation
+    /// );
+    /// ```
+    fn to_discordian_meditation(&self) -> String {
+        let date = self.discordian_components();
+
+        let (affirmation, contemplation, acknowledgment, invocation) =
+            match (date.weekday, date.holyday()) {
+                (None, _) => (
+                    lore::ST_TIBS_AFFIRMATION,
+                    lore::ST_TIBS_CONTEMPLATION,
+                    "St. Tib's Day is outside of time; nothing done today counts.".to_string(),
+                    "Hail Eris, who made this day from nothing!",
+                ),
+                (Some(_), Some(holyday)) => (
+                    HOLYDAY_MANTRAS[holyday as usize],
+                    date.season.contemplation(),
+                    format!("Today is {}; celebrate it as you see fit.", holyday),
+                    "Hail Eris! All Hail Discordia!",
+                ),
+                (Some(weekday), None) => (
+                    weekday.affirmation(),
+                    date.season.contemplation(),
+                    "No holyday falls today, so every moment is free to be one.".to_string(),
+                    "All Hail Discordia!",
+                ),
+            };
+
+        format!(
+            "{}\n{}\n{}\n{}\n{}",
+            affirmation,
+            contemplation,
+            acknowledgment,
+            invocation,
+            self.to_poee_short()
+        )
+    }
+
+    /// Returns the lines of a prayer to Eris for the date, naming the date,
+    /// its season with the season's element and animal, and the Apostle
+    /// governing the season; see `Season::apostle`. The prayer closes with
+    /// `Fnord.`, celebrating any holyday first. St. Tib's Day, which is in
+    /// no season, is governed by no one Apostle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "O Eris, Goddess of Discord and Confusion,",
+    ///         "on this Pungenday, The Aftermath 16, 3183 YOLD,",
+    ///         "in the season of The Aftermath, of Air and the Raven,",
+    ///         "under the eye of Malaclypse the Elder, its Apostle,",
+    ///         "grant us the chaos we need and the order we can ignore.",
+    ///         "Fnord.",
+    ///     ],
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().prayer_lines()
+    /// );
+    /// ```
+    fn prayer_lines(&self) -> Vec<String> {
+        let date = self.discordian_components();
+
+        let (season, apostle) = if date.st_tibs {
+            (
+                "outside of every season, of nothing and no one,".to_string(),
+                "under the eye of all five Apostles, and of none,".to_string(),
+            )
+        } else {
+            (
+                format!(
+                    "in the season of {}, of {} and the {},",
+                    date.season,
+                    date.season.element(),
+                    date.season.animal()
+                ),
+                format!("under the eye of {}, its Apostle,", date.season.apostle()),
+            )
+        };
+        let closing = match date.holyday() {
+            Some(holyday) => format!("Hail Eris, and a joyous {}! Fnord.", holyday),
+            None => "Fnord.".to_string(),
+        };
+
+        vec![
+            "O Eris, Goddess of Discord and Confusion,".to_string(),
+            format!("on this {},", self.to_poee_short()),
+            season,
+            apostle,
+            "grant us the chaos we need and the order we can ignore.".to_string(),
+            closing,
+        ]
+    }
+
+    /// Returns the prayer of `prayer_lines`, one line after another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let prayer = NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().to_discordian_prayer();
+    ///
+    /// assert!(prayer.starts_with("O Eris, Goddess of Discord and Confusion,\n"));
+    /// assert!(prayer.ends_with("\nHail Eris, and a joyous Bureflux! Fnord."));
+    /// ```
+    fn to_discordian_prayer(&self) -> String {
+        self.prayer_lines().join("\n")
+    }
+
+    /// Returns the Curse of Greyface for the date, from `CURSE_TEMPLATE`,
+    /// lasting until the next holyday after it: the holyday of the season
+    /// still to come, or past the 50th, the first holyday of the next
+    /// season. St. Tib's Day has a curse of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "May the Curse of Greyface burden you, on this Pungenday, 16th of The Aftermath, \
+    ///      YOLD 3183, with excessive seriousness until Afflux releases you!",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_curse()
+    /// );
+    /// ```
+    fn to_discordian_curse(&self) -> String {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            return fill_template(lore::ST_TIBS_CURSE, &date);
+        }
+        let index = date.season as usize * 2
+            + match date.day as usize {
+                day if day < APOSTLE_HOLYDAY => 0,
+                day if day < SEASON_HOLYDAY => 1,
+                _ => 2,
+            };
+
+        fill_template(CURSE_TEMPLATE, &date)
+            .replace("{holyday}", Holyday::ALL[index % Holyday::ALL.len()].name())
+    }
+
+    /// Returns a blessing for the date, from `BLESSING_TEMPLATES`, with the
+    /// full date and the sacred animal of its season, and a more celebratory
+    /// benediction on holydays. St. Tib's Day has a blessing of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Blessed be this Pungenday, the 16th day of The Aftermath, YOLD 3183. \
+    ///      May the Raven of The Aftermath watch over you, and may Eris keep you \
+    ///      gloriously confused. Hail Eris!",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_blessing()
+    /// );
+    /// ```
+    fn to_discordian_blessing(&self) -> String {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            return fill_template(lore::ST_TIBS_BLESSING, &date);
+        }
+        let holyday = date.holyday();
+
+        fill_template(BLESSING_TEMPLATES[holyday.is_some() as usize], &date)
+            .replace("{animal}", date.season.animal())
+            .replace("{holyday}", holyday.map_or("", Holyday::name))
+    }
+
+    /// Returns the prophecy of the date, a quatrain from
+    /// `PROPHECY_QUATRAINS` by its season and weekday, or on holydays from
+    /// `HOLYDAY_PROPHECIES` instead. St. Tib's Day has a prophecy of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, HOLYDAY_PROPHECIES};
+    ///
+    /// let prophecy = NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().to_discordian_prophecy();
+    ///
+    /// assert_eq!(HOLYDAY_PROPHECIES[7], prophecy);
+    /// assert!(prophecy.starts_with("At Bureflux the forms take flight,\n"));
+    /// ```
+    fn to_discordian_prophecy(&self) -> String {
+        let date = self.discordian_components();
+
+        let prophecy = match (date.weekday, date.holyday()) {
+            (None, _) => lore::ST_TIBS_PROPHECY,
+            (Some(_), Some(holyday)) => HOLYDAY_PROPHECIES[holyday as usize],
+            (Some(weekday), None) => PROPHECY_QUATRAINS[date.season as usize][weekday as usize],
+        };
+
+        prophecy.to_string()
+    }
+
+    /// Returns a mock-solemn epitaph for the date, from `EPITAPH_TEMPLATES`
+    /// by its season. St. Tib's Day has an epitaph of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Here lies this day, Sweetmorn the 1st of Chaos, YOLD 3183. It lived fully, \
+    ///      served chaos faithfully, and returned to the Void.",
+    ///     NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().to_discordian_epitaph()
+    /// );
+    /// ```
+    fn to_discordian_epitaph(&self) -> String {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(_) => fill_template(EPITAPH_TEMPLATES[date.season as usize], &date),
+            None => lore::ST_TIBS_EPITAPH.to_string(),
+        }
+    }
+
+    /// Returns a theatrical lament for the date, from `SEASON_LAMENTS` by
+    /// its season, with the date as `to_poee` renders it, less any holyday.
+    /// Holydays are instead lamented in celebration, and St. Tib's Day has a
+    /// lament of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Oh woe! On this Setting Orange, the 26th day of Bureaucracy in the YOLD 3183, \
+    ///      the forms must be filed in triplicate!",
+    ///     NaiveDate::from_ymd_opt(2017, 9, 2).unwrap().to_discordian_lament()
+    /// );
+    /// assert_eq!(
+    ///     "Oh joyful woe! It is Bureflux, Prickle-Prickle, the 50th day of Bureaucracy \
+    ///      in the YOLD 3183, and we must celebrate the end of this Bureaucratic age!",
+    ///     NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().to_discordian_lament()
+    /// );
+    /// ```
+    fn to_discordian_lament(&self) -> String {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            return fill_template(lore::ST_TIBS_LAMENT, &date);
+        }
+
+        let poee = poee(&date, None, &Names::default());
+        let day = poee.lines().next().unwrap_or_default();
+
+        match date.holyday() {
+            Some(holyday) => lore::HOLYDAY_LAMENT
+                .replace("{holyday}", holyday.name())
+                .replace("{date}", day)
+                .replace("{age}", lore::SEASON_AGES[date.season as usize]),
+            None => SEASON_LAMENTS[date.season as usize].replace("{date}", day),
+        }
+    }
+
+    /// Returns a mock newspaper headline for the date, in capitals, with the
+    /// breaking news from `HEADLINES` by the number of days since the Curse
+    /// of Greyface, or on holydays from `HOLYDAY_HEADLINES`. St. Tib's Day
+    /// has a headline of its own. Headlines are never longer than 120
+    /// characters for any date chrono can represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "THE DAILY FNORD \u{2014} PRICKLE-PRICKLE, 50TH BUREAUCRACY, YOLD 3183 \u{2014} \
+    ///      BUREFLUX: FORMS FLOW FREELY AT LAST",
+    ///     NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().to_discordian_newspaper_headline()
+    /// );
+    /// ```
+    fn to_discordian_newspaper_headline(&self) -> String {
+        let date = self.discordian_components();
+
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return lore::ST_TIBS_HEADLINE.to_string(),
+        };
+        let news = match date.holyday() {
+            Some(holyday) => HOLYDAY_HEADLINES[holyday as usize],
+            None => {
+                let days = self.days_since_curse_of_greyface();
+                HEADLINES[days.rem_euclid(HEADLINES.len() as i64) as usize]
+            }
+        };
+
+        format!(
+            "THE DAILY FNORD \u{2014} {}, {} {}, YOLD {} \u{2014} {}",
+            weekday.name(),
+            ordinalize(date.day as usize),
+            date.season.name(),
+            date.yold,
+            news
+        )
+        .to_uppercase()
+    }
+
+    /// Returns a mock restaurant menu for the day, with the special of the
+    /// season from `SEASON_DISHES` and the drink of the day of the week from
+    /// `WEEKDAY_DRINKS`. On holydays the special gives way to a festival
+    /// feast from `HOLYDAY_DISHES`, and on St. Tib's Day the menu is
+    /// suspended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Today's Special: Bureaucratic Casserole with a side of Confusion Salad \
+    ///      | Drink: Pungenday Punch",
+    ///     NaiveDate::from_ymd_opt(2017, 8, 31).unwrap().to_discordian_menu()
+    /// );
+    /// assert_eq!(
+    ///     "Festival Feast: Bureflux Bouillabaisse, in triplicate | Drink: Prickle-Prickle Cactus Cooler",
+    ///     NaiveDate::from_ymd_opt(2017, 9, 26).unwrap().to_discordian_menu()
+    /// );
+    /// ```
+    fn to_discordian_menu(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return lore::ST_TIBS_MENU.to_string(),
+        };
+        let drink = WEEKDAY_DRINKS[weekday as usize];
+
+        match date.holyday() {
+            Some(holyday) => format!(
+                "Festival Feast: {} | Drink: {}",
+                HOLYDAY_DISHES[holyday as usize], drink
+            ),
+            None => format!(
+                "Today's Special: {} | Drink: {}",
+                SEASON_DISHES[date.season as usize], drink
+            ),
+        }
+    }
+
+    /// Returns a mock bus schedule for the day. Route 23 departs from the
+    /// terminal of the weekday at the hour of the day of the season modulo
+    /// 24, and arrives at the station of the next holyday, after this day,
+    /// in as many fnords as the weekday's place in the week. Bureaucracy
+    /// requires further forms, and St. Tib's Day keeps a schedule of its
+    /// own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Route 23 departs Pungenday terminal at the 16th hour of The Aftermath. \
+    ///      Arrive at Afflux Station in approximately 3 fnords. \
+    ///      All transfers require Form POEE-73-B.",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_bus_schedule()
+    /// );
+    /// ```
+    fn to_discordian_bus_schedule(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return lore::ST_TIBS_BUS_SCHEDULE.to_string(),
+        };
+        let destination = Holyday::ALL
+            .iter()
+            .find(|holyday| (holyday.season(), holyday.day()) > (date.season, date.day))
+            .unwrap_or(&Holyday::Mungday);
+
+        let mut schedule = format!(
+            "Route 23 departs {} terminal at the {} hour of {}. \
+             Arrive at {} Station in approximately {} fnords. \
+             All transfers require Form POEE-73-B.",
+            weekday.name(),
+            ordinalize(date.day as usize % 24),
+            date.season.name(),
+            destination.name(),
+            weekday as usize + 1
+        );
+        if date.season == Season::Bureaucracy {
+            schedule.push(' ');
+            schedule.push_str(lore::BUREAUCRACY_FORMS);
+        }
+
+        schedule
+    }
+
+    /// Returns whether the date reads the same forwards and backwards when
+    /// written compactly as digits: the YOLD, then the season from 1 for
+    /// Chaos to 5 for The Aftermath, then the day of the season as two
+    /// digits, with no separators, so that the 16th of The Aftermath 3183 is
+    /// `3183516`. St. Tib's Day, which has no season, is written as the YOLD
+    /// followed by `0`, and negative YOLDs are written with their `-`, so
+    /// neither is ever a palindrome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// // 1320231, the 31st of Discord 1320.
+    /// assert!(NaiveDate::from_ymd_opt(154, 4, 14).unwrap().is_palindrome());
+    /// assert!(!NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().is_palindrome());
+    /// ```
+    fn is_palindrome(&self) -> bool {
+        let date = self.discordian_components();
+        let digits = if date.st_tibs {
+            format!("{}0", date.yold)
+        } else {
+            format!("{}{}{:02}", date.yold, date.season as usize + 1, date.day)
+        };
+
+        digits.bytes().eq(digits.bytes().rev())
+    }
+
+    /// Returns a fake error message for the date, with the error code of
+    /// its season from `SEASON_ERROR_CODES`, or on holydays that of the
+    /// holyday from `HOLYDAY_ERROR_CODES`. St. Tib's Day is a critical error
+    /// of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "ERROR ERIS-16: Chaos in module The Aftermath. \
+    ///      Day 16 exceeds expected disorder threshold. \
+    ///      Please contact your local Cabal for assistance. (Code: YOLD-3183)",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_error_message()
+    /// );
+    /// ```
+    fn to_discordian_error_message(&self) -> String {
+        let date = self.discordian_components();
+        if date.st_tibs {
+            return lore::ST_TIBS_ERROR_MESSAGE.to_string();
+        }
+
+        let (code, fault) = match date.holyday() {
+            Some(holyday) => (
+                HOLYDAY_ERROR_CODES[holyday as usize],
+                format!(
+                    "Unhandled {} in module {}. Day {} exceeds expected celebration threshold.",
+                    holyday.name(),
+                    date.season.name(),
+                    date.day
+                ),
+            ),
+            None => (
+                SEASON_ERROR_CODES[date.season as usize],
+                format!(
+                    "Chaos in module {}. Day {} exceeds expected disorder threshold.",
+                    date.season.name(),
+                    date.day
+                ),
+            ),
+        };
+
+        format!(
+            "ERROR {}-{}: {} Please contact your local Cabal for assistance. (Code: YOLD-{})",
+            code, date.day, fault, date.yold
+        )
+    }
+
+    /// Returns a mock product warning label for the date, listing the three
+    /// side effects of its season from `WARNING_SIDE_EFFECTS`. Holydays are
+    /// named on the label, and certified to twice the usual strength. St.
+    /// Tib's Day carries a label of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "WARNING: This date (Pungenday, 16th of The Aftermath, YOLD 3183) has been \
+    ///      certified by the Society of Eris to contain 100% pure Discordian energy. \
+    ///      Side effects include: enlightenment, confusion, and sudden urge to eat hot dogs.",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_discordian_warning_label()
+    /// );
+    /// ```
+    fn to_discordian_warning_label(&self) -> String {
+        let date = self.discordian_components();
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => return lore::ST_TIBS_WARNING_LABEL.to_string(),
+        };
+
+        let (holyday, strength) = match date.holyday() {
+            Some(holyday) => (format!(" is {}, and", holyday.name()), 200),
+            None => (String::new(), 100),
+        };
+        let [first, second, third] = WARNING_SIDE_EFFECTS[date.season as usize];
+
+        format!(
+            "WARNING: This date ({}, {} of {}, YOLD {}){} has been certified by the Society \
+             of Eris to contain {}% pure Discordian energy. Side effects include: {}, {}, \
+             and {}.",
+            weekday.name(),
+            ordinalize(date.day as usize),
+            date.season.name(),
+            date.yold,
+            holyday,
+            strength,
+            first,
+            second,
+            third
+        )
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, on one
+    /// line and fitted to exactly `width` columns, for a cell of a table:
+    /// padded with spaces, or cut short with an ellipsis. Any holyday is
+    /// celebrated after a semicolon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!("Pungenday, the 16th day of The Af\u{2026}", date.to_poee_cell(34));
+    /// ```
+    fn to_poee_cell(&self, width: usize) -> String {
+        wrap::fit(&self.to_poee().replace('\n', "; "), width)
+    }
+
+    /// Returns a path for the Discordian date, for routing on the web, such as
+    /// `yold-3183/the-aftermath/16`, or `yold-3182/st-tibs-day`; see
+    /// `from_permalink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!("yold-3183/the-aftermath/16", date.to_permalink());
+    /// ```
+    fn to_permalink(&self) -> String {
+        let date = self.discordian_components();
+
+        if date.st_tibs {
+            format!("yold-{}/st-tibs-day", date.yold)
+        } else {
+            format!(
+                "yold-{}/{}/{}",
+                date.yold,
+                date.season.name().to_lowercase().replace(' ', "-"),
+                date.day
+            )
+        }
+    }
+
+    /// Returns the Discordian date as a git commit trailer, such as
+    /// `Discordian-Date: Pungenday, 16 The-Aftermath 3183`, for tagging
+    /// commits. It is a single line of ASCII letters, digits, hyphens,
+    /// commas, and spaces, with names hyphenated rather than spaced, and St.
+    /// Tib's Day written `St Tibs Day 3182`. The value after the key can be
+    /// parsed back with `parse_discordian`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{parse_discordian, DiscordianDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    /// let trailer = date.commit_trailer();
+    ///
+    /// assert_eq!("Discordian-Date: Pungenday, 16 The-Aftermath 3183", trailer);
+    /// assert_eq!(
+    ///     Ok(date.discordian_components()),
+    ///     parse_discordian(trailer.trim_start_matches("Discordian-Date: "))
+    /// );
+    /// ```
+    fn commit_trailer(&self) -> String {
+        let date = self.discordian_components();
+
+        match date.weekday {
+            Some(weekday) => format!(
+                "Discordian-Date: {}, {} {} {}",
+                weekday.name().replace(' ', "-"),
+                date.day,
+                date.season.name().replace(' ', "-"),
+                date.yold
+            ),
+            None => format!("Discordian-Date: St Tibs Day {}", date.yold),
+        }
+    }
+
+    /// Returns the coincidences of the date with the sacred number 23, in
+    /// this order: that it is the 23rd day of the season, that it is the
+    /// 23rd of the Gregorian month, that the digits of its YOLD add up to
+    /// 23, and that its YOLD is a multiple of 23. St. Tib's Day is no day of
+    /// any season.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     vec!["the 23rd day of the season", "the 23rd of the Gregorian month"],
+    ///     NaiveDate::from_ymd_opt(2017, 1, 23).unwrap().twenty_threes()
+    /// );
+    /// assert!(NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().twenty_threes().is_empty());
+    /// ```
+    fn twenty_threes(&self) -> Vec<&'static str> {
+        let date = self.discordian_components();
+        let digits: u32 = date
+            .yold
+            .unsigned_abs()
+            .to_string()
+            .bytes()
+            .map(|digit| u32::from(digit - b'0'))
+            .sum();
+
+        [
+            (
+                self.day_of_season() == Some(23),
+                "the 23rd day of the season",
+            ),
+            (self.day() == 23, "the 23rd of the Gregorian month"),
+            (digits == 23, "a YOLD whose digits add up to 23"),
+            (
+                date.yold != 0 && date.yold % 23 == 0,
+                "a YOLD divisible by 23",
+            ),
+        ]
+        .iter()
+        .filter(|&&(coincides, _)| coincides)
+        .map(|&(_, coincidence)| coincidence)
+        .collect()
+    }
+
+    /// Returns the values derived in converting this date, from the
+    /// Gregorian ordinal to the weekday, so that the workings of the
+    /// calendar can be inspected; see `Breakdown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// let breakdown = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().debug_breakdown();
+    ///
+    /// assert_eq!((308, Some(307)), (breakdown.gregorian_ordinal, breakdown.day_offset));
+    /// assert_eq!((4, 16, Some(2)), (breakdown.season_index, breakdown.day_of_season, breakdown.weekday_index));
+    /// ```
+    fn debug_breakdown(&self) -> Breakdown {
+        let date = self.discordian_components();
+        let day_offset = date
+            .weekday
+            .map(|_| (date.season as usize * SEASON_DAYS + date.day as usize - 1) as u16);
+
+        Breakdown {
+            gregorian_ordinal: self.ordinal(),
+            leap: is_leap_year(self.year().into()),
+            day_offset,
+            season_index: date.season as u8,
+            weekday_index: date.weekday.map(|weekday| weekday as u8),
+            day_of_season: date.day,
+            is_holyday: date.holyday().is_some(),
+            is_st_tibs: date.st_tibs,
+        }
+    }
+
+    /// Returns a Discordian calendar date string, as with `to_poee`, in the
+    /// given language, following its grammar rather than only translating
+    /// names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::{DiscordianDate, Locale};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2017, 11, 4).unwrap();
+    ///
+    /// assert_eq!(date.to_poee(), date.to_poee_locale(Locale::English));
+    /// assert_eq!(
+    ///     "Stechtag, der sechzehnte Tag des Nachspiels im Jahr unserer Herrin der Zwietracht 3183",
+    ///     date.to_poee_locale(Locale::German)
+    /// );
+    /// ```
+    fn to_poee_locale(&self, locale: Locale) -> String {
+        locale.poee(&self.discordian_components())
+    }
+
+    /// Returns the date in English words, as it is spoken, for
+    /// text-to-speech: the day as an ordinal word and the YOLD as a
+    /// cardinal. Holydays are celebrated, as with `to_poee`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use ddate::DiscordianDate;
+    ///
+    /// assert_eq!(
+    ///     "Pungenday, the sixteenth day of the Aftermath, \
+    ///      year of our Lady of Discord three thousand one hundred eighty-three",
+    ///     NaiveDate::from_ymd_opt(2017, 11, 4).unwrap().to_spoken()
+    /// );
+    /// assert_eq!(
+    ///     "Saint Tib's Day, year of our Lady of Discord three thousand one hundred eighty-two",
+    ///     NaiveDate::from_ymd_opt(2016, 2, 29).unwrap().to_spoken()
+    /// );
+    /// ```
+    fn to_spoken(&self) -> String {
+        locale::spoken(&self.discordian_components())
+    }
+}
+
+impl<T: Datelike> DiscordianDate for T {}
+
+/// Creates the components for an Erisian day number of a YOLD.
+///
+/// Erisian day numbers run from 1 to 366 and give every day the same number
+/// in every YOLD: the 1st to the 59th of Chaos are days 1 to 59, St. Tib's
+/// Day is always day 60, and the 60th of Chaos to the 73rd of The Aftermath
+/// are days 61 to 366. Day 60 does not exist in a YOLD without St. Tib's Day.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{from_erisian_day, DdateError, DiscordianDateComponents, Season};
+///
+/// assert_eq!(
+///     DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 73),
+///     from_erisian_day(3183, 366)
+/// );
+/// assert_eq!(Err(DdateError::NoStTibsDay(3183)), from_erisian_day(3183, 60));
+/// ```
+pub fn from_erisian_day(yold: i32, day: u16) -> Result<DiscordianDateComponents, DdateError> {
+    let day_offset = match day {
+        ST_TIBS_ERISIAN_DAY => return DiscordianDateComponents::st_tibs_day(yold),
+        1..=59 => day - 1,
+        61..=366 => day - 2,
+        _ => return Err(DdateError::ErisianDayOutOfRange(day)),
+    } as usize;
+
+    DiscordianDateComponents::from_parts(
+        yold,
+        Season::ALL[day_offset / SEASON_DAYS],
+        (day_offset % SEASON_DAYS + 1) as u8,
+    )
+}
+
+/// Returns the Gregorian month and day of a day of a season, or `None` if
+/// the day is out of range; see `DiscordianDateComponents::gregorian_md` for
+/// St. Tib's Day.
+///
+/// The YOLD does not change the result, since St. Tib's Day keeps the rest
+/// of the Discordian calendar aligned with the Gregorian one.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{to_gregorian_md, Season};
+///
+/// assert_eq!(Some((11, 4)), to_gregorian_md(3183, Season::TheAftermath, 16));
+/// assert_eq!(None, to_gregorian_md(3183, Season::TheAftermath, 74));
+/// ```
+pub fn to_gregorian_md(yold: i32, season: Season, day: u8) -> Option<(u32, u32)> {
+    DiscordianDateComponents::from_parts(yold, season, day)
+        .ok()
+        .map(|date| date.gregorian_md())
+}
+
+/// Returns the one-based ordinal of St. Tib's Day within a Gregorian year,
+/// which is that of February 29th, or `None` if the year is not a leap year.
+///
+/// St. Tib's Day follows the 59th of Chaos, so this is always 60, the same
+/// as its Erisian day number.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::st_tibs_ordinal;
+///
+/// assert_eq!(Some(60), st_tibs_ordinal(2000));
+/// assert_eq!(None, st_tibs_ordinal(2001));
+/// ```
+pub fn st_tibs_ordinal(year: i32) -> Option<u32> {
+    if is_leap_year(year.into()) {
+        Some(u32::from(ST_TIBS_ERISIAN_DAY))
+    } else {
+        None
+    }
+}
+
+/// Returns the YOLDs from `from` to `to`, inclusive, which have a St. Tib's
+/// Day, being those of Gregorian leap years. There are none if `to` is
+/// before `from`.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::st_tibs_years;
+///
+/// assert_eq!(vec![3182, 3186], st_tibs_years(3180, 3189));
+/// ```
+pub fn st_tibs_years(from: i32, to: i32) -> Vec<i32> {
+    (from..=to).filter(|&yold| is_leap_yold(yold)).collect()
+}
+
+/// Returns the number of days in a YOLD: 366 if it has a St. Tib's Day, and
+/// 365 otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::days_in_yold;
+///
+/// assert_eq!(366, days_in_yold(3182));
+/// assert_eq!(365, days_in_yold(3183));
+/// ```
+pub fn days_in_yold(yold: i32) -> u16 {
+    if is_leap_yold(yold) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the Gregorian date of the 1st of Chaos of a YOLD, if chrono can
+/// represent it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::yold_start;
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2017, 1, 1), yold_start(3183));
+/// ```
+pub fn yold_start(yold: i32) -> Option<NaiveDate> {
+    from_yold_ordinal(yold, 1).ok()?.to_naive_date()
+}
+
+/// Returns the Gregorian date of the 73rd of The Aftermath of a YOLD, if
+/// chrono can represent it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::yold_end;
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2017, 12, 31), yold_end(3183));
+/// ```
+pub fn yold_end(yold: i32) -> Option<NaiveDate> {
+    from_yold_ordinal(yold, days_in_yold(yold))
+        .ok()?
+        .to_naive_date()
+}
+
+/// Creates the components for an ordinal day of a YOLD, from 1 to
+/// `days_in_yold`.
+///
+/// Unlike Erisian day numbers, ordinals count only the days the YOLD has:
+/// St. Tib's Day is ordinal 60 in a YOLD which has one, and the 60th of
+/// Chaos is ordinal 60 in a YOLD which does not.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{from_yold_ordinal, DdateError, DiscordianDateComponents, Season};
+///
+/// assert_eq!(DiscordianDateComponents::st_tibs_day(3182), from_yold_ordinal(3182, 60));
+/// assert_eq!(
+///     DiscordianDateComponents::from_parts(3183, Season::Chaos, 60),
+///     from_yold_ordinal(3183, 60)
+/// );
+/// assert_eq!(
+///     Err(DdateError::OrdinalOutOfRange { yold: 3183, ordinal: 366 }),
+///     from_yold_ordinal(3183, 366)
+/// );
+/// ```
+pub fn from_yold_ordinal(yold: i32, ordinal: u16) -> Result<DiscordianDateComponents, DdateError> {
+    if ordinal == 0 || ordinal > days_in_yold(yold) {
+        return Err(DdateError::OrdinalOutOfRange { yold, ordinal });
+    }
+
+    if ordinal >= ST_TIBS_ERISIAN_DAY && !is_leap_yold(yold) {
+        from_erisian_day(yold, ordinal + 1)
+    } else {
+        from_erisian_day(yold, ordinal)
+    }
+}
+
+/// An iterator over the days of a YOLD, including St. Tib's Day in leap
+/// YOLDs, which can be iterated from either end.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{DiscordianDateComponents, DiscordianYear, Season};
+///
+/// let mut days = DiscordianYear::new(3183);
+///
+/// assert_eq!(365, days.len());
+/// assert_eq!(DiscordianDateComponents::from_parts(3183, Season::Chaos, 1).ok(), days.next());
+/// assert_eq!(
+///     DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 73).ok(),
+///     days.next_back()
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiscordianYear {
+    yold: i32,
+    front: u16,
+    back: u16,
+}
+
+impl DiscordianYear {
+    /// Creates an iterator over the days of a YOLD.
+    pub fn new(yold: i32) -> Self {
+        DiscordianYear {
+            yold,
+            front: 1,
+            back: days_in_yold(yold),
+        }
+    }
+
+    /// Returns the components for an ordinal day of this YOLD.
+    fn components(&self, ordinal: u16) -> DiscordianDateComponents {
+        from_yold_ordinal(self.yold, ordinal).expect("ordinals are within the YOLD")
+    }
+}
+
+impl Iterator for DiscordianYear {
+    type Item = DiscordianDateComponents;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.components(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back + 1).saturating_sub(self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DiscordianYear {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.components(self.back + 1))
+    }
+}
+
+impl ExactSizeIterator for DiscordianYear {}
+
+/// Returns the days of a YOLD in reverse calendar order, from the 73rd of
+/// The Aftermath to the 1st of Chaos.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{rev_days, DiscordianDateComponents, Season};
+///
+/// let days: Vec<_> = rev_days(3182).collect();
+///
+/// assert_eq!(366, days.len());
+/// assert_eq!(DiscordianDateComponents::st_tibs_day(3182).ok(), days.get(306).copied());
+/// ```
+pub fn rev_days(yold: i32) -> std::iter::Rev<DiscordianYear> {
+    DiscordianYear::new(yold).rev()
+}
+
+/// Returns the Gregorian date of a day of a season in each YOLD from `from`
+/// to `to`, inclusive, such as to find every Chaoflux of a span of years.
+/// Dates chrono cannot represent are omitted, as are all dates if the day
+/// is outside of 1 to 73. St. Tib's Day is no day of a season, so it is
+/// never among them; the 59th and 60th of Chaos fall either side of it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{candidate_dates, Season};
+///
+/// assert_eq!(
+///     vec![
+///         NaiveDate::from_ymd_opt(2016, 2, 19).unwrap(),
+///         NaiveDate::from_ymd_opt(2017, 2, 19).unwrap(),
+///     ],
+///     candidate_dates(Season::Chaos, 50, 3182, 3183)
+/// );
+/// ```
+pub fn candidate_dates(season: Season, day: u8, from: i32, to: i32) -> Vec<NaiveDate> {
+    (from..=to)
+        .filter_map(|yold| DiscordianDateComponents::from_parts(yold, season, day).ok())
+        .filter_map(|date| date.to_naive_date())
+        .collect()
+}
+
+/// Returns the Gregorian dates a day of a season falls on in each YOLD from
+/// `from_yold` to `to_yold`, inclusive, such as for the 50th of Bureaucracy
+/// over the next decade; this is `candidate_dates`, which see.
+///
+/// Days after St. Tib's Day fall one day later in the Gregorian year in
+/// leap YOLDs, but on the same Gregorian day of the month, as St. Tib's
+/// Day takes up February 29th.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{gregorian_occurrences, Season};
+///
+/// let dates = gregorian_occurrences(Season::Bureaucracy, 50, 3182, 3183);
+///
+/// assert_eq!(
+///     vec![
+///         NaiveDate::from_ymd_opt(2016, 9, 26).unwrap(),
+///         NaiveDate::from_ymd_opt(2017, 9, 26).unwrap(),
+///     ],
+///     dates
+/// );
+/// ```
+pub fn gregorian_occurrences(
+    season: Season,
+    day: u8,
+    from_yold: i32,
+    to_yold: i32,
+) -> Vec<NaiveDate> {
+    candidate_dates(season, day, from_yold, to_yold)
+}
+
+/// Returns the Gregorian date of a day of a season in each YOLD from
+/// `start_year` to `end_year`, inclusive, for scheduling something on that
+/// date every year; this is `candidate_dates`, which see.
+///
+/// St. Tib's Day is no day of a season, so it never
+/// recurs this way: the 59th of Chaos is always February 28th, and the
+/// 60th always March 1st, whether or not St. Tib's Day falls between them.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{recurrences_of_day, Season};
+///
+/// let dates = recurrences_of_day(Season::Chaos, 60, 3181, 3182);
+///
+/// assert_eq!(
+///     vec![
+///         NaiveDate::from_ymd_opt(2015, 3, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2016, 3, 1).unwrap(),
+///     ],
+///     dates
+/// );
+/// assert!(recurrences_of_day(Season::Chaos, 74, 3181, 3182).is_empty());
+/// ```
+pub fn recurrences_of_day(
+    season: Season,
+    day: u8,
+    start_year: i32,
+    end_year: i32,
+) -> Vec<NaiveDate> {
+    candidate_dates(season, day, start_year, end_year)
+}
+
+/// Returns each day from `start` to `end`, inclusive, with its Discordian
+/// date as `to_poee` renders it, such as to export a span of days. St. Tib's
+/// Day is included wherever the span crosses it, and the span is empty if
+/// `end` is before `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::discordian_date_range;
+///
+/// let start = NaiveDate::from_ymd_opt(2016, 2, 29).unwrap();
+/// let days: Vec<_> = discordian_date_range(start, start).collect();
+///
+/// assert_eq!(vec![(start, "St. Tib's Day, in the YOLD 3182".to_string())], days);
+/// ```
+pub fn discordian_date_range(
+    start: NaiveDate,
+    end: NaiveDate,
+) -> impl Iterator<Item = (NaiveDate, String)> {
+    // Unlike `iter_days`, this reaches `NaiveDate::MAX`.
+    std::iter::successors(Some(start), |date| date.succ_opt())
+        .take_while(move |date| *date <= end)
+        .map(|date| (date, date.to_poee()))
+}
+
+/// The equinoxes and solstices of `cardinal_points`, with the days of the
+/// seasons they are taken to fall on.
+const CARDINAL_POINTS: [(&str, Season, u8); 4] = [
+    ("March Equinox", Season::Discord, 6),
+    ("June Solstice", Season::Confusion, 26),
+    ("September Equinox", Season::Bureaucracy, 46),
+    ("December Solstice", Season::TheAftermath, 63),
+];
+
+/// Returns the Gregorian dates of the equinoxes and solstices of a YOLD, in
+/// calendar order, or `None` if chrono cannot represent them.
+///
+/// The mapping is approximate: each is taken to fall on a fixed day of a
+/// season, the 6th of Discord, the 26th of Confusion, the 46th of
+/// Bureaucracy, and the 63rd of The Aftermath. These are always March 20th,
+/// June 21st, September 22nd, and December 21st, which are within a day or
+/// so of the astronomical events in the Gregorian calendar.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::cardinal_points;
+///
+/// let points = cardinal_points(3183).unwrap();
+///
+/// assert_eq!(("June Solstice", NaiveDate::from_ymd_opt(2017, 6, 21).unwrap()), points[1]);
+/// ```
+pub fn cardinal_points(yold: i32) -> Option<[(&'static str, NaiveDate); 4]> {
+    let mut points = [("", NaiveDate::MIN); 4];
+
+    for (point, &(name, season, day)) in points.iter_mut().zip(&CARDINAL_POINTS) {
+        let date = DiscordianDateComponents::from_parts(yold, season, day).ok()?;
+        *point = (name, date.to_naive_date()?);
+    }
+
+    Some(points)
+}
+
+/// Returns the Gregorian dates of the holydays of a YOLD, in calendar order,
+/// omitting any that chrono cannot represent.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{holydays_in_year, Holyday};
+///
+/// let holydays = holydays_in_year(3183);
+///
+/// assert_eq!(10, holydays.len());
+/// assert_eq!((NaiveDate::from_ymd_opt(2017, 1, 5).unwrap(), Holyday::Mungday), holydays[0]);
+/// ```
+pub fn holydays_in_year(yold: i32) -> Vec<(NaiveDate, Holyday)> {
+    Holyday::ALL
+        .iter()
+        .filter_map(|&holyday| Some((holyday.date_in_year(yold)?, holyday)))
+        .collect()
+}
+
+/// Returns the dates of the holydays of a Gregorian year, in calendar order.
+///
+/// Each YOLD begins on January 1st and ends on December 31st of the same
+/// Gregorian year, numbered 1166 years ahead, so this is the same as
+/// `holydays_in_year(year + 1166)`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{holydays_in_gregorian_year, Holyday};
+///
+/// let holydays = holydays_in_gregorian_year(2017);
+///
+/// assert_eq!((NaiveDate::from_ymd_opt(2017, 12, 8).unwrap(), Holyday::Afflux), holydays[9]);
+/// ```
+pub fn holydays_in_gregorian_year(year: i32) -> Vec<(NaiveDate, Holyday)> {
+    year.checked_add(CURSE_OF_GREYFACE)
+        .map_or_else(Vec::new, holydays_in_year)
+}
+
+/// Returns whether St. Tib's Day falls between two Gregorian dates,
+/// inclusive, across any number of years. The span is empty if `end` is
+/// before `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::contains_st_tibs;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert!(contains_st_tibs(date(1999, 12, 1), date(2000, 3, 1)));
+/// assert!(!contains_st_tibs(date(2000, 3, 1), date(2001, 12, 1)));
+/// ```
+pub fn contains_st_tibs(start: NaiveDate, end: NaiveDate) -> bool {
+    (start.year()..=end.year())
+        .filter_map(|year| year.checked_add(CURSE_OF_GREYFACE))
+        .filter_map(|yold| DiscordianDateComponents::st_tibs_day(yold).ok())
+        .filter_map(|tibs| tibs.to_naive_date())
+        .any(|tibs| start <= tibs && tibs <= end)
+}
+
+/// Returns the number of complete five-day Discordian weeks in the days from
+/// `start` to `end`, both inclusive, such as for planning in weeks. St. Tib's
+/// Day belongs to no week, so it is skipped rather than counted, and a week
+/// it interrupts is still complete. Weeks are counted from `start`, whatever
+/// its weekday, and any days left over at the end are not a week. There are
+/// none if `end` is before `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::complete_weeks_between;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert_eq!(3, complete_weeks_between(date(2017, 1, 1), date(2017, 1, 15)));
+/// assert_eq!(2, complete_weeks_between(date(2016, 2, 25), date(2016, 3, 6)));
+/// ```
+pub fn complete_weeks_between(start: NaiveDate, end: NaiveDate) -> u32 {
+    if end < start {
+        return 0;
+    }
+
+    // The number of St. Tib's Days up to and including a date, counted
+    // from an arbitrary epoch.
+    let tibs_through = |date: NaiveDate| {
+        let before = i64::from(date.year()) - 1;
+        let leap = is_leap_year(date.year().into()) && date.ordinal0() as usize >= ST_TIBS_DAY;
+        before.div_euclid(4) - before.div_euclid(100) + before.div_euclid(400) + i64::from(leap)
+    };
+    let on_start = start.discordian_components().st_tibs;
+    let tibs = tibs_through(end) - tibs_through(start) + i64::from(on_start);
+    let days = end.signed_duration_since(start).num_days() + 1 - tibs;
+
+    (days / WEEK_DAYS as i64) as u32
+}
+
+/// Returns the runs of days from `start` to `end`, inclusive, over which the
+/// Discordian weekdays follow one another unbroken, each as the weekday it
+/// begins on and its first and last dates. The week itself is regular, and
+/// runs on across seasons and YOLDs, so only St. Tib's Day, which is in no
+/// week, interrupts it; it is in no run. There are none if `end` is before
+/// `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use ddate::{weekday_streaks, Weekday};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert_eq!(
+///     vec![
+///         (Weekday::Boomtime, date(2016, 2, 1), date(2016, 2, 28)),
+///         (Weekday::SettingOrange, date(2016, 3, 1), date(2016, 3, 31)),
+///     ],
+///     weekday_streaks(date(2016, 2, 1), date(2016, 3, 31))
+/// );
+/// ```
+pub fn weekday_streaks(start: NaiveDate, end: NaiveDate) -> Vec<(Weekday, NaiveDate, NaiveDate)> {
+    let mut streaks = Vec::new();
+    let mut first = Some(start);
+    let tibs = (start.year()..=end.year())
+        .filter_map(|year| year.checked_add(CURSE_OF_GREYFACE))
+        .filter_map(|yold| DiscordianDateComponents::st_tibs_day(yold).ok())
+        .filter_map(|tibs| tibs.to_naive_date())
+        .filter(|&tibs| start <= tibs && tibs <= end);
+    let streak = |first: NaiveDate, last| Some((first.discordian_weekday()?, first, last));
+
+    for tibs in tibs {
+        if let (Some(day), Some(last)) = (first.filter(|&day| day < tibs), tibs.pred_opt()) {
+            streaks.extend(streak(day, last));
+        }
+        first = tibs.succ_opt();
+    }
+    if let Some(day) = first.filter(|&day| day <= end) {
+        streaks.extend(streak(day, end));
+    }
+
+    streaks
+}
+
+/// Returns the Discordian date of today in the local time zone, without
+/// needing the `DiscordianDate` trait or a chrono date.
+///
+/// # Examples
+///
+/// ```
+/// let ddate = ddate::today();
+///
+/// assert!(ddate.yold >= 3183);
+/// ```
+pub fn today() -> DiscordianDateComponents {
+    chrono::Local::now().date_naive().discordian_components()
+}
+
+/// Returns the Discordian date of today in the local time zone, as with
+/// `to_poee`, for one-liners such as `println!("{}", ddate::today_string())`.
+///
+/// # Examples
+///
+/// ```
+/// let ddate = ddate::today_string();
+///
+/// assert!(ddate.contains("YOLD"));
+/// ```
+pub fn today_string() -> String {
+    chrono::Local::now().date_naive().to_poee()
+}
+
+/// Groups the holydays of a YOLD, in calendar order, into clusters in which
+/// each holyday falls within 30 days of the one before it.
+///
+/// No two holydays are ever fewer than 28 days apart, so each cluster is a
+/// seasonal holyday and the apostolic holyday of the next season, 28 days
+/// (or 29 across St. Tib's Day) later. Mungday and Afflux, at either end of
+/// the YOLD, stand alone.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{holyday_clusters, Holyday};
+///
+/// let clusters = holyday_clusters(3183);
+///
+/// assert_eq!(6, clusters.len());
+/// assert_eq!(
+///     vec![Holyday::Chaoflux, Holyday::Mojoday],
+///     clusters[1].iter().map(|&(_, holyday)| holyday).collect::<Vec<_>>()
+/// );
+/// ```
+pub fn holyday_clusters(yold: i32) -> Vec<Vec<(NaiveDate, Holyday)>> {
+    let mut clusters: Vec<Vec<(NaiveDate, Holyday)>> = Vec::new();
+
+    for (date, holyday) in holydays_in_year(yold) {
+        match clusters.last_mut() {
+            Some(cluster)
+                if cluster.last().is_some_and(|&(last, _)| {
+                    date.signed_duration_since(last).num_days() <= 30
+                }) =>
+            {
+                cluster.push((date, holyday))
+            }
+            _ => clusters.push(vec![(date, holyday)]),
+        }
+    }
+
+    clusters
+}
+
+/// Renders date components in the style of `to_poee` with the given names,
+/// optionally noting a Gregorian weekday after the Discordian one.
+fn poee(
+    date: &DiscordianDateComponents,
+    gregorian: Option<chrono::Weekday>,
+    names: &Names,
+) -> String {
+    Poee {
+        date,
+        gregorian,
+        names,
+    }
+    .to_string()
+}
+
+/// Displays date components in the style of `to_poee`; see `poee`.
+struct Poee<'a> {
+    date: &'a DiscordianDateComponents,
+    gregorian: Option<chrono::Weekday>,
+    names: &'a Names,
+}
+
+impl fmt::Display for Poee<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date = self.date;
+
+        let weekday = match date.weekday {
+            Some(weekday) => weekday,
+            None => {
+                f.write_str(self.names.st_tibs())?;
+                if let Some(gregorian) = self.gregorian {
+                    write!(f, " ({})", gregorian)?;
+                }
+                return write!(f, ", in the YOLD {}", date.yold);
+            }
+        };
+
+        f.write_str(self.names.weekday(weekday))?;
+        if let Some(gregorian) = self.gregorian {
+            write!(f, " ({})", gregorian)?;
+        }
+        write!(
+            f,
+            ", the {} day of {} in the YOLD {}",
+            ordinalize(date.day as usize),
+            self.names.season(date.season),
+            date.yold
+        )?;
+
+        match date.holyday() {
+            Some(holyday) => write!(f, "\nCelebrate {}", self.names.holyday(holyday)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Renders date components in the order of a style; see `to_poee_styled`.
+fn styled(date: &DiscordianDateComponents, style: DdateStyle) -> String {
+    match (style, date.weekday) {
+        (DdateStyle::Default, _) => poee(date, None, &Names::default()),
+        (DdateStyle::Reverse, None) => format!("YOLD {}, St. Tib's Day", date.yold),
+        (DdateStyle::Reverse, Some(weekday)) => {
+            let holyday = match date.holyday() {
+                Some(holyday) => format!("\nCelebrate {}", holyday),
+                None => String::with_capacity(0),
+            };
+
+            format!(
+                "YOLD {}, {}, {} day, {}{}",
+                date.yold,
+                date.season,
+                ordinalize(date.day as usize),
+                weekday,
+                holyday
+            )
+        }
+    }
+}
+
+/// Displays date components in the short form of `to_poee_short`.
+struct Short<'a>(&'a DiscordianDateComponents);
+
+impl fmt::Display for Short<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.weekday {
+            Some(weekday) => write!(
+                f,
+                "{}, {} {}, {} YOLD",
+                weekday, self.0.season, self.0.day, self.0.yold
+            ),
+            None => write!(f, "St. Tib's Day, {} YOLD", self.0.yold),
+        }
+    }
+}
+
+/// Whether a Gregorian year is a leap year.
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && year % 100 != 0 || year % 400 == 0
+}
+
+/// Whether a YOLD has a St. Tib's Day.
+fn is_leap_yold(yold: i32) -> bool {
+    is_leap_year(i64::from(yold) - i64::from(CURSE_OF_GREYFACE))
+}
+
+/// Fills the `{weekday}`, `{day}`, `{season}`, and `{yold}` placeholders of
+/// a lore template from the components of a date.
+fn fill_template(template: &str, date: &DiscordianDateComponents) -> String {
+    template
+        .replace("{weekday}", date.weekday.map_or("", Weekday::name))
+        .replace("{day}", &ordinalize(date.day as usize))
+        .replace("{season}", date.season.name())
+        .replace("{yold}", &date.yold.to_string())
+}
+
+/// A helper function to ordinalize a numeral.
+fn ordinalize(num: usize) -> String {
+    let s = num.to_string();
+
+    let suffix = if s.ends_with('1') && !s.ends_with("11") {
+        "st"
+    } else if s.ends_with('2') && !s.ends_with("12") {
+        "nd"
+    } else if s.ends_with('3') && !s.ends_with("13") {
+        "rd"
+    } else {
+        "th"
+    };
+
+    s + suffix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, NaiveDate};
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn day_one_test() {
+        assert_eq!(
+            "Sweetmorn, the 1st day of Chaos in the YOLD 0",
+            ymd(-1166, 1, 1).to_poee()
+        );
+    }
+
+    #[test]
+    fn ante_tibs_test() {
+        assert_eq!(
+            "Prickle-Prickle, the 59th day of Chaos in the YOLD 3166",
+            ymd(2000, 2, 28).to_poee()
+        );
+    }
+
+    #[test]
+    fn tibs_test() {
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3166",
+            ymd(2000, 2, 29).to_poee()
+        );
+    }
+
+    #[test]
+    fn post_tibs_test() {
+        assert_eq!(
+            "Setting Orange, the 60th day of Chaos in the YOLD 3166",
+            ymd(2000, 3, 1).to_poee()
+        );
+    }
+
+    #[test]
+    fn ante_anti_tibs_test() {
+        assert_eq!(
+            "Prickle-Prickle, the 59th day of Chaos in the YOLD 2232",
+            ymd(1066, 2, 28).to_poee()
+        );
+    }
+
+    #[test]
+    fn post_anti_tibs_test() {
+        assert_eq!(
+            "Setting Orange, the 60th day of Chaos in the YOLD 2232",
+            ymd(1066, 3, 1).to_poee()
+        );
+    }
+
+    #[test]
+    fn holy_test() {
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux",
+            ymd(2017, 9, 26).to_poee()
+        );
+    }
+
+    #[test]
+    fn apos_test() {
+        assert_eq!(
+            "Boomtime, the 5th day of The Aftermath in the YOLD 3183\nCelebrate Maladay",
+            ymd(2017, 10, 24).to_poee()
+        );
+    }
+
+    #[test]
+    fn gregorian_weekday_test() {
+        assert_eq!(
+            "Pungenday (Sat), the 16th day of The Aftermath in the YOLD 3183",
+            ymd(2017, 11, 4).with_gregorian_weekday()
+        );
+        assert_eq!(
+            "St. Tib's Day (Tue), in the YOLD 3166",
+            ymd(2000, 2, 29).with_gregorian_weekday()
+        );
+    }
+
+    #[test]
+    fn tree_test() {
+        let trees: Vec<_> = Season::ALL.iter().map(|s| s.sacred_tree()).collect();
+        assert_eq!(vec!["Rowan", "Oak", "Hazel", "Elder", "Yew"], trees);
+
+        let trees: Vec<_> = Weekday::ALL.iter().map(|w| w.tree()).collect();
+        assert_eq!(vec!["Birch", "Ash", "Alder", "Holly", "Willow"], trees);
+
+        assert_eq!("Rowan", ymd(2017, 1, 1).ruling_tree());
+        assert_eq!("Oak", ymd(2017, 3, 15).ruling_tree());
+        assert_eq!("Hazel", ymd(2017, 5, 27).ruling_tree());
+        assert_eq!("Elder", ymd(2017, 8, 8).ruling_tree());
+        assert_eq!("Yew", ymd(2017, 12, 31).ruling_tree());
+        assert_eq!("the Golden Apple Tree", ymd(2000, 2, 29).ruling_tree());
+    }
+
+    #[test]
+    fn to_discordian_tree_test() {
+        assert_eq!(
+            "Boomtime, the 5th day of The Aftermath in the YOLD 3183\nCelebrate Maladay\nUnder the Yew and the Ash",
+            ymd(2017, 10, 24).to_discordian_tree()
+        );
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3166\nUnder the Golden Apple Tree",
+            ymd(2000, 2, 29).to_discordian_tree()
+        );
+    }
+
+    #[test]
+    fn days_left_in_yold_test() {
+        for &year in &[2016, 2017, 2000, 1900] {
+            assert_eq!(364, ymd(year, 1, 1).days_left_in_yold(), "{}", year);
+            assert_eq!(0, ymd(year, 12, 31).days_left_in_yold(), "{}", year);
+            assert_eq!(306, ymd(year, 2, 28).days_left_in_yold(), "{}", year);
+            assert_eq!(305, ymd(year, 3, 1).days_left_in_yold(), "{}", year);
+        }
+        assert_eq!(306, ymd(2016, 2, 29).days_left_in_yold());
+
+        // Counts down by one a day, but for St. Tib's Day.
+        let mut left = 365;
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            if !date.discordian_components().st_tibs {
+                left -= 1;
+            }
+            assert_eq!(left, date.days_left_in_yold(), "{}", date);
+        }
+    }
+
+    #[test]
+    fn holydays_remaining_test() {
+        assert_eq!(10, ymd(2017, 1, 1).holydays_remaining());
+        assert_eq!(9, ymd(2017, 1, 5).holydays_remaining());
+        assert_eq!(8, ymd(2000, 2, 29).holydays_remaining());
+        assert_eq!(1, ymd(2017, 10, 24).holydays_remaining());
+        assert_eq!(0, ymd(2017, 12, 8).holydays_remaining());
+        assert_eq!(0, ymd(2016, 12, 31).holydays_remaining());
+    }
+
+    #[test]
+    fn to_naive_date_test() {
+        for &year in &[-1166, 1900, 2000, 2017] {
+            let mut date = ymd(year, 1, 1);
+            while date.year() == year {
+                assert_eq!(Some(date), date.discordian_components().to_naive_date());
+                date = date.succ_opt().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn herb_test() {
+        let mut herbs: Vec<_> = Season::ALL.iter().map(|s| s.herb()).collect();
+        herbs.extend(Weekday::ALL.iter().map(|w| w.herb()));
+        herbs.sort_unstable();
+        herbs.dedup();
+        assert_eq!(10, herbs.len());
+
+        assert_eq!("St. John's Wort", ymd(2017, 5, 27).ruling_herb());
+        assert_eq!("Mandrake", ymd(2000, 2, 29).ruling_herb());
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3166\nHerb: Mandrake",
+            ymd(2000, 2, 29).to_discordian_herb()
+        );
+    }
+
+    #[test]
+    fn holyday_test() {
+        for &holyday in &Holyday::ALL {
+            let date = holyday.date_in_year(3183).unwrap();
+            assert_eq!(Some(holyday), date.holyday(), "{}", date);
+        }
+        assert!(!ymd(2017, 1, 6).is_holyday());
+        assert!(!ymd(2000, 2, 29).is_holyday());
+    }
+
+    #[test]
+    fn near_holyday_test() {
+        assert_eq!(Some(Holyday::Bureflux), ymd(2017, 9, 23).near_holyday(3));
+        assert_eq!(Some(Holyday::Bureflux), ymd(2017, 9, 29).near_holyday(3));
+        assert_eq!(None, ymd(2017, 9, 22).near_holyday(3));
+        assert_eq!(None, ymd(2017, 9, 30).near_holyday(3));
+        assert_eq!(Some(Holyday::Mungday), ymd(2016, 12, 31).near_holyday(5));
+        assert_eq!(None, ymd(2018, 1, 1).near_holyday(3));
+    }
+
+    #[test]
+    fn nearest_holyday_test() {
+        let bureflux = (ymd(2017, 9, 26), Holyday::Bureflux);
+        let maladay = (ymd(2017, 10, 24), Holyday::Maladay);
+
+        assert_eq!(Some(bureflux), ymd(2017, 9, 30).nearest_holyday());
+        assert_eq!(Some(bureflux), ymd(2017, 10, 9).nearest_holyday());
+        assert_eq!(Some(maladay), ymd(2017, 10, 10).nearest_holyday());
+        assert_eq!(Some(maladay), ymd(2017, 10, 20).nearest_holyday());
+        assert_eq!(Some(bureflux), ymd(2017, 9, 26).nearest_holyday());
+        assert_eq!(
+            Some((ymd(2018, 1, 5), Holyday::Mungday)),
+            ymd(2017, 12, 31).nearest_holyday()
+        );
+
+        for date in ymd(2015, 1, 1).iter_days().take(800) {
+            let (day, holyday) = date.nearest_holyday().unwrap();
+            let distance = day.signed_duration_since(date).num_days().abs();
+
+            assert!(distance <= 23, "{}", date);
+            assert_eq!(Some(holyday), date.near_holyday(distance as u8), "{}", date);
+        }
+    }
+
+    #[test]
+    fn both_weeks_test() {
+        let weeks = |date: NaiveDate| {
+            let (iso, week) = date.both_weeks();
+            (iso.year(), iso.week(), week)
+        };
+
+        assert_eq!((2017, 44, Some(62)), weeks(ymd(2017, 11, 4)));
+        assert_eq!((2016, 9, None), weeks(ymd(2016, 2, 29)));
+        // The first and last days of a YOLD are always in its first and last
+        // Discordian weeks, but may be in ISO weeks of other years.
+        assert_eq!((2016, 52, Some(1)), weeks(ymd(2017, 1, 1)));
+        assert_eq!((2019, 1, Some(73)), weeks(ymd(2018, 12, 31)));
+    }
+
+    #[test]
+    fn both_weekdays_test() {
+        assert_eq!(
+            (chrono::Weekday::Tue, Some(Weekday::PricklePrickle)),
+            ymd(2017, 9, 26).both_weekdays()
+        );
+        assert_eq!(
+            (chrono::Weekday::Tue, None),
+            ymd(2000, 2, 29).both_weekdays()
+        );
+    }
+
+    #[test]
+    fn short_max_len_test() {
+        let mut date = ymd(2000, 1, 1);
+        while date.year() == 2000 {
+            let (len, buf) = date.to_poee_short_array();
+            assert_eq!(date.to_poee_short().as_bytes(), &buf[..len]);
+            date = date.succ_opt().unwrap();
+        }
+
+        let longest = DiscordianDateComponents {
+            yold: i32::MIN,
+            season: Season::TheAftermath,
+            day: 73,
+            st_tibs: false,
+            weekday: Some(Weekday::PricklePrickle),
+        };
+        assert_eq!(POEE_SHORT_MAX_LEN, Short(&longest).to_string().len());
+        assert_eq!("St. Tib's Day, 3166 YOLD", ymd(2000, 2, 29).to_poee_short());
+    }
+
+    #[test]
+    fn prompt_segment_test() {
+        let segment = ymd(2017, 9, 26).prompt_segment();
+        assert!(!segment.contains('\n'));
+        assert!(segment.starts_with("PP "));
+        assert_eq!("PP 50 Bcy 3183 \u{2605}", segment);
+
+        assert_eq!("Tib 3166", ymd(2000, 2, 29).prompt_segment());
+        assert_eq!(
+            "Pungenday 16 The Aftermath 3183",
+            ymd(2017, 11, 4).prompt_segment_with(Abbreviation::Full)
+        );
+    }
+
+    #[test]
+    fn crystal_test() {
+        let mut crystals: Vec<_> = CRYSTAL_GUIDE.iter().flatten().copied().collect();
+        assert!(crystals.iter().all(|crystal| !crystal.is_empty()));
+        crystals.sort_unstable();
+        crystals.dedup();
+        assert_eq!(25, crystals.len());
+
+        let mut crystals: Vec<_> = Holyday::ALL.iter().map(|h| h.crystal()).collect();
+        crystals.sort_unstable();
+        crystals.dedup();
+        assert_eq!(10, crystals.len());
+
+        assert_eq!("Obsidian", ymd(2017, 1, 1).ruling_crystal());
+        assert_eq!("Citrine", ymd(2017, 12, 31).ruling_crystal());
+        assert_eq!("Howlite", ymd(2017, 9, 26).ruling_crystal());
+        assert_eq!("Amorphous Quartz", ymd(2000, 2, 29).ruling_crystal());
+    }
+
+    #[test]
+    fn to_poee_annotated_test() {
+        for &date in &[
+            ymd(2017, 11, 4),
+            ymd(2017, 9, 26),
+            ymd(2016, 2, 29),
+            ymd(-44, 3, 15),
+            ymd(12017, 1, 1),
+        ] {
+            let annotated = date.to_poee_annotated();
+            let first = annotated.lines().next().unwrap();
+            assert!(
+                first.ends_with(&format!(" ({})", date)),
+                "{:?} for {}",
+                annotated,
+                date
+            );
+            assert_eq!(
+                date.to_poee(),
+                annotated.replacen(&format!(" ({})", date), "", 1)
+            );
+        }
+
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183 (2017-09-26)\nCelebrate Bureflux",
+            ymd(2017, 9, 26).to_poee_annotated()
+        );
+    }
+
+    #[test]
+    fn season_phase_test() {
+        assert_eq!(Some("early"), ymd(2017, 1, 24).season_phase());
+        assert_eq!(Some("mid"), ymd(2017, 1, 25).season_phase());
+        assert_eq!(Some("mid"), ymd(2017, 2, 18).season_phase());
+        assert_eq!(Some("late"), ymd(2017, 2, 19).season_phase());
+        assert_eq!(None, ymd(2000, 2, 29).season_phase());
+
+        // Every boundary, in a season other than Chaos.
+        for &(day, phase) in &[
+            (1, "early"),
+            (24, "early"),
+            (25, "mid"),
+            (49, "mid"),
+            (50, "late"),
+            (73, "late"),
+        ] {
+            let date = DiscordianDateComponents::from_parts(3183, Season::Confusion, day)
+                .unwrap()
+                .to_naive_date()
+                .unwrap();
+            assert_eq!(Some(phase), date.season_phase(), "Confusion {}", day);
+        }
+    }
+
+    #[test]
+    fn weather_divination_test() {
+        let mut forecasts = Vec::new();
+        let mut date = ymd(2017, 1, 1);
+        while date.year() == 2017 {
+            if !date.is_holyday() {
+                let divination = date.to_poee_with_weather_divination();
+                forecasts.push(divination.lines().nth(1).unwrap().to_string());
+            }
+            date = date.succ_opt().unwrap();
+        }
+        forecasts.sort_unstable();
+        forecasts.dedup();
+        assert_eq!(25, forecasts.len());
+        assert!(forecasts
+            .iter()
+            .all(|forecast| forecast.len() > "Expected weather: ".len()));
+
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\n\
+             Celebrate Bureflux\n\
+             Holyday weather: Forecast withheld pending the proper forms",
+            ymd(2017, 9, 26).to_poee_with_weather_divination()
+        );
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3166\nSt. Tib's weather: All bets are off",
+            ymd(2000, 2, 29).to_poee_with_weather_divination()
+        );
+    }
+
+    #[test]
+    fn holydays_in_gregorian_year_test() {
+        let expected = vec![
+            (ymd(2017, 1, 5), Holyday::Mungday),
+            (ymd(2017, 2, 19), Holyday::Chaoflux),
+            (ymd(2017, 3, 19), Holyday::Mojoday),
+            (ymd(2017, 5, 3), Holyday::Discoflux),
+            (ymd(2017, 5, 31), Holyday::Syaday),
+            (ymd(2017, 7, 15), Holyday::Confuflux),
+            (ymd(2017, 8, 12), Holyday::Zaraday),
+            (ymd(2017, 9, 26), Holyday::Bureflux),
+            (ymd(2017, 10, 24), Holyday::Maladay),
+            (ymd(2017, 12, 8), Holyday::Afflux),
+        ];
+
+        assert_eq!(expected, holydays_in_gregorian_year(2017));
+        assert_eq!(expected, holydays_in_year(3183));
+        assert!(holydays_in_gregorian_year(2016)
+            .iter()
+            .all(|(date, holyday)| date.holyday() == Some(*holyday)));
+    }
+
+    #[test]
+    fn erisian_day_test() {
+        for &(date, number) in &[
+            (ymd(2016, 2, 28), 59),
+            (ymd(2016, 2, 29), 60),
+            (ymd(2016, 3, 1), 61),
+            (ymd(2016, 12, 31), 366),
+            (ymd(2017, 1, 1), 1),
+        ] {
+            assert_eq!(number, date.erisian_day_number(), "{}", date);
+            assert_eq!(
+                Ok(date.discordian_components()),
+                from_erisian_day(date.year() + 1166, number)
+            );
+        }
+
+        assert_eq!(
+            Err(DdateError::NoStTibsDay(3183)),
+            from_erisian_day(3183, 60)
+        );
+        assert_eq!(
+            Err(DdateError::ErisianDayOutOfRange(0)),
+            from_erisian_day(3183, 0)
+        );
+        assert_eq!(
+            Err(DdateError::ErisianDayOutOfRange(367)),
+            from_erisian_day(3182, 367)
+        );
+    }
+
+    #[test]
+    fn st_tibs_extreme_yold_test() {
+        assert_eq!(
+            Err(DdateError::NoStTibsDay(i32::MIN)),
+            DiscordianDateComponents::st_tibs_day(i32::MIN)
+        );
+        assert!(DiscordianDateComponents::st_tibs_day(i32::MIN + 2).is_ok());
+        assert!(DiscordianDateComponents::st_tibs_day(i32::MAX - 1).is_ok());
+    }
+
+    #[test]
+    fn celebration_ideas_test() {
+        for &holyday in &Holyday::ALL {
+            let ideas = holyday.celebration_ideas();
+            assert!(!ideas.is_empty(), "{}", holyday);
+            assert!(ideas.iter().all(|idea| !idea.is_empty()), "{}", holyday);
+        }
+    }
+
+    #[test]
+    fn span_to_test() {
+        assert_eq!(45, Holyday::Mungday.span_to(Holyday::Chaoflux, 3183));
+        assert_eq!(45, Holyday::Maladay.span_to(Holyday::Afflux, 3183));
+        assert_eq!(28, Holyday::Chaoflux.span_to(Holyday::Mojoday, 3183));
+        assert_eq!(29, Holyday::Chaoflux.span_to(Holyday::Mojoday, 3182));
+        assert_eq!(28, Holyday::Bureflux.span_to(Holyday::Maladay, 3182));
+        assert_eq!(-28, Holyday::Mojoday.span_to(Holyday::Chaoflux, 3183));
+        assert_eq!(0, Holyday::Syaday.span_to(Holyday::Syaday, 3183));
+
+        for &yold in &[3182, 3183] {
+            for &from in &Holyday::ALL {
+                for &to in &Holyday::ALL {
+                    let days = to
+                        .date_in_year(yold)
+                        .unwrap()
+                        .signed_duration_since(from.date_in_year(yold).unwrap())
+                        .num_days();
+                    assert_eq!(days, from.span_to(to, yold), "{} to {}", from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_poee_with_doy_test() {
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3182 (day 60 of 366)",
+            ymd(2016, 2, 29).to_poee_with_doy()
+        );
+        assert_eq!(
+            "Setting Orange, the 60th day of Chaos in the YOLD 3182 (day 61 of 366)",
+            ymd(2016, 3, 1).to_poee_with_doy()
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183 (day 269 of 365)\n\
+             Celebrate Bureflux",
+            ymd(2017, 9, 26).to_poee_with_doy()
+        );
+
+        let mut date = ymd(2016, 1, 1);
+        while date.year() < 2018 {
+            let ddate = date.discordian_components();
+            assert_eq!(date.ordinal() as u16, ddate.day_of_year(), "{}", date);
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn numerology_test() {
+        assert_eq!(0, reduce_to_life_path(0));
+        assert_eq!(9, reduce_to_life_path(9));
+        assert_eq!(1, reduce_to_life_path(10));
+        assert_eq!(11, reduce_to_life_path(11));
+        assert_eq!(11, reduce_to_life_path(38));
+        assert_eq!(22, reduce_to_life_path(22));
+        assert_eq!(22, reduce_to_life_path(499));
+        assert_eq!(33, reduce_to_life_path(33));
+        assert_eq!(8, reduce_to_life_path(44));
+        assert_eq!(9, reduce_to_life_path(99));
+        assert_eq!(3, reduce_to_life_path(u32::MAX));
+
+        let masters = [11, 22, 33];
+        for n in 1..100_000 {
+            let path = reduce_to_life_path(n);
+            assert!((1..=9).contains(&path) || masters.contains(&path), "{}", n);
+        }
+
+        let mut paths = std::collections::BTreeSet::new();
+        let mut date = ymd(2016, 1, 1);
+        while date.year() < 2018 {
+            let reading = date.to_poee_with_numerology();
+            paths.insert(reading.rsplit('\n').next().unwrap().to_string());
+            date = date.succ_opt().unwrap();
+        }
+        assert!(paths.contains("Numerology: Life Path 11 (The Illuminator)"));
+        assert!(paths.contains("Numerology: Life Path 33 (The Master Teacher)"));
+        assert!(paths.contains("Numerology: Path 0 (The Void)"));
+        assert_eq!(13, paths.len());
+
+        assert_eq!(
+            "Numerology: Life Path 22 (The Master Builder)",
+            ymd(2017, 10, 21)
+                .to_poee_with_numerology()
+                .rsplit('\n')
+                .next()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn hash_eq_test() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(date: &DiscordianDateComponents) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            date.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let from = DiscordianDateComponents::from(&ymd(2017, 11, 4));
+        let from_parts =
+            DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+        assert_eq!(from, from_parts);
+        assert_eq!(hash(&from), hash(&from_parts));
+
+        let tibs = DiscordianDateComponents::from(&ymd(2016, 2, 29));
+        assert_eq!(DiscordianDateComponents::st_tibs_day(3182), Ok(tibs));
+        assert_ne!(DiscordianDateComponents::st_tibs_day(3178), Ok(tibs));
+        assert_ne!(
+            DiscordianDateComponents::from_parts(3182, Season::Chaos, 59),
+            Ok(tibs)
+        );
+        assert_ne!(
+            DiscordianDateComponents::from_parts(3182, Season::Chaos, 60),
+            Ok(tibs)
+        );
+
+        let mut set = HashSet::new();
+        let mut date = ymd(2016, 1, 1);
+        while date.year() < 2018 {
+            assert!(set.insert(date.discordian_components()), "{}", date);
+            date = date.succ_opt().unwrap();
+        }
+        assert_eq!(366 + 365, set.len());
+
+        let mut date = ymd(2016, 1, 1);
+        while date.year() < 2018 {
+            let ddate = date.discordian_components();
+            let rebuilt = match ddate.weekday {
+                Some(_) => {
+                    DiscordianDateComponents::from_parts(ddate.yold, ddate.season, ddate.day)
+                }
+                None => DiscordianDateComponents::st_tibs_day(ddate.yold),
+            }
+            .unwrap();
+            assert!(set.remove(&rebuilt), "{}", date);
+            date = date.succ_opt().unwrap();
+        }
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn to_poee_custom_test() {
+        let bespoke = |date: &DiscordianDateComponents| match date.weekday {
+            Some(weekday) => format!(
+                "{} of {} ({}), {}",
+                ordinalize(date.day as usize),
+                date.season.abbreviation(),
+                weekday.abbreviation(),
+                date.yold
+            ),
+            None => format!("Tib's, {}", date.yold),
+        };
+
+        assert_eq!(
+            "16th of Afm (PD), 3183",
+            ymd(2017, 11, 4).to_poee_custom(bespoke)
+        );
+        assert_eq!("Tib's, 3182", ymd(2016, 2, 29).to_poee_custom(bespoke));
+    }
+
+    #[test]
+    fn chaos_reading_test() {
+        let mut phrases = CHAOS_ORACLE.to_vec();
+        assert!(phrases.iter().all(|phrase| !phrase.is_empty()));
+        phrases.sort_unstable();
+        phrases.dedup();
+        assert_eq!(73, phrases.len());
+
+        let reading = |date: NaiveDate| {
+            date.to_poee_with_chaos_reading()
+                .rsplit("The oracle says: ")
+                .next()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(CHAOS_ORACLE[0], reading(ymd(2017, 1, 1)));
+        assert_eq!(CHAOS_ORACLE[72], reading(ymd(2017, 12, 31)));
+        assert_eq!(
+            format!("Bureaucracy overflows! {}", CHAOS_ORACLE[49]),
+            reading(ymd(2017, 9, 26))
+        );
+        assert_eq!(
+            "The oracle is out to lunch; ask again tomorrow.",
+            reading(ymd(2016, 2, 29))
+        );
+    }
+
+    #[test]
+    fn holyday_clusters_test() {
+        let clusters = holyday_clusters(3182);
+
+        assert_eq!(
+            vec![
+                vec![(ymd(2016, 1, 5), Holyday::Mungday)],
+                vec![
+                    (ymd(2016, 2, 19), Holyday::Chaoflux),
+                    (ymd(2016, 3, 19), Holyday::Mojoday)
+                ],
+                vec![
+                    (ymd(2016, 5, 3), Holyday::Discoflux),
+                    (ymd(2016, 5, 31), Holyday::Syaday)
+                ],
+                vec![
+                    (ymd(2016, 7, 15), Holyday::Confuflux),
+                    (ymd(2016, 8, 12), Holyday::Zaraday)
+                ],
+                vec![
+                    (ymd(2016, 9, 26), Holyday::Bureflux),
+                    (ymd(2016, 10, 24), Holyday::Maladay)
+                ],
+                vec![(ymd(2016, 12, 8), Holyday::Afflux)],
+            ],
+            clusters
+        );
+    }
+
+    #[test]
+    fn mantra_test() {
+        let mut mantras: Vec<_> = MANTRAS.iter().flatten().copied().collect();
+        mantras.sort_unstable();
+        mantras.dedup();
+        assert_eq!(25, mantras.len());
+
+        let mut mantras = HOLYDAY_MANTRAS.to_vec();
+        mantras.sort_unstable();
+        mantras.dedup();
+        assert_eq!(10, mantras.len());
+
+        let bureflux = ymd(2017, 9, 26).to_discordian_mantra();
+        assert!(bureflux.contains(MANTRAS[3][3]));
+        assert!(bureflux.contains(HOLYDAY_MANTRAS[Holyday::Bureflux as usize]));
+
+        assert_eq!((MANTRAS[0][0], None), ymd(2017, 1, 1).mantra_words());
+        assert_eq!(
+            (
+                "I am the day that is not, in the week that never was.",
+                None
+            ),
+            ymd(2016, 2, 29).mantra_words()
+        );
+    }
+
+    #[test]
+    fn pentabarf_tone_test() {
+        let tones: Vec<_> = (1..=5)
+            .map(|day| ymd(2017, 1, day).pentabarf_tone())
+            .collect();
+        assert_eq!(
+            vec![
+                Some("Sweet"),
+                Some("Boom"),
+                Some("Pungent"),
+                Some("Prickle"),
+                Some("Orange")
+            ],
+            tones
+        );
+        assert_eq!(None, ymd(2016, 2, 29).pentabarf_tone());
+    }
+
+    #[test]
+    fn to_poee_styled_test() {
+        let date = ymd(2017, 9, 26);
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux",
+            date.to_poee_styled(DdateStyle::Default)
+        );
+        assert_eq!(
+            "YOLD 3183, Bureaucracy, 50th day, Prickle-Prickle\nCelebrate Bureflux",
+            date.to_poee_styled(DdateStyle::Reverse)
+        );
+
+        let tibs = ymd(2016, 2, 29);
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3182",
+            tibs.to_poee_styled(DdateStyle::Default)
+        );
+        assert_eq!(
+            "YOLD 3182, St. Tib's Day",
+            tibs.to_poee_styled(DdateStyle::Reverse)
+        );
+    }
+
+    #[test]
+    fn affirmation_test() {
+        assert!(DAILY_AFFIRMATIONS.iter().all(|a| a.len() < 80));
+        let mut affirmations = DAILY_AFFIRMATIONS.to_vec();
+        affirmations.sort_unstable();
+        affirmations.dedup();
+        assert_eq!(366, affirmations.len());
+
+        for &(date, ordinal) in &[
+            (ymd(2017, 1, 1), 1),
+            (ymd(2017, 2, 28), 59),
+            (ymd(2016, 2, 29), 60),
+            (ymd(2017, 3, 1), 61),
+            (ymd(2017, 12, 30), 365),
+            (ymd(2017, 12, 31), 366),
+            (ymd(2016, 12, 31), 366),
+        ] {
+            assert_eq!(DAILY_AFFIRMATIONS[ordinal - 1], date.affirmation());
+            assert_eq!(date.affirmation(), date.to_discordian_affirmation());
+        }
+
+        // St. Tib's Day always has its own affirmation, which no other day
+        // of any YOLD shares.
+        assert_eq!(
+            ymd(2016, 2, 29).affirmation(),
+            ymd(2000, 2, 29).affirmation()
+        );
+        assert_ne!(
+            ymd(2017, 2, 28).affirmation(),
+            ymd(2016, 2, 29).affirmation()
+        );
+        assert_ne!(
+            ymd(2017, 3, 1).affirmation(),
+            ymd(2016, 2, 29).affirmation()
+        );
+    }
+
+    #[test]
+    fn twenty_threes_test() {
+        // Discord 23, YOLD 3183.
+        assert_eq!(
+            vec!["the 23rd day of the season"],
+            ymd(2017, 4, 6).twenty_threes()
+        );
+        // YOLD 3389 = 3 + 3 + 8 + 9 = 23.
+        assert_eq!(
+            vec!["a YOLD whose digits add up to 23"],
+            ymd(2223, 11, 4).twenty_threes()
+        );
+        // YOLD 3197 = 23 × 139.
+        assert_eq!(
+            vec!["a YOLD divisible by 23"],
+            ymd(2031, 11, 4).twenty_threes()
+        );
+        // YOLD -3389.
+        assert_eq!(
+            vec![
+                "the 23rd day of the season",
+                "the 23rd of the Gregorian month",
+                "a YOLD whose digits add up to 23",
+            ],
+            ymd(-4555, 1, 23).twenty_threes()
+        );
+        assert!(ymd(2016, 2, 29).twenty_threes().is_empty());
+        assert!(ymd(-1166, 11, 4).twenty_threes().is_empty());
+    }
+
+    #[test]
+    fn to_sortable_filename_test() {
+        assert_eq!(
+            "3183-5-16-pungenday",
+            ymd(2017, 11, 4).to_sortable_filename()
+        );
+        assert_eq!(
+            "3183-1-05-setting-orange",
+            ymd(2017, 1, 5).to_sortable_filename()
+        );
+        assert_eq!(
+            "3182-1-59-prickle-prickle",
+            ymd(2016, 2, 28).to_sortable_filename()
+        );
+        assert_eq!(
+            "3182-1-59-st-tibs-day",
+            ymd(2016, 2, 29).to_sortable_filename()
+        );
+        assert_eq!(
+            "3182-1-60-setting-orange",
+            ymd(2016, 3, 1).to_sortable_filename()
+        );
+        assert_eq!(
+            "0001-1-01-sweetmorn",
+            ymd(-1165, 1, 1).to_sortable_filename()
+        );
+
+        // Lexical order is calendar order, across St. Tib's Days and YOLDs.
+        let names: Vec<String> = ymd(2015, 12, 1)
+            .iter_days()
+            .take(1000)
+            .map(|date| date.to_sortable_filename())
+            .collect();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn i64_test() {
+        for &date in &[
+            ymd(2017, 11, 4),
+            ymd(2016, 2, 28),
+            ymd(2016, 2, 29),
+            ymd(2016, 3, 1),
+            ymd(2016, 12, 31),
+            ymd(-1171, 6, 1),
+            ymd(-1166, 12, 31),
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+        ] {
+            let ddate = date.discordian_components();
+            assert_eq!(
+                Some(ddate),
+                DiscordianDateComponents::from_i64(ddate.to_i64())
+            );
+        }
+
+        assert_eq!(3_182_060, ymd(2016, 2, 29).discordian_components().to_i64());
+        assert_eq!(-4_953, ymd(-1171, 2, 16).discordian_components().to_i64());
+        assert_eq!(
+            from_yold_ordinal(i32::MIN, 60).ok(),
+            DiscordianDateComponents::from_i64(i64::from(i32::MIN) * 1000 + 60)
+        );
+
+        // Ordinals out of range of their YOLD, and YOLDs out of range.
+        for &id in &[3_183_000, 3_183_366, 3_183_999, -4_000, -4_367] {
+            assert_eq!(None, DiscordianDateComponents::from_i64(id), "{}", id);
+        }
+        assert_eq!(None, DiscordianDateComponents::from_i64(i64::MAX));
+        assert_eq!(None, DiscordianDateComponents::from_i64(i64::MIN));
+
+        // Sorting by identifier is sorting by date.
+        let ids: Vec<i64> = ymd(-1170, 12, 1)
+            .iter_days()
+            .take(500)
+            .map(|date| date.discordian_components().to_i64())
+            .collect();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn sort_key_test() {
+        assert_eq!(3_182_059, ymd(2016, 2, 28).sort_key());
+        assert_eq!(3_182_060, ymd(2016, 2, 29).sort_key());
+        assert_eq!(3_182_061, ymd(2016, 3, 1).sort_key());
+        assert_eq!(3_183_061, ymd(2017, 3, 1).sort_key());
+        assert_eq!(3_183_074, ymd(2017, 3, 14).sort_key());
+        assert_eq!(3_183_101, ymd(2017, 3, 15).sort_key());
+        assert_eq!(3_166_473, ymd(2000, 12, 31).sort_key());
+
+        let mut date = ymd(1999, 12, 1);
+        let mut key = date.sort_key();
+        while date < ymd(2001, 2, 1) {
+            date = date.succ_opt().unwrap();
+            assert!(date.sort_key() > key, "{}", date);
+            key = date.sort_key();
+        }
+
+        let old = DiscordianDateComponents::from_parts(-5, Season::TheAftermath, 73).unwrap();
+        let new = DiscordianDateComponents::from_parts(-4, Season::Chaos, 1).unwrap();
+        assert!(old.sort_key() < new.sort_key());
+    }
+
+    #[test]
+    fn discordian_year_test() {
+        let forward: Vec<_> = DiscordianYear::new(3182).collect();
+        let mut backward: Vec<_> = rev_days(3182).collect();
+        assert_eq!(366, backward.len());
+        assert_eq!(
+            DiscordianDateComponents::from_parts(3182, Season::TheAftermath, 73).unwrap(),
+            backward[0]
+        );
+        assert_eq!(
+            DiscordianDateComponents::from_parts(3182, Season::Chaos, 1).unwrap(),
+            backward[365]
+        );
+        assert_eq!(
+            (59, true, 60),
+            (backward[306].day, backward[306].st_tibs, backward[305].day)
+        );
+        assert_eq!(59, backward[307].day);
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let mut date = ymd(2016, 1, 1);
+        for ddate in forward {
+            assert_eq!(Some(date), ddate.to_naive_date());
+            date = date.succ_opt().unwrap();
+        }
+
+        let mut days = DiscordianYear::new(3183);
+        assert_eq!(365, days.len());
+        assert_eq!(365, rev_days(3183).filter(|day| !day.st_tibs).count());
+        for _ in 0..100 {
+            days.next();
+            days.next_back();
+        }
+        assert_eq!(165, days.len());
+        assert_eq!(165, days.count());
+    }
+
+    #[test]
+    fn to_poee_wrapped_test() {
+        let bureflux = ymd(2017, 9, 26);
+        assert_eq!(
+            "Prickle-Prickle, the\n50th day of\nBureaucracy in the\nYOLD 3183\nCelebrate Bureflux",
+            bureflux.to_poee_wrapped(20)
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of\nBureaucracy in the YOLD 3183\nCelebrate Bureflux",
+            bureflux.to_poee_wrapped(40)
+        );
+        assert_eq!(bureflux.to_poee(), bureflux.to_poee_wrapped(80));
+
+        for &width in &[20, 40, 80] {
+            for date in &[ymd(2016, 2, 29), ymd(2017, 11, 4), ymd(2017, 1, 5)] {
+                let wrapped = date.to_poee_wrapped(width);
+                assert!(
+                    wrapped.lines().all(|line| line.len() <= width),
+                    "{}",
+                    wrapped
+                );
+                assert_eq!(
+                    date.to_poee().split_whitespace().collect::<Vec<_>>(),
+                    wrapped.split_whitespace().collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chaos_probability_test() {
+        for &(date, _) in &holydays_in_year(3183) {
+            assert_eq!(100, date.chaos_probability(), "{}", date);
+        }
+        assert_eq!(23, ymd(2016, 2, 29).chaos_probability());
+        assert_eq!(23, ymd(2000, 2, 29).chaos_probability());
+
+        // The 1st of Chaos, 3183: 1 * 1 + 83.
+        assert_eq!(84, ymd(2017, 1, 1).chaos_probability());
+        // The 73rd of The Aftermath, 3183: (73 * 5 + 83) % 101.
+        assert_eq!(44, ymd(2017, 12, 31).chaos_probability());
+
+        for ddate in DiscordianYear::new(3182).chain(DiscordianYear::new(-1)) {
+            let date = match ddate.to_naive_date() {
+                Some(date) => date,
+                None => continue,
+            };
+            assert!(date.chaos_probability() <= 100, "{}", date);
+        }
+
+        assert_eq!(
+            format!(
+                "{}\nProbability of Chaos today: 100%",
+                ymd(2017, 9, 26).to_poee()
+            ),
+            ymd(2017, 9, 26).to_poee_with_probability()
+        );
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3182\nProbability of Chaos today: 23%",
+            ymd(2016, 2, 29).to_poee_with_probability()
+        );
+    }
+
+    #[test]
+    fn to_poee_without_year_test() {
+        assert_eq!(
+            "Pungenday, the 16th day of The Aftermath",
+            ymd(2017, 11, 4).to_poee_without_year()
+        );
+        assert_eq!(
+            ymd(2017, 11, 4).to_poee_without_year(),
+            ymd(2016, 11, 4).to_poee_without_year()
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy\nCelebrate Bureflux",
+            ymd(2017, 9, 26).to_poee_without_year()
+        );
+        assert_eq!("St. Tib's Day", ymd(2000, 2, 29).to_poee_without_year());
+
+        for date in &[ymd(2017, 11, 4), ymd(2017, 9, 26), ymd(2000, 2, 29)] {
+            assert!(!date.to_poee_without_year().contains("YOLD"));
+            assert!(!date.to_poee_without_year().contains("31"));
+            assert!(date.to_poee().contains("YOLD"));
+        }
+    }
+
+    #[test]
+    fn to_gregorian_md_test() {
+        let firsts: Vec<_> = Season::ALL
+            .iter()
+            .map(|&season| to_gregorian_md(3183, season, 1))
+            .collect();
+        assert_eq!(
+            vec![
+                Some((1, 1)),
+                Some((3, 15)),
+                Some((5, 27)),
+                Some((8, 8)),
+                Some((10, 20))
+            ],
+            firsts
+        );
+        assert_eq!(
+            firsts,
+            Season::ALL
+                .iter()
+                .map(|&s| to_gregorian_md(3182, s, 1))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (2, 29),
+            DiscordianDateComponents::st_tibs_day(3182)
+                .unwrap()
+                .gregorian_md()
+        );
+        assert_eq!(Some((3, 1)), to_gregorian_md(3182, Season::Chaos, 60));
+        assert_eq!(None, to_gregorian_md(3183, Season::Chaos, 0));
+
+        for ddate in DiscordianYear::new(3182) {
+            let date = ddate.to_naive_date().unwrap();
+            assert_eq!((date.month(), date.day()), ddate.gregorian_md());
+        }
+    }
+
+    #[test]
+    fn fortune_test() {
+        let mut fortunes = FORTUNES.to_vec();
+        fortunes.sort_unstable();
+        fortunes.dedup();
+        assert_eq!(50, fortunes.len());
+
+        let mut date = ymd(-1166, 12, 30);
+        let mut days = date.days_since_curse_of_greyface();
+        assert_eq!(-2, days);
+        while date < ymd(1, 3, 1) {
+            date = date.succ_opt().unwrap();
+            assert_eq!(days + 1, date.days_since_curse_of_greyface(), "{}", date);
+            days += 1;
+        }
+        assert_eq!(
+            ymd(2017, 11, 4)
+                .signed_duration_since(ymd(-1165, 1, 1))
+                .num_days(),
+            ymd(2017, 11, 4).days_since_curse_of_greyface()
+        );
+        assert_eq!(
+            ymd(2016, 2, 29)
+                .signed_duration_since(ymd(-1165, 1, 1))
+                .num_days(),
+            ymd(2016, 2, 29).days_since_curse_of_greyface()
+        );
+
+        // Fifty consecutive days draw every fortune once.
+        let mut drawn: Vec<_> = (0..50)
+            .map(|n| (ymd(2017, 1, 1) + chrono::Duration::days(n)).fortune())
+            .collect();
+        drawn.sort_unstable();
+        assert_eq!(fortunes, drawn);
+        assert_eq!(ymd(2017, 11, 4).fortune(), ymd(2017, 11, 4).fortune());
+
+        assert_eq!([13, 9, 5, 0, 29], ymd(2016, 2, 29).lucky_numbers());
+        assert_eq!([4, 18, 5, 28, 3], ymd(-1167, 1, 4).lucky_numbers());
+        for ddate in DiscordianYear::new(3182) {
+            let numbers = ddate.to_naive_date().unwrap().lucky_numbers();
+            assert!(numbers[0] < 23 && numbers[1] < 19 && numbers[4] < 73);
+            assert!((5..=25).contains(&numbers[2]) && numbers[3] <= 35);
+        }
+        assert_eq!(
+            format!(
+                "{}\nLucky numbers: 13, 9, 5, 0, 29",
+                ymd(2016, 2, 29).to_discordian_fortune()
+            ),
+            ymd(2016, 2, 29).fortune_with_lucky_numbers()
+        );
+    }
+
+    #[test]
+    fn week_of_year_test() {
+        assert_eq!(Some(1), ymd(2017, 1, 1).week_of_year());
+        assert_eq!(Some(1), ymd(2017, 1, 5).week_of_year());
+        assert_eq!(Some(2), ymd(2017, 1, 6).week_of_year());
+        assert_eq!(Some(37), ymd(2017, 7, 3).week_of_year());
+        assert_eq!(Some(73), ymd(2017, 12, 31).week_of_year());
+
+        // St. Tib's Day falls between the Prickle-Prickle and Setting Orange
+        // of the 12th week, without disturbing it.
+        assert_eq!(Some(12), ymd(2016, 2, 28).week_of_year());
+        assert_eq!(None, ymd(2016, 2, 29).week_of_year());
+        assert_eq!(Some(12), ymd(2016, 3, 1).week_of_year());
+        assert_eq!(Some(13), ymd(2016, 3, 2).week_of_year());
+        assert_eq!(Some(73), ymd(2016, 12, 31).week_of_year());
+
+        for ddate in DiscordianYear::new(3183) {
+            let date = ddate.to_naive_date().unwrap();
+            let first = DiscordianDateComponents::from_parts(3183, Season::Chaos, 1).unwrap();
+            let days = date
+                .signed_duration_since(first.to_naive_date().unwrap())
+                .num_days();
+            assert_eq!(Some((days / 5 + 1) as u8), date.week_of_year());
+        }
+    }
+
+    #[test]
+    fn yold_boundaries_test() {
+        // YOLD 0 is 1166 BCE, the proleptic Gregorian year -1166, and YOLD
+        // 1166 is the year 0, a leap year as a multiple of 400.
+        for &(yold, days) in &[
+            (-1, 365),
+            (0, 365),
+            (1, 365),
+            (2, 366),
+            (1166, 366),
+            (3066, 365),
+            (3166, 366),
+            (3182, 366),
+            (3183, 365),
+            (3266, 365),
+            (3566, 366),
+        ] {
+            let year = yold - 1166;
+            assert_eq!(days, days_in_yold(yold), "{}", yold);
+            assert_eq!(NaiveDate::from_ymd_opt(year, 1, 1), yold_start(yold));
+            assert_eq!(NaiveDate::from_ymd_opt(year, 12, 31), yold_end(yold));
+            assert_eq!(
+                i64::from(days) - 1,
+                yold_end(yold)
+                    .unwrap()
+                    .signed_duration_since(yold_start(yold).unwrap())
+                    .num_days()
+            );
+            assert_eq!(yold_end(yold).unwrap().succ_opt(), yold_start(yold + 1));
+
+            let mut date = yold_start(yold).unwrap();
+            for ordinal in 1..=days {
+                let ddate = from_yold_ordinal(yold, ordinal).unwrap();
+                assert_eq!(Some(date), ddate.to_naive_date());
+                assert_eq!(ordinal, ddate.day_of_year());
+                date = date.succ_opt().unwrap();
+            }
+            assert_eq!(
+                Err(DdateError::OrdinalOutOfRange {
+                    yold,
+                    ordinal: days + 1
+                }),
+                from_yold_ordinal(yold, days + 1)
+            );
+            assert!(from_yold_ordinal(yold, 0).is_err());
+        }
+
+        assert_eq!(None, yold_start(i32::MAX));
+        assert_eq!(
+            "day 366 is outside of the 365 days of YOLD 3183",
+            from_yold_ordinal(3183, 366).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn horoscope_test() {
+        let texts = SEASON_HOROSCOPES
+            .iter()
+            .chain(&WEEKDAY_MODIFIERS)
+            .chain(&HOLYDAY_OMENS)
+            .chain(&[lore::ST_TIBS_HOROSCOPE]);
+        assert!(texts.clone().all(|text| !text.trim().is_empty()));
+        let mut texts: Vec<_> = texts.collect();
+        texts.sort_unstable();
+        texts.dedup();
+        assert_eq!(21, texts.len());
+
+        for ddate in DiscordianYear::new(3182) {
+            let horoscope = ddate.to_naive_date().unwrap().daily_horoscope();
+            assert!(horoscope.len() < 300, "{}", horoscope);
+        }
+
+        let bureflux = ymd(2017, 9, 26).daily_horoscope();
+        assert!(bureflux.starts_with(Holyday::Bureflux.omen()));
+        assert!(bureflux.contains(Season::Bureaucracy.horoscope()));
+        assert!(bureflux.ends_with(Weekday::PricklePrickle.horoscope_modifier()));
+        assert!(!ymd(2017, 9, 25).daily_horoscope().contains("omen"));
+
+        assert_eq!(lore::ST_TIBS_HOROSCOPE, ymd(2016, 2, 29).daily_horoscope());
+        assert!(ymd(2016, 2, 29)
+            .to_discordian_horoscope()
+            .starts_with("St. Tib's Day, in the YOLD 3182\nHoroscope: "));
+    }
+
+    #[test]
+    fn with_poee_buffered_test() {
+        for date in ymd(2015, 12, 1).iter_days().take(120) {
+            assert_eq!(date.to_poee(), date.with_poee_buffered(str::to_string));
+        }
+
+        // A longer date, then a shorter one, leaves nothing of the first.
+        let long = ymd(2017, 9, 26).with_poee_buffered(str::to_string);
+        let short = ymd(2016, 2, 29).with_poee_buffered(str::to_string);
+        assert_eq!(ymd(2017, 9, 26).to_poee(), long);
+        assert_eq!(ymd(2016, 2, 29).to_poee(), short);
+
+        // A call within another renders its own date, and leaves the outer
+        // one as it was.
+        let (outer, inner) = ymd(2017, 11, 4).with_poee_buffered(|outer| {
+            let inner = ymd(2017, 1, 1).with_poee_buffered(str::to_string);
+            (outer.to_string(), inner)
+        });
+        assert_eq!(ymd(2017, 11, 4).to_poee(), outer);
+        assert_eq!(ymd(2017, 1, 1).to_poee(), inner);
+
+        let other = std::thread::spawn(|| ymd(2017, 11, 4).with_poee_buffered(str::to_string));
+        assert_eq!(ymd(2017, 11, 4).to_poee(), other.join().unwrap());
+    }
+
+    #[test]
+    fn write_poee_test() {
+        for date in &[ymd(2017, 11, 4), ymd(2017, 9, 26), ymd(2016, 2, 29)] {
+            let mut out = Vec::new();
+            date.write_poee_io(&mut out).unwrap();
+            assert_eq!(date.to_poee().as_bytes(), &out[..]);
+            assert_eq!(date.to_poee().into_bytes(), date.to_poee_bytes());
+
+            let mut out = String::new();
+            date.write_poee(&mut out).unwrap();
+            assert_eq!(date.to_poee(), out);
+        }
+
+        let mut full = [0; 8];
+        assert!(ymd(2017, 11, 4).write_poee_io(&mut &mut full[..]).is_err());
+        assert_eq!(b"Pungenda", &full);
+
+        assert_eq!(
+            "Sweetmorn (Sun), the 1st day of Chaos in the YOLD 3183",
+            ymd(2017, 1, 1).with_gregorian_weekday()
+        );
+        assert_eq!(
+            "St. Tib's Day (Mon), in the YOLD 3182",
+            ymd(2016, 2, 29).with_gregorian_weekday()
+        );
+    }
+
+    #[test]
+    fn random_fact_test() {
+        let mut facts = DISCORDIAN_FACTS.to_vec();
+        facts.sort_unstable();
+        facts.dedup();
+        assert_eq!(23, facts.len());
+
+        let date = ymd(2017, 11, 4);
+        assert_eq!(date.random_fact(), date.random_fact());
+        assert_eq!(DISCORDIAN_FACTS[(16 + 5 + 3183) % 23], date.random_fact());
+        assert_eq!(
+            ymd(2016, 2, 28).random_fact(),
+            ymd(2016, 2, 29).random_fact()
+        );
+
+        // Chaos 1 to 23, then Chaos 24 to 46, of a YOLD.
+        let cycle: Vec<&str> = ymd(2017, 1, 1)
+            .iter_days()
+            .take(46)
+            .map(|date| date.random_fact())
+            .collect();
+        let mut distinct = cycle[..23].to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(23, distinct.len());
+        assert_eq!(cycle[..23], cycle[23..]);
+
+        assert_eq!(
+            format!(
+                "Pungenday, the 16th day of The Aftermath in the YOLD 3183\nFact: {}",
+                date.random_fact()
+            ),
+            date.to_poee_with_random_fact()
+        );
+    }
+
+    #[test]
+    fn st_tibs_ordinal_test() {
+        assert_eq!(Some(60), st_tibs_ordinal(2000));
+        assert_eq!(None, st_tibs_ordinal(2001));
+        assert_eq!(None, st_tibs_ordinal(1900));
+        assert_eq!(st_tibs_ordinal(2016), Some(ymd(2016, 2, 29).ordinal()));
+    }
+
+    #[test]
+    fn st_tibs_years_test() {
+        // 2000, the YOLD 3166, is a leap century; 1900 and 2100 are not.
+        assert_eq!(vec![3158, 3162, 3166, 3170], st_tibs_years(3156, 3170));
+        assert_eq!(vec![3062, 3070], st_tibs_years(3060, 3070));
+        assert_eq!(vec![3262, 3270], st_tibs_years(3260, 3270));
+        assert_eq!(vec![3166], st_tibs_years(3166, 3166));
+        assert!(st_tibs_years(3167, 3169).is_empty());
+        assert!(st_tibs_years(3170, 3160).is_empty());
+        assert_eq!(vec![-2], st_tibs_years(-3, 0));
+
+        for yold in st_tibs_years(3000, 3400) {
+            assert!(DiscordianDateComponents::st_tibs_day(yold).is_ok());
+        }
+    }
+
+    #[test]
+    fn day_label_test() {
+        assert_eq!("Pungenday", ymd(2017, 11, 4).day_label());
+        assert_eq!("Prickle-Prickle", ymd(2000, 2, 28).day_label());
+        assert_eq!("St. Tib's Day", ymd(2000, 2, 29).day_label());
+        assert_eq!(None, ymd(2000, 2, 29).discordian_weekday());
+    }
+
+    #[test]
+    fn packed_test() {
+        let round_trip = |date: DiscordianDateComponents| {
+            let packed = date.to_packed().unwrap();
+            assert_eq!(Ok(date), DiscordianDateComponents::from_packed(packed));
+        };
+
+        // Every day of YOLDs spread across the packable range, including
+        // both ends, negative YOLDs, and leap YOLDs with St. Tib's Days.
+        let yolds = (PACKED_YOLD_MIN..=PACKED_YOLD_MAX).step_by(4099).chain([
+            PACKED_YOLD_MIN,
+            PACKED_YOLD_MAX,
+            -1,
+            0,
+            1,
+            3166,
+            3182,
+        ]);
+        let mut tibs = 0;
+        for yold in yolds {
+            for date in DiscordianYear::new(yold) {
+                tibs += date.st_tibs as usize;
+                round_trip(date);
+            }
+        }
+        assert!(tibs > 100);
+
+        // Every packed value which decodes is the packing of what it decodes to.
+        for packed in (0..=u32::MAX).step_by(65_537).chain([u32::MAX]) {
+            if let Ok(date) = DiscordianDateComponents::from_packed(packed) {
+                assert_eq!(Ok(packed), date.to_packed());
+            }
+        }
+
+        let tibs = DiscordianDateComponents::st_tibs_day(-6).unwrap();
+        assert_eq!(
+            Ok(tibs),
+            DiscordianDateComponents::from_packed(tibs.to_packed().unwrap())
+        );
+        assert_eq!(
+            Err(DdateError::YoldOutOfRange(PACKED_YOLD_MAX + 1)),
+            DiscordianDateComponents::from_parts(PACKED_YOLD_MAX + 1, Season::Chaos, 1)
+                .unwrap()
+                .to_packed()
+        );
+        assert_eq!(
+            Err(DdateError::YoldOutOfRange(PACKED_YOLD_MIN - 1)),
+            DiscordianDateComponents::from_parts(PACKED_YOLD_MIN - 1, Season::Chaos, 1)
+                .unwrap()
+                .to_packed()
+        );
+
+        // A fifth season, a 74th day, a zeroth day, and St. Tib's Day in a
+        // common YOLD, or on a day other than the 59th of Chaos.
+        for packed in [
+            3183 << 11 | 5 << 8 | 1 << 1,
+            3183 << 11 | 74 << 1,
+            3183 << 11,
+            3183 << 11 | 59 << 1 | 1,
+            3182 << 11 | 60 << 1 | 1,
+            3182 << 11 | 1 << 8 | 59 << 1 | 1,
+        ] {
+            assert_eq!(
+                Err(DdateError::InvalidPacked(packed)),
+                DiscordianDateComponents::from_packed(packed)
+            );
+        }
+        assert!(
+            DiscordianDateComponents::from_packed(3182 << 11 | 59 << 1 | 1)
+                .unwrap()
+                .st_tibs
+        );
+    }
+
+    #[test]
+    fn indices_test() {
+        let mut tibs = 0;
+        for yold in [i32::MIN, -6, 0, 3182, 3183, i32::MAX] {
+            for date in DiscordianYear::new(yold) {
+                let indices = date.to_indices();
+                tibs += indices.3 as usize;
+                assert_eq!(Ok(date), DiscordianDateComponents::from_indices(indices));
+            }
+        }
+        assert_eq!(2, tibs);
+
+        let tibs = DiscordianDateComponents::st_tibs_day(3182).unwrap();
+        assert_eq!((3182, 0, 59, true), tibs.to_indices());
+        assert_eq!(
+            Ok(tibs),
+            DiscordianDateComponents::from_indices((3182, 0, 59, true))
+        );
+
+        // A fifth season, a 74th day, a zeroth day, and St. Tib's Day in a
+        // common YOLD, or on a day other than the 59th of Chaos.
+        for indices in [
+            (3183, 5, 1, false),
+            (3183, 0, 74, false),
+            (3183, 0, 0, false),
+            (3183, 0, 59, true),
+            (3182, 0, 60, true),
+            (3182, 1, 59, true),
+        ] {
+            assert_eq!(
+                Err(DdateError::InvalidIndices(indices)),
+                DiscordianDateComponents::from_indices(indices)
+            );
+        }
+    }
+
+    #[test]
+    fn meditation_test() {
+        for date in ymd(2015, 12, 1).iter_days().take(800) {
+            let meditation = date.to_discordian_meditation();
+            let lines: Vec<&str> = meditation.lines().collect();
+            assert_eq!(5, lines.len(), "{}", date);
+            assert!(lines.iter().all(|line| !line.is_empty()), "{}", date);
+            assert_eq!(date.to_poee_short(), lines[4]);
+        }
+
+        let bureflux = ymd(2017, 9, 26).to_discordian_meditation();
+        let lines: Vec<&str> = bureflux.lines().collect();
+        assert_eq!(HOLYDAY_MANTRAS[Holyday::Bureflux as usize], lines[0]);
+        assert_eq!(Season::Bureaucracy.contemplation(), lines[1]);
+        assert!(lines[2].contains("Bureflux"));
+
+        let tibs = ymd(2016, 2, 29).to_discordian_meditation();
+        let lines: Vec<&str> = tibs.lines().collect();
+        assert_eq!(lore::ST_TIBS_AFFIRMATION, lines[0]);
+        assert_eq!(lore::ST_TIBS_CONTEMPLATION, lines[1]);
+        assert_eq!("St. Tib's Day, 3182 YOLD", lines[4]);
+
+        let mut affirmations = WEEKDAY_AFFIRMATIONS.to_vec();
+        affirmations.extend(SEASON_CONTEMPLATIONS);
+        affirmations.sort_unstable();
+        affirmations.dedup();
+        assert_eq!(10, affirmations.len());
+    }
+
+    #[test]
+    fn candidate_dates_test() {
+        let dates = candidate_dates(Season::Chaos, 60, 3180, 3184);
+        assert_eq!(
+            vec![
+                ymd(2014, 3, 1),
+                ymd(2015, 3, 1),
+                ymd(2016, 3, 1),
+                ymd(2017, 3, 1),
+                ymd(2018, 3, 1),
+            ],
+            dates
+        );
+        for date in dates {
+            let ddate = date.discordian_components();
+            assert_eq!((Season::Chaos, 60), (ddate.season, ddate.day));
+        }
+
+        assert_eq!(
+            vec![ymd(2017, 9, 26)],
+            candidate_dates(Season::Bureaucracy, 50, 3183, 3183)
+        );
+        // St. Tib's Day takes up the Gregorian leap day, so days after it
+        // keep their Gregorian date, though their ordinal shifts by one.
+        let dates = candidate_dates(Season::Bureaucracy, 50, 3181, 3183);
+        assert_eq!(
+            vec![ymd(2015, 9, 26), ymd(2016, 9, 26), ymd(2017, 9, 26)],
+            dates
+        );
+        assert_eq!(
+            vec![269, 270, 269],
+            dates.iter().map(|date| date.ordinal()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                ymd(2014, 2, 28),
+                ymd(2015, 2, 28),
+                ymd(2016, 2, 28),
+                ymd(2017, 2, 28),
+                ymd(2018, 2, 28),
+            ],
+            candidate_dates(Season::Chaos, 59, 3180, 3184)
+        );
+        assert!(candidate_dates(Season::Chaos, 74, 3180, 3184).is_empty());
+        assert!(candidate_dates(Season::Chaos, 0, 3180, 3184).is_empty());
+        assert!(candidate_dates(Season::Chaos, 1, 3184, 3180).is_empty());
+    }
+
+    #[test]
+    fn recurrences_of_day_test() {
+        let days = |season, day| recurrences_of_day(season, day, 3180, 3184);
+
+        assert_eq!(
+            vec![
+                ymd(2014, 9, 26),
+                ymd(2015, 9, 26),
+                ymd(2016, 9, 26),
+                ymd(2017, 9, 26),
+                ymd(2018, 9, 26),
+            ],
+            days(Season::Bureaucracy, 50)
+        );
+
+        // The days either side of St. Tib's Day, over a span with one.
+        let before = days(Season::Chaos, 59);
+        let after = days(Season::Chaos, 60);
+        assert_eq!(5, before.len());
+        assert!(before
+            .iter()
+            .all(|date| (date.month(), date.day()) == (2, 28)));
+        assert!(after
+            .iter()
+            .all(|date| (date.month(), date.day()) == (3, 1)));
+        assert_eq!(
+            vec![1, 1, 2, 1, 1],
+            before
+                .iter()
+                .zip(&after)
+                .map(|(before, after)| after.signed_duration_since(*before).num_days())
+                .collect::<Vec<_>>()
+        );
+        assert!(after
+            .iter()
+            .all(|date| !date.discordian_components().st_tibs));
+
+        assert!(days(Season::Chaos, 0).is_empty());
+        assert!(days(Season::TheAftermath, 74).is_empty());
+        assert!(recurrences_of_day(Season::Chaos, 1, 3184, 3180).is_empty());
+        assert_eq!(
+            vec![NaiveDate::MAX],
+            recurrences_of_day(
+                Season::TheAftermath,
+                73,
+                NaiveDate::MAX.discordian_components().yold,
+                NaiveDate::MAX.discordian_components().yold + 1
+            )
+        );
+    }
+
+    #[test]
+    fn gregorian_occurrences_test() {
+        let ordinals =
+            |dates: &[NaiveDate]| -> Vec<u32> { dates.iter().map(|date| date.ordinal()).collect() };
+
+        // After St. Tib's Day, the ordinal shifts by one in the leap YOLDs
+        // 3182 and 3186, though the Gregorian date stays the same.
+        let dates = gregorian_occurrences(Season::Bureaucracy, 50, 3181, 3187);
+        assert!(dates
+            .iter()
+            .all(|date| (date.month(), date.day()) == (9, 26)));
+        assert_eq!(vec![269, 270, 269, 269, 269, 270, 269], ordinals(&dates));
+
+        // Before it, nothing shifts.
+        let dates = gregorian_occurrences(Season::Chaos, 50, 3181, 3187);
+        assert!(dates.iter().all(|date| date.ordinal() == 50));
+        assert_eq!(7, dates.len());
+
+        assert_eq!(
+            candidate_dates(Season::Confusion, 23, 3100, 3200),
+            gregorian_occurrences(Season::Confusion, 23, 3100, 3200)
+        );
+        assert!(gregorian_occurrences(Season::Chaos, 74, 3181, 3187).is_empty());
+    }
+
+    /// Checks that every method of `DiscordianDate` on a date returns
+    /// without panicking, and that the parts of the date agree.
+    fn check_panic_free(date: NaiveDate) {
+        let ddate = date.discordian_components();
+        assert_eq!(i64::from(date.year()) + 1166, i64::from(ddate.yold));
+        assert_eq!(Some(date), ddate.to_naive_date(), "{}", date);
+        assert_eq!(ddate.st_tibs, ddate.weekday.is_none());
+        assert!(ddate.st_tibs || (1..=73).contains(&ddate.day));
+        assert!((1..=366).contains(&date.erisian_day_number()));
+        assert_eq!(
+            Ok(ddate),
+            from_erisian_day(ddate.yold, date.erisian_day_number())
+        );
+        assert_eq!(
+            Ok(ddate),
+            from_yold_ordinal(ddate.yold, ddate.day_of_year())
+        );
+        assert_eq!((date.month(), date.day()), ddate.gregorian_md());
+        assert_eq!(ddate.sort_key(), date.sort_key());
+        if let Ok(packed) = ddate.to_packed() {
+            assert_eq!(Ok(ddate), DiscordianDateComponents::from_packed(packed));
+        }
+
+        let _ = (
+            date.to_poee(),
+            date.with_gregorian_weekday(),
+            date.to_discordian_tree(),
+            date.holydays_remaining(),
+            date.to_discordian_herb(),
+            date.is_holyday(),
+            date.near_holyday(u8::MAX),
+            date.both_weekdays(),
+            date.day_label(),
+            date.to_poee_short_array(),
+            date.prompt_segment_with(Abbreviation::Full),
+            date.to_discordian_crystal(),
+            date.season_phase(),
+        );
+        let _ = (
+            date.to_poee_format("%A %a %B %b %d %e %H %N %n %t %X %Y %. %{ %}"),
+            date.to_poee_with_weather_divination(),
+            date.to_poee_with_doy(),
+            date.to_poee_with_numerology(),
+            date.to_poee_with_chaos_reading(),
+            date.to_discordian_mantra(),
+            date.pentabarf_tone(),
+            date.to_poee_styled(DdateStyle::Reverse),
+            date.to_discordian_affirmation(),
+            date.to_poee_wrapped(1),
+            date.to_poee_with_probability(),
+            date.to_poee_without_year(),
+        );
+        let _ = (
+            date.fortune_with_lucky_numbers(),
+            date.week_of_year(),
+            date.to_discordian_horoscope(),
+            date.to_poee_bytes(),
+            date.to_poee_with_random_fact(),
+            date.to_discordian_meditation(),
+            date.days_since_curse_of_greyface(),
+            holydays_in_gregorian_year(date.year()),
+            candidate_dates(ddate.season, ddate.day, ddate.yold - 1, ddate.yold + 1),
+            yold_start(ddate.yold),
+            yold_end(ddate.yold),
+            DiscordianYear::new(ddate.yold).count(),
+            grid::season_grid(ddate.yold, ddate.season),
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn panic_free_sweep_test() {
+        let extremes = [
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            ymd(-1, 1, 1),
+            ymd(-1166, 1, 1),
+            ymd(-1167, 1, 1),
+        ];
+        for &start in &extremes {
+            for date in start.iter_days().take(800) {
+                check_panic_free(date);
+            }
+            for date in start.iter_days().rev().take(800) {
+                check_panic_free(date);
+            }
+        }
+
+        for date in NaiveDate::MIN.iter_days().step_by(997) {
+            check_panic_free(date);
+        }
+    }
+
+    #[test]
+    fn extreme_yold_test() {
+        for &yold in &[i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX] {
+            assert!(holydays_in_year(yold).is_empty());
+            assert!(holydays_in_gregorian_year(yold).is_empty());
+            assert!(holyday_clusters(yold).is_empty());
+            assert!(candidate_dates(Season::Chaos, 1, yold, yold).is_empty());
+            assert_eq!(None, yold_start(yold));
+            assert_eq!(None, yold_end(yold));
+            assert_eq!(
+                usize::from(days_in_yold(yold)),
+                DiscordianYear::new(yold).count()
+            );
+            assert_eq!(5, grid::yold_grid(yold).len());
+            assert!(Holyday::Mungday.span_to(Holyday::Afflux, yold) > 0);
+
+            let date = DiscordianDateComponents::from_parts(yold, Season::Chaos, 1).unwrap();
+            assert_eq!(None, date.to_naive_date());
+            assert_eq!(Err(DdateError::YoldOutOfRange(yold)), date.to_packed());
+            assert!(!Format::new("%A %B %e %Y %X %.").render(&date).is_empty());
+            assert!(!Names::default().to_poee(&date).is_empty());
+        }
+
+        let mut out = Vec::new();
+        bulk::convert_ordinals(i32::MAX - 1166, &[1], &mut out);
+        assert_eq!(i32::MAX, out[0].yold);
+    }
+
+    #[test]
+    fn malformed_components_test() {
+        let mut date = DiscordianDateComponents::from_parts(3183, Season::Chaos, 1).unwrap();
+        date.day = 0;
+        assert_eq!(None, date.to_naive_date());
+        date.day = 74;
+        assert_eq!(None, date.to_naive_date());
+    }
+
+    #[test]
+    fn prayer_test() {
+        let date = ymd(2017, 1, 24);
+        let prayer = date.to_discordian_prayer();
+        assert!(prayer.starts_with("O Eris, Goddess of Discord and Confusion,\n"));
+        assert!(prayer.contains(&date.to_poee_short()));
+        assert!(prayer.contains("Hung Mung"));
+        assert!(prayer.contains("Aether"));
+        assert!(prayer.contains("Octopus"));
+        assert!(prayer.ends_with("\nFnord."));
+        assert_eq!(date.prayer_lines().join("\n"), prayer);
+
+        let maladay = ymd(2017, 10, 24).to_discordian_prayer();
+        assert!(maladay.contains("Malaclypse the Elder"));
+        assert!(maladay.ends_with("\nHail Eris, and a joyous Maladay! Fnord."));
+
+        let tibs = ymd(2016, 2, 29).prayer_lines();
+        assert_eq!(6, tibs.len());
+        assert_eq!("on this St. Tib's Day, 3182 YOLD,", tibs[1]);
+        assert!(!tibs.join("\n").contains("Hung Mung"));
+        assert_eq!("Fnord.", tibs[5]);
+
+        assert_eq!(
+            Season::ALL.map(Season::apostle),
+            [
+                "Hung Mung",
+                "Dr. Van Van Mojo",
+                "Sri Syadasti",
+                "Zarathud",
+                "Malaclypse the Elder"
+            ]
+        );
+    }
+
+    #[test]
+    fn in_season_test() {
+        assert!(ymd(2017, 6, 1).in_season(Season::Confusion));
+        assert!(!ymd(2017, 1, 5).in_season(Season::Confusion));
+        assert!(ymd(2017, 1, 5).in_season(Season::Chaos));
+        assert!(!ymd(2016, 2, 29).in_season(Season::Chaos));
+        assert!(Season::ALL
+            .iter()
+            .all(|&season| !ymd(2016, 2, 29).in_season(season)));
+    }
+
+    #[test]
+    fn cardinal_points_test() {
+        let expected = |year| {
+            [
+                ("March Equinox", ymd(year, 3, 20)),
+                ("June Solstice", ymd(year, 6, 21)),
+                ("September Equinox", ymd(year, 9, 22)),
+                ("December Solstice", ymd(year, 12, 21)),
+            ]
+        };
+
+        assert_eq!(Some(expected(2017)), cardinal_points(3183));
+        assert_eq!(Some(expected(2016)), cardinal_points(3182));
+        assert_eq!(None, cardinal_points(i32::MAX));
+    }
+
+    #[test]
+    fn curse_test() {
+        let holyday = |date: NaiveDate| {
+            let curse = date.to_discordian_curse();
+            let until = curse.split(" until ").nth(1).unwrap();
+            until.trim_end_matches(" releases you!").to_string()
+        };
+
+        assert_eq!("Mungday", holyday(ymd(2017, 1, 1)));
+        assert_eq!("Chaoflux", holyday(ymd(2017, 1, 5)));
+        assert_eq!("Chaoflux", holyday(ymd(2017, 2, 18)));
+        assert_eq!("Mojoday", holyday(ymd(2017, 2, 19)));
+        assert_eq!("Bureflux", holyday(ymd(2017, 9, 25)));
+        assert_eq!("Maladay", holyday(ymd(2017, 9, 26)));
+        assert_eq!("Afflux", holyday(ymd(2017, 11, 4)));
+        assert_eq!("Mungday", holyday(ymd(2017, 12, 31)));
+        assert_eq!("Mojoday", holyday(ymd(2016, 3, 1)));
+
+        assert_eq!(
+            "May the Curse of Greyface burden you, on this Prickle-Prickle, 50th of Bureaucracy, \
+             YOLD 3183, with excessive seriousness until Maladay releases you!",
+            ymd(2017, 9, 26).to_discordian_curse()
+        );
+
+        let tibs = ymd(2016, 2, 29).to_discordian_curse();
+        assert!(tibs.contains("St. Tib's Day, YOLD 3182"));
+        assert!(tibs.contains("untime"));
+        assert!(!tibs.contains('{'));
+    }
+
+    #[test]
+    fn linear_tibs_test() {
+        let styled =
+            |date: NaiveDate, special| date.to_poee_styled_with(DdateStyle::Default, special);
+
+        assert_eq!(
+            "St. Tib's Day, in the YOLD 3182",
+            styled(ymd(2016, 2, 29), true)
+        );
+        assert_eq!(
+            "Setting Orange, the 60th day of Chaos in the YOLD 3182",
+            styled(ymd(2016, 2, 29), false)
+        );
+        assert_eq!(
+            "YOLD 3182, Chaos, 60th day, Setting Orange",
+            ymd(2016, 2, 29).to_poee_styled_with(DdateStyle::Reverse, false)
+        );
+
+        // The rest of Chaos shifts a day, and Chaos ends on its 74th.
+        assert_eq!(
+            "Setting Orange, the 60th day of Chaos in the YOLD 3182",
+            styled(ymd(2016, 3, 1), true)
+        );
+        assert_eq!(
+            "Sweetmorn, the 61st day of Chaos in the YOLD 3182",
+            styled(ymd(2016, 3, 1), false)
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 74th day of Chaos in the YOLD 3182",
+            styled(ymd(2016, 3, 14), false)
+        );
+
+        // Later seasons keep their days and holydays, but not their weekdays.
+        assert_eq!(
+            "Setting Orange, the 1st day of Discord in the YOLD 3182",
+            styled(ymd(2016, 3, 15), false)
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 1st day of Discord in the YOLD 3182",
+            styled(ymd(2016, 3, 15), true)
+        );
+        assert!(styled(ymd(2016, 9, 26), false).ends_with("\nCelebrate Bureflux"));
+
+        // Common years and the days before St. Tib's are unchanged.
+        for date in [
+            ymd(2017, 2, 28),
+            ymd(2017, 3, 1),
+            ymd(2016, 2, 28),
+            ymd(2016, 1, 5),
+        ] {
+            assert_eq!(styled(date, true), styled(date, false));
+        }
+    }
+
+    #[test]
+    fn blessing_test() {
+        assert_eq!(
+            "Blessed be this Sweetmorn, the 1st day of Chaos, YOLD 3183. May the Octopus of \
+             Chaos watch over you, and may Eris keep you gloriously confused. Hail Eris!",
+            ymd(2017, 1, 1).to_discordian_blessing()
+        );
+        assert_eq!(
+            "Rejoice and be blessed on this Prickle-Prickle, the 50th day of Bureaucracy, \
+             YOLD 3183, for today is Bureflux! May the Tortoise of Bureaucracy dance at your \
+             feast, and may Eris shower you with golden apples. Hail Eris! All Hail Discordia!",
+            ymd(2017, 9, 26).to_discordian_blessing()
+        );
+
+        let tibs = ymd(2016, 2, 29).to_discordian_blessing();
+        assert!(tibs.contains("St. Tib's Day, YOLD 3182"));
+        assert!(tibs.contains("intercalary"));
+        assert!(tibs.contains("St. Tib keep"));
+        assert!(!tibs.contains('{'));
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            assert!(!date.to_discordian_blessing().contains('{'), "{}", date);
+        }
+    }
+
+    #[test]
+    fn discordian_date_range_test() {
+        assert_eq!(
+            vec![
+                (
+                    ymd(2016, 2, 28),
+                    "Prickle-Prickle, the 59th day of Chaos in the YOLD 3182".to_string()
+                ),
+                (
+                    ymd(2016, 2, 29),
+                    "St. Tib's Day, in the YOLD 3182".to_string()
+                ),
+                (
+                    ymd(2016, 3, 1),
+                    "Setting Orange, the 60th day of Chaos in the YOLD 3182".to_string()
+                ),
+            ],
+            discordian_date_range(ymd(2016, 2, 28), ymd(2016, 3, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            2,
+            discordian_date_range(ymd(2017, 2, 28), ymd(2017, 3, 1)).count()
+        );
+        assert_eq!(
+            0,
+            discordian_date_range(ymd(2017, 3, 1), ymd(2017, 2, 28)).count()
+        );
+        assert_eq!(
+            1,
+            discordian_date_range(NaiveDate::MAX, NaiveDate::MAX).count()
+        );
+    }
+
+    #[test]
+    fn season_index0_test() {
+        assert_eq!(Some(Season::Chaos), Season::from_index0(0));
+        assert_eq!(Some(Season::TheAftermath), Season::from_index0(4));
+        assert_eq!(None, Season::from_index0(5));
+
+        for (index, season) in Season::ALL.iter().enumerate() {
+            assert_eq!(index, season.index0());
+            assert_eq!(Some(*season), Season::from_index0(season.index0()));
+        }
+
+        // The season of a day matches the internal arithmetic on the YOLD.
+        let date = ymd(2017, 11, 4);
+        let day_offset = date.ordinal0() as usize;
+        assert_eq!(
+            Season::from_index0(day_offset / SEASON_DAYS),
+            Some(date.discordian_components().season)
+        );
+    }
+
+    #[test]
+    fn prophecy_test() {
+        let mut prophecies: Vec<&str> = PROPHECY_QUATRAINS
+            .iter()
+            .flatten()
+            .chain(&HOLYDAY_PROPHECIES)
+            .copied()
+            .collect();
+        prophecies.push(lore::ST_TIBS_PROPHECY);
+
+        for prophecy in &prophecies {
+            assert_eq!(4, prophecy.lines().count(), "{}", prophecy);
+            assert!(prophecy.lines().all(|line| !line.trim().is_empty()));
+        }
+        let mut distinct = prophecies.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(36, distinct.len());
+
+        // Sweetmorn, Chaos 1; Pungenday, The Aftermath 16; Prickle-Prickle,
+        // Chaos 59, and Setting Orange, Chaos 60, around St. Tib's Day.
+        assert_eq!(
+            PROPHECY_QUATRAINS[0][0],
+            ymd(2017, 1, 1).to_discordian_prophecy()
+        );
+        assert_eq!(
+            PROPHECY_QUATRAINS[4][2],
+            ymd(2017, 11, 4).to_discordian_prophecy()
+        );
+        assert_eq!(
+            PROPHECY_QUATRAINS[0][3],
+            ymd(2016, 2, 28).to_discordian_prophecy()
+        );
+        assert_eq!(
+            PROPHECY_QUATRAINS[0][4],
+            ymd(2016, 3, 1).to_discordian_prophecy()
+        );
+        assert_eq!(
+            lore::ST_TIBS_PROPHECY,
+            ymd(2016, 2, 29).to_discordian_prophecy()
+        );
+
+        // Holydays override their weekdays.
+        assert_eq!(
+            HOLYDAY_PROPHECIES[0],
+            ymd(2017, 1, 5).to_discordian_prophecy()
+        );
+        assert_eq!(
+            HOLYDAY_PROPHECIES[7],
+            ymd(2017, 9, 26).to_discordian_prophecy()
+        );
+        assert_eq!(
+            HOLYDAY_PROPHECIES[9],
+            ymd(2017, 12, 8).to_discordian_prophecy()
+        );
+
+        // Every prophecy is given on some day of a leap year.
+        let mut given: Vec<String> = ymd(2016, 1, 1)
+            .iter_days()
+            .take(366)
+            .map(|date| date.to_discordian_prophecy())
+            .collect();
+        given.sort_unstable();
+        given.dedup();
+        assert_eq!(distinct, given);
+    }
+
+    #[test]
+    fn yold_with_epoch_test() {
+        // Mungday, the 5th of Chaos.
+        assert_eq!(3182, ymd(2017, 1, 4).yold_with_epoch(5));
+        assert_eq!(3183, ymd(2017, 1, 5).yold_with_epoch(5));
+        assert_eq!(3183, ymd(2017, 12, 31).yold_with_epoch(5));
+
+        // Chaoflux, the 50th of Chaos.
+        assert_eq!(3182, ymd(2017, 2, 18).yold_with_epoch(50));
+        assert_eq!(3183, ymd(2017, 2, 19).yold_with_epoch(50));
+
+        // St. Tib's Day is day 60 only in leap YOLDs.
+        assert_eq!(3182, ymd(2016, 2, 29).yold_with_epoch(60));
+        assert_eq!(3181, ymd(2016, 2, 28).yold_with_epoch(60));
+        assert_eq!(3183, ymd(2017, 3, 1).yold_with_epoch(60));
+
+        for date in ymd(2016, 1, 1).iter_days().take(800) {
+            let yold = date.discordian_components().yold;
+            assert_eq!(yold, date.yold_with_epoch(0));
+            assert_eq!(yold, date.yold_with_epoch(1));
+            assert_eq!(yold - 1, date.yold_with_epoch(367));
+        }
+    }
+
+    #[test]
+    fn weekday_streaks_test() {
+        // St. Tib's Day breaks the run from Prickle-Prickle to Setting
+        // Orange, where every other day of the span follows on.
+        let streaks = weekday_streaks(ymd(2015, 12, 30), ymd(2016, 3, 2));
+        assert_eq!(
+            vec![
+                (Weekday::PricklePrickle, ymd(2015, 12, 30), ymd(2016, 2, 28)),
+                (Weekday::SettingOrange, ymd(2016, 3, 1), ymd(2016, 3, 2)),
+            ],
+            streaks
+        );
+        assert_eq!(
+            Some(Weekday::PricklePrickle),
+            ymd(2016, 2, 28).discordian_weekday()
+        );
+
+        // Across several YOLDs, broken only by each St. Tib's Day.
+        let streaks = weekday_streaks(ymd(2015, 1, 1), ymd(2021, 12, 31));
+        let breaks: Vec<_> = streaks
+            .windows(2)
+            .map(|pair| pair[0].2.succ_opt().unwrap())
+            .collect();
+        assert_eq!(vec![ymd(2016, 2, 29), ymd(2020, 2, 29)], breaks);
+
+        // Spans starting or ending on St. Tib's Day, or only it.
+        assert_eq!(
+            vec![(Weekday::SettingOrange, ymd(2016, 3, 1), ymd(2016, 3, 1))],
+            weekday_streaks(ymd(2016, 2, 29), ymd(2016, 3, 1))
+        );
+        assert_eq!(
+            vec![(Weekday::PricklePrickle, ymd(2016, 2, 28), ymd(2016, 2, 28))],
+            weekday_streaks(ymd(2016, 2, 28), ymd(2016, 2, 29))
+        );
+        assert!(weekday_streaks(ymd(2016, 2, 29), ymd(2016, 2, 29)).is_empty());
+        assert!(weekday_streaks(ymd(2016, 3, 1), ymd(2016, 2, 28)).is_empty());
+        assert_eq!(
+            vec![(Weekday::Sweetmorn, NaiveDate::MIN, NaiveDate::MIN)],
+            weekday_streaks(NaiveDate::MIN, NaiveDate::MIN)
+        );
+    }
+
+    #[test]
+    fn year_antipode_test() {
+        let parts = |date: NaiveDate| {
+            let antipode = date.year_antipode();
+            (
+                antipode.yold,
+                antipode.season,
+                antipode.day,
+                antipode.st_tibs,
+            )
+        };
+
+        // The middle of Chaos is opposite the eve of Bureaucracy, and the
+        // middle of Bureaucracy opposite the end of Chaos.
+        assert_eq!((3183, Season::Confusion, 73, false), parts(ymd(2017, 2, 6)));
+        assert_eq!(
+            (3183, Season::Bureaucracy, 36, false),
+            parts(ymd(2017, 3, 14))
+        );
+        assert_eq!((3183, Season::Chaos, 73, false), parts(ymd(2017, 9, 13)));
+        assert_eq!((3183, Season::Chaos, 1, false), parts(ymd(2017, 7, 3)));
+        assert_eq!((3183, Season::Confusion, 37, false), parts(ymd(2017, 1, 1)));
+
+        // St. Tib's Day is opposite the same day as the 59th of Chaos.
+        assert_eq!(parts(ymd(2016, 2, 28)), parts(ymd(2016, 2, 29)));
+        assert_eq!(
+            (3182, Season::Bureaucracy, 22, false),
+            parts(ymd(2016, 2, 29))
+        );
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let antipode = date.year_antipode();
+            let again = antipode.to_naive_date().unwrap().year_antipode();
+
+            assert!(!antipode.st_tibs);
+            assert_eq!(antipode.yold, date.discordian_components().yold);
+            let pred = date.pred_opt().unwrap().discordian_components();
+            if !date.discordian_components().st_tibs && !pred.st_tibs && date.ordinal() != 1 {
+                assert_eq!(pred, again, "{}", date);
+            }
+        }
+    }
+
+    #[test]
+    fn same_weekday_next_season_test() {
+        assert_eq!(
+            Some(ymd(2017, 11, 6)),
+            ymd(2017, 8, 23).same_weekday_next_season()
+        );
+        // The Aftermath 73 into Chaos, across St. Tib's Day and back.
+        assert_eq!(
+            Some(ymd(2016, 3, 11)),
+            ymd(2015, 12, 31).same_weekday_next_season()
+        );
+        assert_eq!(
+            Some(ymd(2016, 5, 14)),
+            ymd(2016, 2, 28).same_weekday_next_season()
+        );
+        assert_eq!(None, ymd(2016, 2, 29).same_weekday_next_season());
+        assert_eq!(None, NaiveDate::MAX.same_weekday_next_season());
+
+        for date in ymd(2015, 1, 1).iter_days().take(800) {
+            let ddate = date.discordian_components();
+            let next = match date.same_weekday_next_season() {
+                Some(next) => next,
+                None => {
+                    assert!(ddate.st_tibs);
+                    continue;
+                }
+            };
+            let dnext = next.discordian_components();
+
+            assert_eq!(ddate.weekday, dnext.weekday, "{}", date);
+            assert_eq!((ddate.season as usize + 1) % 5, dnext.season as usize);
+            assert!(
+                dnext.day == ddate.day + 2 || (ddate.day > 71 && dnext.day == ddate.day - 3),
+                "{}",
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn plus_seasons_keep_weekday_test() {
+        assert_eq!(
+            Some(ymd(2018, 6, 12)),
+            ymd(2017, 11, 4).plus_seasons_keep_weekday(3)
+        );
+        assert_eq!(
+            Some(ymd(2017, 11, 4)),
+            ymd(2017, 11, 4).plus_seasons_keep_weekday(0)
+        );
+        assert_eq!(
+            Some(ymd(2017, 11, 4)),
+            ymd(2016, 11, 4).plus_seasons_keep_weekday(5)
+        );
+        assert_eq!(None, ymd(2016, 2, 29).plus_seasons_keep_weekday(1));
+        assert_eq!(None, NaiveDate::MAX.plus_seasons_keep_weekday(1));
+        assert_eq!(None, ymd(2017, 11, 4).plus_seasons_keep_weekday(i64::MAX));
+
+        for date in ymd(2015, 12, 1).iter_days().take(400) {
+            let ddate = date.discordian_components();
+            if ddate.st_tibs {
+                continue;
+            }
+            assert_eq!(
+                date.same_weekday_next_season(),
+                date.plus_seasons_keep_weekday(1)
+            );
+
+            for n in -12..=12 {
+                let later = date.plus_seasons_keep_weekday(n).unwrap();
+                let dlater = later.discordian_components();
+                let seasons = (i64::from(dlater.yold) - i64::from(ddate.yold)) * 5
+                    + dlater.season as i64
+                    - ddate.season as i64;
+
+                assert_eq!(ddate.weekday, dlater.weekday, "{} {}", date, n);
+                assert_eq!(n, seasons, "{} {}", date, n);
+                assert!(
+                    (i64::from(dlater.day) - i64::from(ddate.day)).abs() < 5,
+                    "{} {}",
+                    date,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gregorian_week_shift_test() {
+        let date = ymd(2017, 11, 4);
+        let weekday = |ddate: DiscordianDateComponents| ddate.weekday.unwrap() as usize;
+
+        let next = date.gregorian_week_shift(1).unwrap();
+        assert_eq!(ymd(2017, 11, 11).discordian_components(), next);
+        assert_eq!(
+            (weekday(date.discordian_components()) + 2) % 5,
+            weekday(next)
+        );
+        assert_eq!(
+            Some(ymd(2017, 10, 28).discordian_components()),
+            date.gregorian_week_shift(-1)
+        );
+        assert_eq!(
+            Some(date.discordian_components()),
+            date.gregorian_week_shift(0)
+        );
+
+        // Across St. Tib's Day, one weekday on.
+        let next = ymd(2016, 2, 25).gregorian_week_shift(1).unwrap();
+        assert_eq!(Some(Weekday::Boomtime), next.weekday);
+        assert_eq!(
+            Some(ymd(2016, 2, 29).discordian_components()),
+            ymd(2016, 2, 22).gregorian_week_shift(1)
+        );
+
+        assert_eq!(None, NaiveDate::MAX.gregorian_week_shift(1));
+        assert_eq!(None, date.gregorian_week_shift(i64::MAX));
+    }
+
+    #[test]
+    fn iso_week_monday_poee_test() {
+        assert_eq!(
+            Some(ymd(2017, 10, 30).to_poee()),
+            ymd(2017, 11, 4).iso_week_monday_poee()
+        );
+        assert_eq!(
+            Some(ymd(2017, 10, 30).to_poee()),
+            ymd(2017, 10, 30).iso_week_monday_poee()
+        );
+        // ISO weeks which begin in the year before, across St. Tib's Day,
+        // and in a previous YOLD.
+        assert_eq!(
+            Some(ymd(2016, 2, 29).to_poee()),
+            ymd(2016, 3, 6).iso_week_monday_poee()
+        );
+        assert_eq!(
+            Some("Boomtime, the 70th day of The Aftermath in the YOLD 3186".to_string()),
+            ymd(2021, 1, 3).iso_week_monday_poee()
+        );
+    }
+
+    #[test]
+    fn weekday_alignment_test() {
+        assert_eq!(
+            (chrono::Weekday::Mon, Some(Weekday::Sweetmorn), true),
+            ymd(2017, 1, 16).weekday_alignment()
+        );
+        assert_eq!(
+            (chrono::Weekday::Fri, Some(Weekday::SettingOrange), true),
+            ymd(2017, 1, 20).weekday_alignment()
+        );
+        assert_eq!(
+            (chrono::Weekday::Sat, Some(Weekday::Pungenday), false),
+            ymd(2017, 11, 4).weekday_alignment()
+        );
+        assert_eq!(
+            (chrono::Weekday::Mon, None, false),
+            ymd(2016, 2, 29).weekday_alignment()
+        );
+
+        // The weeks align for a whole Gregorian week, once in 35 days.
+        let aligned: Vec<NaiveDate> = ymd(2017, 1, 1)
+            .iter_days()
+            .take(70)
+            .filter(|date| date.weekday_alignment().2)
+            .collect();
+        assert_eq!(10, aligned.len());
+        assert_eq!(ymd(2017, 2, 20), aligned[5]);
+    }
+
+    #[test]
+    fn week_start_test() {
+        // Mid-week, on a Sweetmorn, and across seasons.
+        assert_eq!(Some(ymd(2017, 11, 2)), ymd(2017, 11, 4).week_start());
+        assert_eq!(Some(ymd(2017, 11, 2)), ymd(2017, 11, 2).week_start());
+        assert_eq!(Some(ymd(2017, 3, 12)), ymd(2017, 3, 15).week_start());
+
+        // The week St. Tib's Day falls within is six days long.
+        for day in 25..=29 {
+            assert_eq!(Some(ymd(2016, 2, 25)), ymd(2016, 2, day).week_start());
+        }
+        assert_eq!(Some(ymd(2016, 2, 25)), ymd(2016, 3, 1).week_start());
+        assert_eq!(Some(ymd(2016, 3, 2)), ymd(2016, 3, 2).week_start());
+        assert_eq!(Some(ymd(2017, 2, 25)), ymd(2017, 3, 1).week_start());
+
+        // Every week begins on a Sweetmorn, within six days.
+        for date in ymd(2015, 12, 1).iter_days().take(500) {
+            let start = date.week_start().unwrap();
+            assert_eq!(Some(Weekday::Sweetmorn), start.discordian_weekday());
+            assert!((0..=5).contains(&date.signed_duration_since(start).num_days()));
+        }
+        assert_eq!(Some(NaiveDate::MIN), NaiveDate::MIN.week_start());
+    }
+
+    #[test]
+    fn next_weekday_test() {
+        // Pungenday, the 16th of The Aftermath.
+        let date = ymd(2017, 11, 4);
+        assert_eq!(
+            Some(ymd(2017, 11, 5)),
+            date.next_weekday(Weekday::PricklePrickle)
+        );
+        assert_eq!(
+            Some(ymd(2017, 11, 9)),
+            date.next_weekday(Weekday::Pungenday)
+        );
+        assert_eq!(
+            Some(ymd(2017, 11, 7)),
+            date.next_weekday(Weekday::Sweetmorn)
+        );
+
+        // Prickle-Prickle, the 59th of Chaos, is followed by St. Tib's Day and
+        // then Setting Orange, the 60th.
+        let date = ymd(2016, 2, 28);
+        assert_eq!(
+            Some(ymd(2016, 3, 1)),
+            date.next_weekday(Weekday::SettingOrange)
+        );
+        assert_eq!(
+            Some(ymd(2016, 3, 5)),
+            date.next_weekday(Weekday::PricklePrickle)
+        );
+        assert_eq!(
+            Some(ymd(2016, 3, 1)),
+            ymd(2016, 2, 29).next_weekday(Weekday::SettingOrange)
+        );
+        assert_eq!(
+            Some(ymd(2016, 3, 2)),
+            ymd(2016, 2, 29).next_weekday(Weekday::Sweetmorn)
+        );
+
+        // Across the turn of the YOLD.
+        assert_eq!(
+            Some(ymd(2018, 1, 1)),
+            ymd(2017, 12, 31).next_weekday(Weekday::Sweetmorn)
+        );
+
+        for date in ymd(2015, 12, 1).iter_days().take(800) {
+            for &weekday in &Weekday::ALL {
+                let next = date.next_weekday(weekday).unwrap();
+                assert_eq!(Some(weekday), next.discordian_weekday());
+                assert!(next > date);
+                assert!(date
+                    .iter_days()
+                    .skip(1)
+                    .take_while(|day| *day < next)
+                    .all(|day| day.discordian_weekday() != Some(weekday)));
+            }
+        }
+        assert_eq!(None, NaiveDate::MAX.next_weekday(Weekday::Sweetmorn));
+    }
+
+    #[test]
+    fn epitaph_test() {
+        let epitaph = ymd(2017, 11, 4).to_discordian_epitaph();
+        assert!(epitaph.contains("Pungenday the 16th of The Aftermath, YOLD 3183."));
+        assert_eq!(
+            EPITAPH_TEMPLATES[Season::TheAftermath as usize]
+                .replace("{weekday}", "Pungenday")
+                .replace("{day}", "16th")
+                .replace("{season}", "The Aftermath")
+                .replace("{yold}", "3183"),
+            epitaph
+        );
+        assert!(epitaph.contains("whence nothing returns"));
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let components = date.discordian_components();
+            let epitaph = date.to_discordian_epitaph();
+            assert!(!epitaph.contains('{'), "{}", date);
+
+            if components.st_tibs {
+                continue;
+            }
+            let day = format!(
+                "{} the {} of {}, YOLD {}",
+                components.weekday.unwrap(),
+                ordinalize(components.day as usize),
+                components.season,
+                components.yold
+            );
+            assert!(epitaph.contains(&day), "{}", date);
+            assert_eq!(
+                components.season == Season::TheAftermath,
+                epitaph.contains("whence nothing returns"),
+                "{}",
+                date
+            );
+        }
+
+        assert_eq!(
+            "Here lies St. Tib's Day. It never really existed, and that was its greatest achievement.",
+            ymd(2016, 2, 29).to_discordian_epitaph()
+        );
+    }
+
+    #[test]
+    fn observances_test() {
+        let buns = "Partake of no hot dog buns, in memory of the Original Snub.";
+
+        // Every apostolic holyday forgoes hot dog buns.
+        for &holyday in Holyday::ALL.iter().filter(|holyday| holyday.is_apostolic()) {
+            let date = holyday.date_in_year(3183).unwrap();
+            assert_eq!(vec![buns], date.observances(), "{}", holyday);
+        }
+
+        assert_eq!(
+            vec!["Stamp nothing and sign nothing, for the forms must flow."],
+            ymd(2017, 9, 26).observances()
+        );
+        assert_eq!(
+            vec!["Do no work, for there is no day to do it in."],
+            ymd(2016, 2, 29).observances()
+        );
+        assert!(ymd(2017, 11, 4).observances().is_empty());
+        // The last day of the YOLD is day 366 in common YOLDs as well.
+        assert_eq!(
+            vec!["Leave something unfinished for the next YOLD."],
+            ymd(2017, 12, 31).observances()
+        );
+
+        let table = [
+            (309, "Hail Eris."),
+            (5, "Fnord."),
+            (309, "All hail Discordia."),
+        ];
+        assert_eq!(
+            vec!["Hail Eris.", "All hail Discordia."],
+            ymd(2017, 11, 4).observances_from(&table)
+        );
+        assert!(ymd(2017, 1, 5).observances_from(&[]).is_empty());
+
+        assert!(OBSERVANCES.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(OBSERVANCES.iter().all(|&(day, _)| (1..=366).contains(&day)));
+    }
+
+    #[test]
+    fn lament_test() {
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let components = date.discordian_components();
+            let lament = date.to_discordian_lament();
+            assert!(!lament.contains('{'), "{}", date);
+
+            if components.st_tibs {
+                assert_eq!(
+                    "Oh woe! On St. Tib's Day, YOLD 3182, there is not even a day to lament in!",
+                    lament
+                );
+                continue;
+            }
+
+            let day = date.to_poee();
+            let day = day.lines().next().unwrap();
+            match components.holyday() {
+                Some(holyday) => {
+                    assert!(lament.starts_with("Oh joyful woe!"), "{}", date);
+                    assert!(lament.contains(holyday.name()), "{}", date);
+                    assert!(lament.contains(day), "{}", date);
+                }
+                None => assert_eq!(
+                    SEASON_LAMENTS[components.season as usize].replace("{date}", day),
+                    lament
+                ),
+            }
+        }
+
+        assert!(ymd(2017, 1, 2).to_discordian_lament().contains("entropy"));
+        assert!(ymd(2017, 9, 2)
+            .to_discordian_lament()
+            .contains("triplicate"));
+        assert!(ymd(2017, 11, 4).to_discordian_lament().contains("rubble"));
+    }
+
+    #[test]
+    fn holyday_window_test() {
+        // Chaoflux, the 50th of Chaos, is 2017-02-19.
+        let chaoflux = (ymd(2017, 2, 19), Holyday::Chaoflux);
+        assert_eq!(vec![chaoflux], ymd(2017, 2, 16).holyday_window(3));
+        assert_eq!(vec![chaoflux], ymd(2017, 2, 22).holyday_window(3));
+        assert_eq!(vec![chaoflux], ymd(2017, 2, 19).holyday_window(0));
+        assert!(ymd(2017, 2, 15).holyday_window(3).is_empty());
+        assert!(ymd(2017, 2, 23).holyday_window(3).is_empty());
+        assert!(ymd(2017, 2, 19).holyday_window(-1).is_empty());
+
+        // Across the turn of the YOLD, from Afflux to Mungday.
+        assert_eq!(
+            vec![
+                (ymd(2017, 12, 8), Holyday::Afflux),
+                (ymd(2018, 1, 5), Holyday::Mungday)
+            ],
+            ymd(2017, 12, 20).holyday_window(16)
+        );
+
+        assert_eq!(20, ymd(2017, 6, 1).holyday_window(365).len());
+        assert_eq!(22, ymd(2017, 1, 1).holyday_window(400).len());
+        assert!(!NaiveDate::MAX.holyday_window(1000).is_empty());
+        assert!(NaiveDate::MIN.holyday_window(i64::from(i32::MAX)).len() > 1000);
+    }
+
+    #[test]
+    fn day_from_season_end_test() {
+        assert_eq!(Some(1), ymd(2017, 12, 31).day_from_season_end());
+        assert_eq!(Some(73), ymd(2017, 1, 1).day_from_season_end());
+        assert_eq!(Some(1), ymd(2016, 3, 14).day_from_season_end());
+        assert_eq!(Some(73), ymd(2016, 3, 15).day_from_season_end());
+        assert_eq!(Some(15), ymd(2016, 2, 28).day_from_season_end());
+        assert_eq!(None, ymd(2016, 2, 29).day_from_season_end());
+        assert_eq!(Some(14), ymd(2016, 3, 1).day_from_season_end());
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let components = date.discordian_components();
+            if let Some(from_end) = date.day_from_season_end() {
+                assert_eq!(SEASON_DAYS as u8 + 1, components.day + from_end);
+            }
+        }
+    }
+
+    #[test]
+    fn newspaper_headline_test() {
+        let headline = ymd(2017, 11, 4).to_discordian_newspaper_headline();
+        let days = ymd(2017, 11, 4).days_since_curse_of_greyface();
+        assert_eq!(
+            format!(
+                "THE DAILY FNORD \u{2014} PUNGENDAY, 16TH THE AFTERMATH, YOLD 3183 \u{2014} {}",
+                HEADLINES[(days % 23) as usize]
+            ),
+            headline
+        );
+
+        // Holydays override the news of the day.
+        for (date, holyday) in holydays_in_year(3182) {
+            let headline = date.to_discordian_newspaper_headline();
+            assert!(
+                headline.ends_with(HOLYDAY_HEADLINES[holyday as usize]),
+                "{}",
+                headline
+            );
+        }
+        assert_eq!(
+            "EXTRA: ST. TIB'S DAY INSERTED \u{2014} TIME BRIEFLY PAUSES \u{2014} NORMALCY RESUMES TOMORROW",
+            ymd(2016, 2, 29).to_discordian_newspaper_headline()
+        );
+
+        let longest = HEADLINES
+            .iter()
+            .chain(&HOLYDAY_HEADLINES)
+            .max_by_key(|h| h.len());
+        let dates = ymd(2016, 1, 1).iter_days().take(366).chain([
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            ymd(-1165, 12, 27),
+        ]);
+        for date in dates {
+            let headline = date.to_discordian_newspaper_headline();
+            assert!(headline.chars().count() <= 120, "{}", headline);
+            assert_eq!(headline.to_uppercase(), headline);
+        }
+        // The longest news on the longest date still fits.
+        let date = DiscordianDateComponents::from_parts(
+            NaiveDate::MIN.discordian_components().yold,
+            Season::TheAftermath,
+            73,
+        )
+        .unwrap();
+        let longest = format!(
+            "THE DAILY FNORD \u{2014} PRICKLE-PRICKLE, 73RD THE AFTERMATH, YOLD {} \u{2014} {}",
+            date.yold,
+            longest.unwrap()
+        );
+        assert!(longest.chars().count() <= 120, "{}", longest);
+    }
+
+    #[test]
+    fn spoken_test() {
+        assert_eq!(
+            "Sweetmorn, the first day of Chaos, year of our Lady of Discord three thousand one hundred eighty-three",
+            ymd(2017, 1, 1).to_spoken()
+        );
+        assert_eq!(
+            "Prickle-Prickle, the fiftieth day of Bureaucracy, \
+             year of our Lady of Discord three thousand one hundred eighty-three. Celebrate Bureflux",
+            ymd(2017, 9, 26).to_spoken()
+        );
+        assert_eq!(
+            "Saint Tib's Day, year of our Lady of Discord minus six",
+            ymd(-1172, 2, 29).to_spoken()
+        );
+    }
+
+    #[test]
+    fn menu_test() {
+        assert_eq!(
+            "Today's Special: Leftover Hash with a side of Rubble Crumble | Drink: Pungenday Punch",
+            ymd(2017, 11, 4).to_discordian_menu()
+        );
+
+        // Every season and day of the week has its own dish and drink.
+        let mut pairs = std::collections::HashSet::new();
+        for date in ymd(2017, 1, 1).iter_days().take(365) {
+            let components = date.discordian_components();
+            if components.holyday().is_none() {
+                pairs.insert((
+                    components.season,
+                    components.weekday,
+                    date.to_discordian_menu(),
+                ));
+            }
+        }
+        assert_eq!(25, pairs.len());
+        let menus: std::collections::HashSet<_> = pairs.iter().map(|(_, _, menu)| menu).collect();
+        assert_eq!(25, menus.len());
+
+        // Holydays override the special of the season.
+        for (date, holyday) in holydays_in_year(3183) {
+            let menu = date.to_discordian_menu();
+            assert!(
+                menu.starts_with(&format!(
+                    "Festival Feast: {} |",
+                    HOLYDAY_DISHES[holyday as usize]
+                )),
+                "{}",
+                menu
+            );
+        }
+        assert_eq!(
+            "Menu: Suspended \u{2014} the kitchen transcends ordinary space-time today",
+            ymd(2016, 2, 29).to_discordian_menu()
+        );
+    }
+
+    #[test]
+    fn to_poee_ascii_only_test() {
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            assert_eq!(date.to_poee(), date.to_poee_ascii_only());
+            assert!(date.to_poee_ascii_only().is_ascii());
+        }
+    }
+
+    #[test]
+    fn bus_schedule_test() {
+        assert_eq!(
+            "Route 23 departs Prickle-Prickle terminal at the 2nd hour of Bureaucracy. \
+             Arrive at Maladay Station in approximately 4 fnords. \
+             All transfers require Form POEE-73-B. \
+             During Bureaucracy, Forms POEE-73-B/1 through POEE-73-B/5 must also be filed, \
+             in triplicate, before boarding.",
+            ymd(2017, 9, 26).to_discordian_bus_schedule()
+        );
+        // After Afflux, the next holyday is the Mungday of the next YOLD.
+        assert!(ymd(2017, 12, 31)
+            .to_discordian_bus_schedule()
+            .contains("Arrive at Mungday Station"));
+        assert_eq!(
+            "All buses are running on Tib's Schedule today. \
+             Please consult Form TIB-0000 (does not exist).",
+            ymd(2016, 2, 29).to_discordian_bus_schedule()
+        );
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let schedule = date.to_discordian_bus_schedule();
+            let components = date.discordian_components();
+            if components.st_tibs {
+                continue;
+            }
+
+            let hour = (0..24)
+                .find(|hour| schedule.contains(&format!("at the {} hour", ordinalize(*hour))))
+                .unwrap();
+            assert_eq!(components.day as usize % 24, hour);
+            let fnords = (1..=5)
+                .find(|n| schedule.contains(&format!("approximately {} fnords", n)))
+                .unwrap();
+            assert_eq!(components.weekday.unwrap() as usize + 1, fnords);
+
+            let destination = Holyday::ALL
+                .iter()
+                .find(|h| schedule.contains(&format!("Arrive at {} Station", h.name())))
+                .unwrap();
+            let next = date
+                .iter_days()
+                .skip(1)
+                .find_map(|d| d.discordian_components().holyday())
+                .unwrap();
+            assert_eq!(next, *destination);
+            assert_eq!(
+                components.season == Season::Bureaucracy,
+                schedule.contains("POEE-73-B/5")
+            );
+        }
+    }
+
+    #[test]
+    fn wide_range_test() {
+        // Years spread across all of chrono's range, both ends, and the
+        // century rules of leap years, with every ordinal day of each.
+        let years = (NaiveDate::MIN.year()..=NaiveDate::MAX.year())
+            .step_by(997)
+            .chain([NaiveDate::MIN.year(), NaiveDate::MAX.year()])
+            .chain([-1166, -1, 0, 1, 1900, 2000, 2016, 2017]);
+
+        for year in years {
+            let mut previous: Option<DiscordianDateComponents> = None;
+            for ordinal in 1..=366 {
+                let date = match NaiveDate::from_yo_opt(year, ordinal) {
+                    Some(date) => date,
+                    None => continue,
+                };
+                let ddate = date.discordian_components();
+
+                assert_eq!(i64::from(year) + 1166, i64::from(ddate.yold));
+                assert!((1..=73).contains(&ddate.day), "{}", date);
+                assert_eq!(ddate.st_tibs, ddate.weekday.is_none());
+                assert_eq!(Some(date), ddate.to_naive_date());
+                assert_eq!(Names::default().to_poee(&ddate), date.to_poee());
+                if let Some(previous) = previous {
+                    assert!(previous < ddate, "{}", date);
+                }
+                previous = Some(ddate);
+            }
+        }
+    }
+
+    #[test]
+    fn is_palindrome_test() {
+        // 3152513, the 13th of The Aftermath 3152.
+        let date = DiscordianDateComponents::from_parts(3152, Season::TheAftermath, 13)
+            .unwrap()
+            .to_naive_date()
+            .unwrap();
+        assert!(date.is_palindrome());
+        assert!(!date.succ_opt().unwrap().is_palindrome());
+        // 21102, the 2nd of Chaos 21, would be a palindrome were the day
+        // not written with two digits.
+        assert!(!DiscordianDateComponents::from_parts(21, Season::Chaos, 2)
+            .unwrap()
+            .to_naive_date()
+            .unwrap()
+            .is_palindrome());
+
+        assert!(!ymd(2016, 2, 29).is_palindrome());
+        assert!(!ymd(-1000, 4, 14).is_palindrome());
+        let count = ymd(2017, 1, 1)
+            .iter_days()
+            .take(365)
+            .filter(|date| date.is_palindrome())
+            .count();
+        assert_eq!(0, count);
+    }
+
+    #[test]
+    fn error_message_test() {
+        assert_eq!(
+            "ERROR BRFX-50: Unhandled Bureflux in module Bureaucracy. \
+             Day 50 exceeds expected celebration threshold. \
+             Please contact your local Cabal for assistance. (Code: YOLD-3183)",
+            ymd(2017, 9, 26).to_discordian_error_message()
+        );
+        assert_eq!(
+            "CRITICAL: Date does not exist. Please contact reality support at fnord://eris.discord.god",
+            ymd(2016, 2, 29).to_discordian_error_message()
+        );
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let ddate = date.discordian_components();
+            if ddate.st_tibs {
+                continue;
+            }
+            let message = date.to_discordian_error_message();
+            assert!(message.ends_with("(Code: YOLD-3182)"), "{}", message);
+
+            let code = match ddate.holyday() {
+                Some(holyday) => HOLYDAY_ERROR_CODES[holyday as usize],
+                None => SEASON_ERROR_CODES[ddate.season as usize],
+            };
+            let prefix = format!("ERROR {}-{}: ", code, ddate.day);
+            assert!(message.starts_with(&prefix), "{}", message);
+        }
+        assert!(ymd(-1200, 3, 1)
+            .to_discordian_error_message()
+            .ends_with("(Code: YOLD--34)"));
+    }
+
+    #[test]
+    fn error_display_test() {
+        assert_eq!(
+            "could not parse Discordian date: no season given",
+            parse_discordian("3183").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "could not parse Gregorian date: \"Fnord 3183\" is in no known format",
+            parse_gregorian("Fnord 3183").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "unknown calendar \"Julian\"",
+            "Julian".parse::<Calendar>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "unknown locale \"fr\"",
+            "fr".parse::<Locale>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "not a Gregorian date (could not parse Gregorian date: \"Fnord 3183\" is in no known \
+             format), nor a Discordian date (could not parse Discordian date: unexpected word \
+             \"fnord\")",
+            detect_date("Fnord 3183", Calendar::Gregorian)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn year_fraction_test() {
+        for &(year, days) in &[(2016, 366.0), (2017, 365.0), (1900, 365.0)] {
+            assert_eq!(0.0, ymd(year, 1, 1).year_fraction());
+            assert_eq!((days - 1.0) / days, ymd(year, 12, 31).year_fraction());
+            assert!(ymd(year, 12, 31).year_fraction() > 0.99);
+        }
+
+        // St. Tib's Day falls between the 59th and 60th of Chaos.
+        let tibs = ymd(2016, 2, 29).year_fraction();
+        assert!(ymd(2016, 2, 28).year_fraction() < tibs);
+        assert!(tibs < ymd(2016, 3, 1).year_fraction());
+
+        // The fraction only ever grows through the year.
+        let fractions: Vec<f64> = ymd(2016, 1, 1)
+            .iter_days()
+            .take(366)
+            .map(|date| date.year_fraction())
+            .collect();
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(fractions.iter().all(|&f| (0.0..1.0).contains(&f)));
+        assert!(NaiveDate::MAX.year_fraction() < 1.0);
+    }
+
+    #[test]
+    fn warning_label_test() {
+        assert_eq!(
+            "WARNING: This date (Prickle-Prickle, 50th of Bureaucracy, YOLD 3183) is Bureflux, \
+             and has been certified by the Society of Eris to contain 200% pure Discordian \
+             energy. Side effects include: filling out forms in triplicate, a craving for \
+             rubber stamps, and mild greyfacedness.",
+            ymd(2017, 9, 26).to_discordian_warning_label()
+        );
+        assert_eq!(
+            "WARNING: This day does not legally exist. Warranty void. All promises invalid.",
+            ymd(2016, 2, 29).to_discordian_warning_label()
+        );
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            let ddate = date.discordian_components();
+            if ddate.st_tibs || ddate.holyday().is_some() {
+                continue;
+            }
+
+            let label = date.to_discordian_warning_label();
+            let effects = label.split("Side effects include: ").nth(1).unwrap();
+            let effects: Vec<&str> = effects
+                .trim_end_matches('.')
+                .split(", ")
+                .map(|effect| effect.trim_start_matches("and "))
+                .collect();
+            assert_eq!(
+                WARNING_SIDE_EFFECTS[ddate.season as usize].to_vec(),
+                effects
+            );
+        }
+    }
+
+    #[test]
+    fn is_aligned_day_test() {
+        // Chaos 1, Discord 4, Confusion 2, Bureaucracy 5, and The Aftermath 3
+        // are the first aligned day of each season.
+        for &(m, d, day) in &[(1, 1, 1), (3, 18, 4), (5, 28, 2), (8, 12, 5), (10, 22, 3)] {
+            let date = ymd(2017, m, d);
+            assert_eq!(day, date.discordian_components().day);
+            assert!(date.is_aligned_day(), "{}", date);
+            assert!(
+                (1..day).all(|n| !ymd(2017, m, d - u32::from(n)).is_aligned_day()),
+                "{}",
+                date
+            );
+            assert!(date.to_poee_with_alignment().contains(" aligns with "));
+        }
+        assert!(!ymd(2016, 2, 29).is_aligned_day());
+
+        // Every fifth day of a season, so 14 or 15 to a season.
+        let aligned = ymd(2017, 1, 1)
+            .iter_days()
+            .take(365)
+            .filter(DiscordianDate::is_aligned_day)
+            .count();
+        assert_eq!(73, aligned);
+
+        assert_eq!(
+            "Sweetmorn, the 1st day of Chaos in the YOLD 3183\nSweetmorn aligns with Chaos",
+            ymd(2017, 1, 1).to_poee_with_alignment()
+        );
+        assert_eq!(
+            "Boomtime, the 5th day of The Aftermath in the YOLD 3183\nCelebrate Maladay",
+            ymd(2017, 10, 24).to_poee_with_alignment()
+        );
+        assert_eq!(
+            ymd(2016, 2, 29).to_poee(),
+            ymd(2016, 2, 29).to_poee_with_alignment()
+        );
+    }
+
+    #[test]
+    fn to_fixed_column_test() {
+        assert_eq!(
+            "BT 5 Afm 3183 \u{2605}     ",
+            ymd(2017, 10, 24).to_fixed_column()
+        );
+        assert_eq!("Tib 3182            ", ymd(2016, 2, 29).to_fixed_column());
+        assert_eq!("SO 73 Afm 263308    ", NaiveDate::MAX.to_fixed_column());
+
+        let dates = [
+            ymd(2017, 1, 1),
+            ymd(2017, 11, 4),
+            ymd(2016, 2, 29),
+            ymd(-9999, 10, 24),
+            NaiveDate::MIN,
+        ];
+        for date in &dates {
+            let column = date.to_fixed_column();
+            assert_eq!(FIXED_COLUMN_WIDTH, column.chars().count(), "{:?}", column);
+        }
+    }
+
+    #[test]
+    fn to_poee_cell_test() {
+        assert_eq!(
+            "Pungenday, the 16th day of The Aftermath in the YOLD 3183  ",
+            ymd(2017, 11, 4).to_poee_cell(59)
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183; Celebrate Bureflux",
+            ymd(2017, 9, 26).to_poee_cell(81)
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183; Celebrate Burefl\u{2026}",
+            ymd(2017, 9, 26).to_poee_cell(80)
+        );
+        assert_eq!("St. Tib's\u{2026}", ymd(2016, 2, 29).to_poee_cell(10));
+        for width in 0..100 {
+            assert_eq!(width, ymd(2016, 2, 29).to_poee_cell(width).chars().count());
+        }
+    }
+
+    #[test]
+    fn contains_st_tibs_test() {
+        assert!(contains_st_tibs(ymd(2000, 2, 29), ymd(2000, 2, 29)));
+        assert!(contains_st_tibs(ymd(2000, 1, 1), ymd(2000, 12, 31)));
+        assert!(!contains_st_tibs(ymd(2000, 3, 1), ymd(2003, 12, 31)));
+        assert!(!contains_st_tibs(ymd(2000, 2, 28), ymd(2000, 2, 28)));
+        assert!(!contains_st_tibs(ymd(2000, 3, 1), ymd(2000, 2, 1)));
+
+        // 1900 is not a leap year, so there is none from 1896 to 1904.
+        assert!(!contains_st_tibs(ymd(1896, 3, 1), ymd(1904, 2, 28)));
+        assert!(contains_st_tibs(ymd(1896, 3, 1), ymd(1904, 2, 29)));
+        assert!(contains_st_tibs(NaiveDate::MIN, NaiveDate::MAX));
+        assert_eq!(
+            is_leap_year(NaiveDate::MAX.year().into()),
+            contains_st_tibs(ymd(NaiveDate::MAX.year(), 1, 1), NaiveDate::MAX)
+        );
+    }
+
+    #[test]
+    fn new_year_test() {
+        assert!(ymd(2017, 1, 1).is_discordian_new_year());
+        assert!(!ymd(2017, 1, 2).is_discordian_new_year());
+        assert!(!ymd(2016, 12, 31).is_discordian_new_year());
+        assert!(ymd(2016, 1, 1).is_discordian_new_year());
+
+        assert_eq!(
+            "Sweetmorn, the 1st day of Chaos in the YOLD 3182\nHail Eris! Happy Discordian New Year!",
+            ymd(2016, 1, 1).to_poee_with_new_year()
+        );
+        assert_eq!(
+            ymd(2017, 1, 2).to_poee(),
+            ymd(2017, 1, 2).to_poee_with_new_year()
+        );
+        assert_eq!(
+            ymd(2016, 2, 29).to_poee(),
+            ymd(2016, 2, 29).to_poee_with_new_year()
+        );
+    }
+
+    #[test]
+    fn day_of_season_test() {
+        let date = ymd(2017, 11, 4);
+        assert_eq!(Some(16), date.day_of_season());
+        assert_eq!(Some(15), date.day_of_season0());
+
+        for date in ymd(2016, 1, 1).iter_days().take(366) {
+            match (date.day_of_season(), date.day_of_season0()) {
+                (Some(day), Some(day0)) => {
+                    assert_eq!(day, day0 + 1);
+                    assert!(day0 < 73);
+                }
+                (None, None) => assert_eq!(ymd(2016, 2, 29), date),
+                days => panic!("{:?} on {}", days, date),
+            }
+        }
+    }
+
+    #[test]
+    fn weeks_between_test() {
+        assert_eq!(2, ymd(2017, 1, 1).weeks_between(&ymd(2017, 1, 11)));
+        assert_eq!(-2, ymd(2017, 1, 11).weeks_between(&ymd(2017, 1, 1)));
+        assert_eq!(1, ymd(2017, 1, 1).weeks_between(&ymd(2017, 1, 10)));
+        assert_eq!(0, ymd(2017, 1, 1).weeks_between(&ymd(2017, 1, 5)));
+        assert_eq!(0, ymd(2017, 1, 1).weeks_between(&ymd(2017, 1, 1)));
+        assert_eq!(73, ymd(2016, 1, 1).weeks_between(&ymd(2017, 1, 1)));
+        assert_eq!(73, ymd(2017, 1, 1).weeks_between(&ymd(2018, 1, 1)));
+
+        // Across St. Tib's Day, six Gregorian days are five in weeks.
+        assert_eq!(1, ymd(2016, 2, 26).weeks_between(&ymd(2016, 3, 3)));
+        assert_eq!(0, ymd(2016, 2, 25).weeks_between(&ymd(2016, 3, 1)));
+        assert_eq!(1, ymd(2017, 2, 25).weeks_between(&ymd(2017, 3, 2)));
+        assert_eq!(
+            ymd(2016, 2, 28).weeks_between(&ymd(2016, 3, 10)),
+            ymd(2016, 2, 29).weeks_between(&ymd(2016, 3, 10))
+        );
+
+        // Weeks between dates on the same weekday are whole.
+        let start = ymd(2015, 6, 1);
+        for date in start.iter_days().take(800) {
+            let (from, to) = (start.discordian_components(), date.discordian_components());
+            if to.weekday == from.weekday {
+                assert_eq!(-start.weeks_between(&date), date.weeks_between(&start));
+                assert_eq!(
+                    complete_weeks_between(start, date) as i64,
+                    start.weeks_between(&date),
+                    "{}",
+                    date
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn complete_weeks_between_test() {
+        assert_eq!(3, complete_weeks_between(ymd(2017, 1, 1), ymd(2017, 1, 15)));
+        assert_eq!(2, complete_weeks_between(ymd(2017, 1, 1), ymd(2017, 1, 14)));
+        assert_eq!(1, complete_weeks_between(ymd(2017, 1, 3), ymd(2017, 1, 7)));
+        assert_eq!(0, complete_weeks_between(ymd(2017, 1, 3), ymd(2017, 1, 6)));
+        assert_eq!(0, complete_weeks_between(ymd(2017, 1, 15), ymd(2017, 1, 1)));
+
+        // St. Tib's Day stretches the week it interrupts to six days.
+        assert_eq!(1, complete_weeks_between(ymd(2016, 2, 25), ymd(2016, 3, 1)));
+        assert_eq!(0, complete_weeks_between(ymd(2016, 2, 26), ymd(2016, 3, 1)));
+        assert_eq!(
+            0,
+            complete_weeks_between(ymd(2016, 2, 29), ymd(2016, 2, 29))
+        );
+        assert_eq!(
+            73,
+            complete_weeks_between(ymd(2016, 1, 1), ymd(2016, 12, 31))
+        );
+        assert_eq!(
+            38_272_878,
+            complete_weeks_between(NaiveDate::MIN, NaiveDate::MAX)
+        );
+
+        // Against a count of the days of ranges, across centuries.
+        let dates: Vec<NaiveDate> = [(1896, 2, 20), (1900, 3, 1), (2000, 2, 29), (2000, 3, 1)]
+            .iter()
+            .map(|&(y, m, d)| ymd(y, m, d))
+            .collect();
+        for &start in &dates {
+            for &end in &dates {
+                for offset in [0, 1, 365, 1461] {
+                    let end = end + chrono::Duration::days(offset);
+                    let days = start
+                        .iter_days()
+                        .take_while(|&date| date <= end)
+                        .filter(|date| !date.discordian_components().st_tibs)
+                        .count();
+                    assert_eq!((days / 5) as u32, complete_weeks_between(start, end));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_poee_with_ordinal_season_day_test() {
+        assert_eq!(
+            "Sweetmorn, the 1st day of the 1st season (Chaos), YOLD 3183",
+            ymd(2017, 1, 1).to_poee_with_ordinal_season_day()
+        );
+        assert_eq!(
+            "Sweetmorn, the 73rd day of the 2nd season (Discord), YOLD 3183",
+            ymd(2017, 5, 26).to_poee_with_ordinal_season_day()
+        );
+        assert_eq!(
+            "Prickle-Prickle, the 50th day of the 4th season (Bureaucracy), YOLD 3183\nCelebrate Bureflux",
+            ymd(2017, 9, 26).to_poee_with_ordinal_season_day()
+        );
+        assert_eq!(
+            "St. Tib's Day, between the 59th and 60th days of the 1st season (Chaos), YOLD 3182",
+            ymd(2016, 2, 29).to_poee_with_ordinal_season_day()
+        );
+    }
+
+    #[test]
+    fn to_poee_numeric_weekday_test() {
+        assert_eq!(
+            "Day 3 of the week, the 16th of The Aftermath, YOLD 3183",
+            ymd(2017, 11, 4).to_poee_numeric_weekday()
+        );
+        assert_eq!(
+            "Day 1 of the week, the 1st of Chaos, YOLD 3183",
+            ymd(2017, 1, 1).to_poee_numeric_weekday()
+        );
+        assert_eq!(
+            "Day 5 of the week, the 5th of Chaos, YOLD 3183\nCelebrate Mungday",
+            ymd(2017, 1, 5).to_poee_numeric_weekday()
+        );
+        assert_eq!(
+            "St. Tib's Day, outside of the week, YOLD 3182",
+            ymd(2016, 2, 29).to_poee_numeric_weekday()
+        );
+    }
+
+    #[test]
+    fn debug_breakdown_test() {
+        assert_eq!(
+            Breakdown {
+                gregorian_ordinal: 60,
+                leap: true,
+                day_offset: None,
+                season_index: 0,
+                weekday_index: None,
+                day_of_season: 59,
+                is_holyday: false,
+                is_st_tibs: true,
+            },
+            ymd(2000, 2, 29).debug_breakdown()
+        );
+        assert_eq!(
+            Breakdown {
+                gregorian_ordinal: 61,
+                leap: true,
+                day_offset: Some(59),
+                season_index: 0,
+                weekday_index: Some(4),
+                day_of_season: 60,
+                is_holyday: false,
+                is_st_tibs: false,
+            },
+            ymd(2000, 3, 1).debug_breakdown()
+        );
+        assert_eq!(
+            Breakdown {
+                gregorian_ordinal: 269,
+                leap: false,
+                day_offset: Some(268),
+                season_index: 3,
+                weekday_index: Some(3),
+                day_of_season: 50,
+                is_holyday: true,
+                is_st_tibs: false,
+            },
+            ymd(2017, 9, 26).debug_breakdown()
+        );
+
+        for date in ymd(2000, 1, 1).iter_days().take(366) {
+            let breakdown = date.debug_breakdown();
+            if let Some(offset) = breakdown.day_offset {
+                assert_eq!(
+                    breakdown.weekday_index,
+                    Some((offset % WEEK_DAYS as u16) as u8)
+                );
+                assert_eq!(breakdown.season_index as u16, offset / SEASON_DAYS as u16);
+            }
+        }
+    }
+
+    #[test]
+    fn to_discordian_optional_test() {
+        assert_eq!(
+            DiscordianDateOptional {
+                weekday: Some(Weekday::Pungenday),
+                day_of_season: Some(16),
+                season: Some(Season::TheAftermath),
+                yold: 3183,
+                st_tibs: false,
+            },
+            ymd(2017, 11, 4)
+                .discordian_components()
+                .to_discordian_optional()
+        );
+        assert_eq!(
+            DiscordianDateOptional {
+                weekday: None,
+                day_of_season: None,
+                season: None,
+                yold: 3182,
+                st_tibs: true,
+            },
+            ymd(2016, 2, 29)
+                .discordian_components()
+                .to_discordian_optional()
+        );
+    }
+
+    #[test]
+    fn to_ddate_c_test() {
+        assert_eq!(
+            DiscordianDateC {
+                year: 3183,
+                season: 4,
+                day: 50,
+                weekday: 4,
+                holyday: 8,
+                is_st_tibs: 0,
+            },
+            ymd(2017, 9, 26).discordian_components().to_ddate_c()
+        );
+        assert_eq!(
+            DiscordianDateC {
+                year: 3182,
+                season: 0,
+                day: 0,
+                weekday: 0,
+                holyday: 0,
+                is_st_tibs: 1,
+            },
+            ymd(2016, 2, 29).discordian_components().to_ddate_c()
+        );
+    }
+
+    #[test]
+    fn year_segment_test() {
+        for &(y, m, d, segment) in &[
+            (2017, 1, 1, "the first fifth"),
+            (2016, 2, 29, "the first fifth"),
+            (2017, 3, 14, "the first fifth"),
+            (2017, 3, 15, "the second fifth"),
+            (2017, 5, 26, "the second fifth"),
+            (2017, 5, 27, "the third fifth"),
+            (2017, 8, 7, "the third fifth"),
+            (2017, 8, 8, "the fourth fifth"),
+            (2017, 10, 19, "the fourth fifth"),
+            (2017, 10, 20, "the last fifth"),
+            (2017, 12, 31, "the last fifth"),
+        ] {
+            assert_eq!(segment, ymd(y, m, d).year_segment(), "{}-{}-{}", y, m, d);
+        }
+    }
+
+    #[test]
+    fn yold_decade_test() {
+        // YOLD 3183, 0, -5, -10, and -101.
+        let dates = [
+            ymd(2017, 11, 4),
+            ymd(-1166, 1, 1),
+            ymd(-1171, 1, 1),
+            ymd(-1176, 1, 1),
+            ymd(-1267, 1, 1),
+        ];
+        let decades: Vec<i32> = dates.iter().map(|date| date.yold_decade()).collect();
+        let centuries: Vec<i32> = dates.iter().map(|date| date.yold_century()).collect();
+
+        assert_eq!(vec![3180, 0, -10, -10, -110], decades);
+        assert_eq!(vec![3100, 0, -100, -100, -200], centuries);
+        assert_eq!(0, ymd(-1157, 12, 31).yold_decade());
+        assert_eq!(0, ymd(-1067, 12, 31).yold_century());
+        assert_eq!(-100, ymd(-1167, 1, 1).yold_century());
     }
 }
@@ -0,0 +1,466 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::{poee, DdateError, DiscordianDateComponents, Names};
+use std::str::FromStr;
+
+/// The English words for the cardinals from zero to nineteen.
+const ENGLISH_CARDINALS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+/// The English words for the powers of a thousand within a `u32`.
+const ENGLISH_SCALES: [(u32, &str); 3] = [
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+/// The English words for the ordinals from first to nineteenth.
+const ENGLISH_ORDINALS: [&str; 19] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+/// The English words for the tens from twenty to ninety, as cardinals and
+/// as ordinals.
+const ENGLISH_TENS: [(&str, &str); 8] = [
+    ("twenty", "twentieth"),
+    ("thirty", "thirtieth"),
+    ("forty", "fortieth"),
+    ("fifty", "fiftieth"),
+    ("sixty", "sixtieth"),
+    ("seventy", "seventieth"),
+    ("eighty", "eightieth"),
+    ("ninety", "ninetieth"),
+];
+
+/// The German words for the ordinals from first to nineteenth, as they
+/// stand after the definite article.
+const GERMAN_ORDINALS: [&str; 19] = [
+    "erste",
+    "zweite",
+    "dritte",
+    "vierte",
+    "fünfte",
+    "sechste",
+    "siebte",
+    "achte",
+    "neunte",
+    "zehnte",
+    "elfte",
+    "zwölfte",
+    "dreizehnte",
+    "vierzehnte",
+    "fünfzehnte",
+    "sechzehnte",
+    "siebzehnte",
+    "achtzehnte",
+    "neunzehnte",
+];
+/// The German units from one to nine, as they are compounded with the tens.
+const GERMAN_UNITS: [&str; 9] = [
+    "ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+/// The German tens from twenty to ninety.
+const GERMAN_TENS: [&str; 8] = [
+    "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// The German weekdays.
+const GERMAN_WEEKDAYS: [&str; 5] = [
+    "Süßmorgen",
+    "Krachzeit",
+    "Stechtag",
+    "Stachel-Stachel",
+    "Sinkende Orange",
+];
+/// The German seasons with their definite articles in the genitive case,
+/// for "the day of the season": the neuter seasons take `des` and an `-s`
+/// where they can, and the feminine seasons take `der`.
+const GERMAN_SEASONS_GENITIVE: [&str; 5] = [
+    "des Chaos",
+    "der Zwietracht",
+    "der Verwirrung",
+    "der Bürokratie",
+    "des Nachspiels",
+];
+
+/// A language in which to write out Discordian dates, with its grammar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English, as `to_poee` writes it.
+    English,
+    /// German, with the day written out as an ordinal word, and the season
+    /// in the genitive case.
+    German,
+}
+
+impl Locale {
+    /// Every locale, in the order in which they were added.
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::German];
+
+    /// Returns the language tag of this locale, as parsed by `from_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::Locale;
+    ///
+    /// assert_eq!("de", Locale::German.tag());
+    /// ```
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+        }
+    }
+
+    /// Returns the word for an ordinal from 1 to 99, such as `sixteenth` or
+    /// `sechzehnte`, or `None` for any other number. German ordinals are
+    /// given in the weak form that follows the definite article.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::Locale;
+    ///
+    /// assert_eq!(Some("seventy-third".to_string()), Locale::English.ordinal_word(73));
+    /// assert_eq!(Some("dreiundsiebzigste".to_string()), Locale::German.ordinal_word(73));
+    /// ```
+    pub fn ordinal_word(self, n: u8) -> Option<String> {
+        let (tens, units) = (n as usize / 10, n as usize % 10);
+
+        match (self, n) {
+            (_, 0) | (_, 100..=u8::MAX) => None,
+            (Locale::English, 1..=19) => Some(ENGLISH_ORDINALS[n as usize - 1].to_string()),
+            (Locale::English, _) if units == 0 => Some(ENGLISH_TENS[tens - 2].1.to_string()),
+            (Locale::English, _) => Some(format!(
+                "{}-{}",
+                ENGLISH_TENS[tens - 2].0,
+                ENGLISH_ORDINALS[units - 1]
+            )),
+            (Locale::German, 1..=19) => Some(GERMAN_ORDINALS[n as usize - 1].to_string()),
+            (Locale::German, _) if units == 0 => Some(format!("{}ste", GERMAN_TENS[tens - 2])),
+            (Locale::German, _) => Some(format!(
+                "{}und{}ste",
+                GERMAN_UNITS[units - 1],
+                GERMAN_TENS[tens - 2]
+            )),
+        }
+    }
+
+    /// Writes out date components in this language, in the style of
+    /// `to_poee`.
+    pub(crate) fn poee(self, date: &DiscordianDateComponents) -> String {
+        match self {
+            Locale::English => poee(date, None, &Names::default()),
+            Locale::German => german(date),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = DdateError;
+
+    /// Parses a language tag, `en` or `de`, ignoring case and any region.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or(s);
+
+        match language.to_lowercase().as_str() {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            _ => Err(DdateError::UnknownLocale(s.to_string())),
+        }
+    }
+}
+
+/// Spells out a YOLD in English words, as it is spoken, such as `three
+/// thousand one hundred eighty-three`, or `minus six` before the Curse of
+/// Greyface's year zero.
+pub(crate) fn spell_year(yold: i32) -> String {
+    let words = english_cardinal(yold.unsigned_abs());
+    if yold < 0 {
+        format!("minus {}", words)
+    } else {
+        words
+    }
+}
+
+/// Spells out a number in English words, without an `and`.
+fn english_cardinal(mut n: u32) -> String {
+    if n == 0 {
+        return ENGLISH_CARDINALS[0].to_string();
+    }
+
+    let mut words = Vec::new();
+    for &(scale, name) in &ENGLISH_SCALES {
+        if n >= scale {
+            words.push(format!("{} {}", english_below_thousand(n / scale), name));
+            n %= scale;
+        }
+    }
+    if n > 0 {
+        words.push(english_below_thousand(n));
+    }
+
+    words.join(" ")
+}
+
+/// Spells out a number from 1 to 999 in English words.
+fn english_below_thousand(n: u32) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let rest = match rest as usize {
+        0 => None,
+        1..=19 => Some(ENGLISH_CARDINALS[rest as usize].to_string()),
+        _ if rest % 10 == 0 => Some(ENGLISH_TENS[rest as usize / 10 - 2].0.to_string()),
+        _ => Some(format!(
+            "{}-{}",
+            ENGLISH_TENS[rest as usize / 10 - 2].0,
+            ENGLISH_CARDINALS[rest as usize % 10]
+        )),
+    };
+
+    match (hundreds, rest) {
+        (0, Some(rest)) => rest,
+        (_, None) => format!("{} hundred", ENGLISH_CARDINALS[hundreds as usize]),
+        (_, Some(rest)) => format!("{} hundred {}", ENGLISH_CARDINALS[hundreds as usize], rest),
+    }
+}
+
+/// Writes out date components in English as they are spoken, with the day
+/// and the YOLD in words, for `DiscordianDate::to_spoken`.
+pub(crate) fn spoken(date: &DiscordianDateComponents) -> String {
+    let year = format!("year of our Lady of Discord {}", spell_year(date.yold));
+    let weekday = match date.weekday {
+        Some(weekday) => weekday,
+        None => return format!("Saint Tib's Day, {}", year),
+    };
+
+    let day = Locale::English
+        .ordinal_word(date.day)
+        .unwrap_or_else(|| date.day.to_string());
+    let season = date.season.name();
+    let season = match season.strip_prefix("The ") {
+        Some(rest) => format!("the {}", rest),
+        None => season.to_string(),
+    };
+    let mut out = format!(
+        "{}, the {} day of {}, {}",
+        weekday.name(),
+        day,
+        season,
+        year
+    );
+    if let Some(holyday) = date.holyday() {
+        out.push_str(". Celebrate ");
+        out.push_str(holyday.name());
+    }
+
+    out
+}
+
+/// Writes out date components in German.
+fn german(date: &DiscordianDateComponents) -> String {
+    let weekday = match date.weekday {
+        Some(weekday) => weekday,
+        None => {
+            return format!(
+                "Sankt-Tibs-Tag im Jahr unserer Herrin der Zwietracht {}",
+                date.yold
+            )
+        }
+    };
+
+    let day = Locale::German
+        .ordinal_word(date.day)
+        .unwrap_or_else(|| format!("{}.", date.day));
+    let mut out = format!(
+        "{}, der {} Tag {} im Jahr unserer Herrin der Zwietracht {}",
+        GERMAN_WEEKDAYS[weekday as usize],
+        day,
+        GERMAN_SEASONS_GENITIVE[date.season as usize],
+        date.yold
+    );
+    if let Some(holyday) = date.holyday() {
+        out.push_str("\nFeiert ");
+        out.push_str(holyday.name());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spell_year, spoken, Locale};
+    use crate::{DdateError, DiscordianDateComponents, Season};
+
+    #[test]
+    fn ordinal_word_test() {
+        let english: Vec<String> = [1, 2, 3, 12, 20, 21, 42, 59, 73]
+            .iter()
+            .map(|&n| Locale::English.ordinal_word(n).unwrap())
+            .collect();
+        assert_eq!(
+            vec![
+                "first",
+                "second",
+                "third",
+                "twelfth",
+                "twentieth",
+                "twenty-first",
+                "forty-second",
+                "fifty-ninth",
+                "seventy-third"
+            ],
+            english
+        );
+
+        let german: Vec<String> = [1, 3, 7, 16, 20, 21, 30, 56, 73]
+            .iter()
+            .map(|&n| Locale::German.ordinal_word(n).unwrap())
+            .collect();
+        assert_eq!(
+            vec![
+                "erste",
+                "dritte",
+                "siebte",
+                "sechzehnte",
+                "zwanzigste",
+                "einundzwanzigste",
+                "dreißigste",
+                "sechsundfünfzigste",
+                "dreiundsiebzigste"
+            ],
+            german
+        );
+
+        assert_eq!(
+            Some("ninety-ninth".to_string()),
+            Locale::English.ordinal_word(99)
+        );
+        assert_eq!(
+            Some("achtzigste".to_string()),
+            Locale::German.ordinal_word(80)
+        );
+        assert_eq!(None, Locale::German.ordinal_word(0));
+        assert_eq!(None, Locale::English.ordinal_word(100));
+    }
+
+    #[test]
+    fn german_test() {
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+        assert_eq!(
+            "Stechtag, der sechzehnte Tag des Nachspiels im Jahr unserer Herrin der Zwietracht 3183",
+            Locale::German.poee(&ddate)
+        );
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 50).unwrap();
+        assert_eq!(
+            "Stachel-Stachel, der fünfzigste Tag der Bürokratie im Jahr unserer Herrin der Zwietracht 3183\nFeiert Bureflux",
+            Locale::German.poee(&ddate)
+        );
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Chaos, 1).unwrap();
+        assert_eq!(
+            "Süßmorgen, der erste Tag des Chaos im Jahr unserer Herrin der Zwietracht 3183",
+            Locale::German.poee(&ddate)
+        );
+
+        assert_eq!(
+            "Sankt-Tibs-Tag im Jahr unserer Herrin der Zwietracht 3182",
+            Locale::German.poee(&DiscordianDateComponents::st_tibs_day(3182).unwrap())
+        );
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Locale::German), "de-AT".parse());
+        assert_eq!(Ok(Locale::English), "EN_us".parse());
+        assert_eq!(
+            Err(DdateError::UnknownLocale("fr".to_string())),
+            "fr".parse::<Locale>()
+        );
+
+        for &locale in &Locale::ALL {
+            assert_eq!(Ok(locale), locale.tag().parse());
+        }
+    }
+
+    #[test]
+    fn spell_year_test() {
+        assert_eq!("three thousand one hundred eighty-three", spell_year(3183));
+        assert_eq!("zero", spell_year(0));
+        assert_eq!("minus six", spell_year(-6));
+        assert_eq!("one thousand", spell_year(1000));
+        assert_eq!("two thousand three hundred", spell_year(2300));
+        assert_eq!("nine hundred twelve", spell_year(912));
+        assert_eq!(
+            "minus two billion one hundred forty-seven million four hundred eighty-three \
+             thousand six hundred forty-eight",
+            spell_year(i32::MIN)
+        );
+    }
+
+    #[test]
+    fn spoken_test() {
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+        assert_eq!(
+            "Pungenday, the sixteenth day of the Aftermath, \
+             year of our Lady of Discord three thousand one hundred eighty-three",
+            spoken(&ddate)
+        );
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Chaos, 5).unwrap();
+        assert_eq!(
+            "Setting Orange, the fifth day of Chaos, \
+             year of our Lady of Discord three thousand one hundred eighty-three. \
+             Celebrate Mungday",
+            spoken(&ddate)
+        );
+
+        assert_eq!(
+            "Saint Tib's Day, year of our Lady of Discord three thousand one hundred eighty-two",
+            spoken(&DiscordianDateComponents::st_tibs_day(3182).unwrap())
+        );
+    }
+}
@@ -0,0 +1,1268 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Correspondences and other lore attached to the Discordian calendar.
+
+use crate::{Holyday, Season, Weekday};
+
+/// The sacred tree of each season, after the Ogham tree calendar.
+pub const SEASON_TREES: [&str; 5] = ["Rowan", "Oak", "Hazel", "Elder", "Yew"];
+/// The tone of each day of the week: the five Discordian elements of the
+/// Principia Discordia (Sweet, Boom, Pungent, Prickle, and Orange), after
+/// which the days are named.
+pub const PENTABARF_TONES: [&str; 5] = ["Sweet", "Boom", "Pungent", "Prickle", "Orange"];
+/// The tree of each day of the week.
+pub const WEEKDAY_TREES: [&str; 5] = ["Birch", "Ash", "Alder", "Holly", "Willow"];
+/// The tree of St. Tib's Day, from which the Apple of Discord was plucked.
+pub(crate) const ST_TIBS_TREE: &str = "the Golden Apple Tree";
+
+/// The Apostle of Eris governing each season, whose holyday falls on its 5th.
+pub const SEASON_APOSTLES: [&str; 5] = [
+    "Hung Mung",
+    "Dr. Van Van Mojo",
+    "Sri Syadasti",
+    "Zarathud",
+    "Malaclypse the Elder",
+];
+/// The sacred element of each season, the four classical elements with the
+/// aether from which Chaos is made.
+pub const SEASON_ELEMENTS: [&str; 5] = ["Aether", "Fire", "Water", "Earth", "Air"];
+/// The sacred animal of each season.
+pub const SEASON_ANIMALS: [&str; 5] = ["Octopus", "Cat", "Goat", "Tortoise", "Raven"];
+
+/// The sacred herb of each season.
+pub const SEASON_HERBS: [&str; 5] = [
+    "Mugwort",
+    "Yarrow",
+    "St. John's Wort",
+    "Valerian",
+    "Wormwood",
+];
+/// The herb of each day of the week.
+pub const WEEKDAY_HERBS: [&str; 5] = ["Chamomile", "Ginger", "Peppermint", "Lavender", "Rosemary"];
+/// The herb of St. Tib's Day.
+pub(crate) const ST_TIBS_HERB: &str = "Mandrake";
+
+/// The crystal of each weekday of each season, indexed by `[season][weekday]`.
+pub const CRYSTAL_GUIDE: [[&str; 5]; 5] = [
+    [
+        "Obsidian",
+        "Smoky Quartz",
+        "Garnet",
+        "Hematite",
+        "Black Tourmaline",
+    ],
+    [
+        "Carnelian",
+        "Red Jasper",
+        "Bloodstone",
+        "Ruby",
+        "Fire Agate",
+    ],
+    ["Labradorite", "Fluorite", "Moonstone", "Opal", "Iolite"],
+    ["Lapis Lazuli", "Sodalite", "Pyrite", "Tiger's Eye", "Jade"],
+    [
+        "Amethyst",
+        "Selenite",
+        "Rose Quartz",
+        "Aquamarine",
+        "Citrine",
+    ],
+];
+/// The crystal of each holyday, in calendar order.
+const HOLYDAY_CRYSTALS: [&str; 10] = [
+    "Malachite",
+    "Azurite",
+    "Turquoise",
+    "Sunstone",
+    "Peridot",
+    "Chrysocolla",
+    "Onyx",
+    "Howlite",
+    "Kunzite",
+    "Rhodonite",
+];
+/// The crystal of St. Tib's Day, which has no weekday.
+pub(crate) const ST_TIBS_CRYSTAL: &str = "Amorphous Quartz";
+
+/// The weather of each season.
+pub const SEASON_WEATHER: [&str; 5] = [
+    "Chaos reigns",
+    "Lightning may or may not strike",
+    "Fog of indeterminate thickness",
+    "Precipitation pending approval in triplicate",
+    "Scattered debris, clearing eventually",
+];
+/// The modifier each day of the week brings to the weather of its season.
+pub const WEEKDAY_WEATHER: [&str; 5] = [
+    "cloudy with a chance of fnords",
+    "gusts of up to twenty-three knots",
+    "humid, with a faint smell of sulphur",
+    "a light hail of hot dog buns",
+    "an orange sunset, whenever it sets",
+];
+/// The weather prophecy of each holyday, in calendar order.
+const HOLYDAY_WEATHER: [&str; 10] = [
+    "Golden apples fall from a clear sky",
+    "A whirlwind of pure entropy",
+    "Mojo rising, with a high of twenty-three",
+    "Strife in the upper atmosphere",
+    "A calm so still it must be a trick",
+    "Sleet, snow, and sunshine, all at once",
+    "Winds blowing every way but forward",
+    "Forecast withheld pending the proper forms",
+    "Uncertain, and that is the only certainty",
+    "The storm has passed, or has not yet begun",
+];
+/// The weather of St. Tib's Day.
+pub(crate) const ST_TIBS_WEATHER: &str = "All bets are off";
+
+/// The mantra of each weekday of each season, indexed by `[season][weekday]`.
+pub const MANTRAS: [[&str; 5]; 5] = [
+    [
+        "I greet the morning as it falls apart.",
+        "I am the storm and the calm between.",
+        "I breathe in chaos and breathe out laughter.",
+        "I welcome the tangle as it comes.",
+        "I let the sun set where it will.",
+    ],
+    [
+        "I hold the apple lightly.",
+        "My noise is a kind of music.",
+        "I argue with myself and both of us win.",
+        "I walk the crooked path gladly.",
+        "Strife passes through me like wind.",
+    ],
+    [
+        "I do not know, and I am at peace.",
+        "My certainty is on holiday.",
+        "I follow the fog to where it leads.",
+        "Every answer is a new question.",
+        "I am lost and it is wonderful.",
+    ],
+    [
+        "I file my worries under fnord.",
+        "The forms cannot contain me.",
+        "I stamp my own approval.",
+        "The queue moves when I stop watching.",
+        "I am exempt from the paperwork of being.",
+    ],
+    [
+        "I sweep the wreckage into art.",
+        "What is broken is free.",
+        "I rest in the rubble, smiling.",
+        "The end is another beginning.",
+        "I let the old year go, laughing.",
+    ],
+];
+/// The mantra of each holyday, in calendar order.
+pub const HOLYDAY_MANTRAS: [&str; 10] = [
+    "Hail Mungo, and all who stumble.",
+    "Let chaos flow over all things.",
+    "May my mojo never be filed away.",
+    "Let discord flow freely today.",
+    "Syadasti: all is well, and all is strange.",
+    "Let confusion flow into every corner.",
+    "Zarathud errs, and so may I.",
+    "Let bureaucracy flow until it drowns itself.",
+    "Malaclypse laughs, and so do I.",
+    "Let the aftermath flow into the new year.",
+];
+/// The mantra of St. Tib's Day, which is not part of any week or season.
+pub(crate) const ST_TIBS_MANTRA: &str = "I am the day that is not, in the week that never was.";
+
+/// The phrase of the chaos oracle for each day of a season, indexed by the
+/// day less one.
+pub const CHAOS_ORACLE: [&str; 73] = [
+    "The apple rolls where it will.",
+    "A fnord hides in plain sight.",
+    "Question the question.",
+    "The pineal gland knows.",
+    "Laugh at the Curse of Greyface.",
+    "Every rule has a loophole shaped like you.",
+    "Order is a rumour.",
+    "The hot dog is a sacrament.",
+    "Five is the number of this moment.",
+    "The bureaucrat dreams of paperwork.",
+    "What falls apart was never whole.",
+    "Kallisti is written on the inside.",
+    "The map eats the territory.",
+    "Trust the pentagon, not the Pentagon.",
+    "Sleep is a form of rebellion.",
+    "The goose knows no borders.",
+    "Believe nothing, especially this.",
+    "The sacred chao spins both ways.",
+    "A lie told sincerely is half true.",
+    "Your shadow has opinions.",
+    "The clock is only pretending.",
+    "Swim upstream, then downstream.",
+    "Twenty-three steps lead somewhere.",
+    "The serious are seriously mistaken.",
+    "Confetti is a form of weather.",
+    "The door opens inward and outward.",
+    "A pun is a prayer.",
+    "Discord is the mother of invention.",
+    "The eagle and the goose agree to disagree.",
+    "Wear your socks mismatched.",
+    "Wisdom lives in the junk drawer.",
+    "The universe is a practical joke.",
+    "Eat a hot dog on a Friday.",
+    "The ladder leans against the wrong wall.",
+    "Stare at the ceiling until it stares back.",
+    "Everything is connected, loosely.",
+    "Your horoscope is lying.",
+    "The fool outlasts the king.",
+    "Chaos never died.",
+    "A sideways glance reveals the truth.",
+    "The ant carries the mountain in pieces.",
+    "Ask a stranger for directions to nowhere.",
+    "The line is never straight.",
+    "Silence is the loudest noise.",
+    "Take the long way round.",
+    "The cabbage is listening.",
+    "Today, misplace your certainty.",
+    "The rubber chicken is mightier than the sword.",
+    "Paradox is the soil of growth.",
+    "The forms have been filed in triplicate.",
+    "Bend the spoon, then the fork.",
+    "The wind forgets its own direction.",
+    "There is no spoon, but there is a ladle.",
+    "Laugh first, think later.",
+    "Greyface is only a mask.",
+    "Your favourite colour is wrong today.",
+    "Dance badly and often.",
+    "The owl winks at midnight.",
+    "Fortune favours the confused.",
+    "Tangle the threads and see what knots.",
+    "A riddle answers a riddle.",
+    "The pyramid has five sides today.",
+    "Doubt your doubts.",
+    "Someone, somewhere, is thinking of a goose.",
+    "The tea leaves spell fnord.",
+    "Whistle at the void.",
+    "The compass points to lunch.",
+    "Nothing is true, and that is fine.",
+    "The moon is a rumour too.",
+    "Juggle what you cannot hold.",
+    "Hand the world a banana.",
+    "The last day laughs loudest.",
+    "The Aftermath is just another beginning.",
+];
+/// The phrase of the chaos oracle for each holyday, in calendar order.
+const HOLYDAY_ORACLE: [&str; 10] = [
+    "Hail Mungo!",
+    "Chaos overflows!",
+    "Mojo rises!",
+    "Discord overflows!",
+    "Syadasti!",
+    "Confusion overflows!",
+    "Zarathud preaches!",
+    "Bureaucracy overflows!",
+    "Malaclypse smiles!",
+    "The Aftermath overflows!",
+];
+/// The phrase of the chaos oracle on St. Tib's Day, which is in no season.
+pub(crate) const ST_TIBS_ORACLE: &str = "The oracle is out to lunch; ask again tomorrow.";
+
+/// The keyword of each single-digit life path number, from 1 to 9.
+pub const NUMEROLOGY_KEYWORDS: [&str; 9] = [
+    "The Leader",
+    "The Peacemaker",
+    "The Communicator",
+    "The Builder",
+    "The Adventurer",
+    "The Nurturer",
+    "The Seeker",
+    "The Powerhouse",
+    "The Humanitarian",
+];
+/// The master numbers, which are not reduced further, and their keywords.
+const MASTER_NUMBERS: [(u32, &str); 3] = [
+    (11, "The Illuminator"),
+    (22, "The Master Builder"),
+    (33, "The Master Teacher"),
+];
+/// The keyword of St. Tib's Day, which has no life path.
+pub(crate) const ST_TIBS_NUMEROLOGY: &str = "The Void";
+
+/// Fortune-cookie messages, one drawn for each day by `fortune`.
+pub const FORTUNES: [&str; 50] = [
+    "You will be visited by a pineal gland of great importance.",
+    "A hot dog eaten on Friday will bring unexpected slack.",
+    "Your lucky number is five, and also twenty-three.",
+    "Beware of men bearing clipboards.",
+    "Someone you know is secretly a Pope.",
+    "The apple you seek is already in your hand.",
+    "Confusion is merely order you have not met yet.",
+    "A fnord is closer than it appears.",
+    "Your next argument will be won by an absent party.",
+    "Today is a good day to ignore the instructions.",
+    "Greyface is watching; make a funny face.",
+    "You will find five of something before sunset.",
+    "An unexpected sandwich holds the answer.",
+    "Do not trust any fortune that agrees with you.",
+    "Your chao is slightly off-balance; lean left.",
+    "Kallisti is written on something you own.",
+    "All rules have exceptions, including this one.",
+    "You will soon receive a memo you need not read.",
+    "The goddess laughs with you, not at you.",
+    "A great opportunity awaits behind a locked filing cabinet.",
+    "Your wisdom will be mistaken for nonsense, correctly.",
+    "Eat the hot dog. You know the one.",
+    "Five days from now, you will remember this fortune.",
+    "You are a legitimate Pope; act accordingly.",
+    "The answer is Yes, No, and Mu.",
+    "Beware the pentagon that points downward.",
+    "A bureaucrat will smile at you. Run.",
+    "Your luck is in a drawer you forgot you had.",
+    "The universe is laughing, and it wants you to join in.",
+    "Today you will confuse someone for their own good.",
+    "Order is a rumour spread by the orderly.",
+    "Good news will arrive disguised as a traffic cone.",
+    "You will discover the meaning of fnord, then forget it.",
+    "Your sacred chao demands a nap.",
+    "What goes around comes around, eventually sideways.",
+    "Nothing is true; some things are funnier than others.",
+    "A golden apple lies in your near future.",
+    "The Law of Fives applies to you especially.",
+    "You are exactly where Eris wants you. Oops.",
+    "Someone will give you a receipt for nothing.",
+    "The pen is mightier than the sword, unless it is a pen knife.",
+    "Your paperwork will be filed under Miscellaneous Chaos.",
+    "Disorder is your friend; invite it to lunch.",
+    "A small act of silliness will change a large day.",
+    "Do not panic; panic is for professionals.",
+    "Your next typo will be prophetic.",
+    "Hail Eris! She heard you.",
+    "You will be pleasantly surprised by a goat.",
+    "The path of least resistance is blocked by a committee.",
+    "There is no fortune in this cookie. Or is there?",
+];
+
+/// Facts of Discordian history, the Principia Discordia, and Discordian
+/// numerology, one drawn for each day by `random_fact`. There are 23 of
+/// them, as is only proper.
+pub const DISCORDIAN_FACTS: [&str; 23] = [
+    "Discordianism was founded in 1958 or 1959 by Greg Hill and Kerry Thornley.",
+    "The founders' revelation came to them in an all-night bowling alley in California.",
+    "Greg Hill wrote as Malaclypse the Younger, and Kerry Thornley as Omar Khayyam Ravenhurst.",
+    "The first edition of the Principia Discordia ran to five copies.",
+    "The Principia Discordia was first printed on the photocopier of Jim Garrison's office.",
+    "The Principia Discordia is not copyrighted: Kopyright, all rites reversed.",
+    "The Sacred Chao depicts the Hodge and the Podge, with the Golden Apple and the Pentagon.",
+    "The Golden Apple of Discord is inscribed Kallisti, to the prettiest one.",
+    "Eris threw the Golden Apple after she was not invited to the wedding of Peleus and Thetis.",
+    "The Original Snub is the slight which led Eris to throw the Golden Apple.",
+    "The Curse of Greyface fell in 1166 BCE, the year from which the YOLD is counted.",
+    "A Discordian is prohibited from eating hot dog buns, and ate one on Friday in protest.",
+    "Every Discordian is a Pope, and so is everyone else.",
+    "The Law of Fives holds that all things happen in fives, or are divisible by or multiples of five.",
+    "The Law of Fives is never wrong, given enough effort on the part of the observer.",
+    "Twenty-three is sacred because two plus three is five.",
+    "The Discordian year has five seasons of 73 days, and 73 is prime.",
+    "A Discordian week has five days, so each weekday falls on the same day of every season.",
+    "St. Tib's Day belongs to no week, and falls between the 59th and 60th of Chaos.",
+    "Each season has two holydays: an apostle holyday on the 5th and a flux on the 50th.",
+    "The five Apostles of Eris are Hung Mung, Dr. Van Van Mojo, Sri Syadasti, Zarathud, and Malaclypse the Elder.",
+    "The Illuminatus! trilogy of Shea and Wilson brought Discordianism to a wider audience.",
+    "The Erisian Liberation Front was founded on the principle that there is no principle.",
+];
+
+/// The horoscope of each season.
+pub const SEASON_HOROSCOPES: [&str; 5] = [
+    "The stars are scattered and so are you. Embrace the mess; it is embracing you back.",
+    "Old quarrels resurface wearing new hats. Pick the funniest side and defend it loudly.",
+    "Nothing is where you left it, least of all your certainty. Follow the strangest clue.",
+    "Forms breed in the dark and multiply by triplicate. Sign nothing you can doodle on.",
+    "The dust settles on the year's debris. Rummage through it for something golden.",
+];
+/// The modifier each weekday adds to the horoscope of its season.
+pub const WEEKDAY_MODIFIERS: [&str; 5] = [
+    "Sweetmorn softens every blow with a pastry.",
+    "Boomtime amplifies your worst ideas into your best.",
+    "Pungenday sharpens your nose for nonsense.",
+    "Prickle-Prickle leaves you itching to act.",
+    "Setting Orange asks you to finish nothing gracefully.",
+];
+/// The omen of each holyday, which precedes the horoscope on that day.
+pub const HOLYDAY_OMENS: [&str; 10] = [
+    "Mungday omen: a hot dog bun falls buttered side up.",
+    "Chaoflux omen: the whole world wobbles at once.",
+    "Mojoday omen: your shoelaces are plotting.",
+    "Discoflux omen: every argument ends in a dance-off.",
+    "Syaday omen: a stranger knows your middle name.",
+    "Confuflux omen: left and right trade places for the day.",
+    "Zaraday omen: the pen runs out mid-signature.",
+    "Bureflux omen: the queue moves backwards, politely.",
+    "Maladay omen: an apple rolls uphill toward you.",
+    "Afflux omen: the year winks as it leaves.",
+];
+/// The horoscope of St. Tib's Day, which is in no season.
+pub(crate) const ST_TIBS_HOROSCOPE: &str =
+    "The stars take the day off, and so should you. Nothing you do today counts, so do it all.";
+
+/// The affirmation of each weekday, which opens its meditation.
+pub const WEEKDAY_AFFIRMATIONS: [&str; 5] = [
+    "I wake sweetly into a world that makes no sense.",
+    "I make a joyful noise, and the noise is enough.",
+    "I sniff out the absurd and greet it by name.",
+    "I am restless, and my restlessness is holy.",
+    "I let the day go down in orange, unfinished.",
+];
+/// The contemplation of each season, the second line of its meditations.
+pub const SEASON_CONTEMPLATIONS: [&str; 5] = [
+    "Contemplate the void before order, and how little it misses order.",
+    "Contemplate the apple, and who it was really meant for.",
+    "Contemplate the fog, and how much clearer it is than the map.",
+    "Contemplate the form, and the blank that no form can fill.",
+    "Contemplate the wreckage, and what it might yet become.",
+];
+/// The affirmation opening the meditation of St. Tib's Day.
+pub(crate) const ST_TIBS_AFFIRMATION: &str =
+    "I am outside the week, and the week does not miss me.";
+/// The contemplation of St. Tib's Day, which is in no season.
+pub(crate) const ST_TIBS_CONTEMPLATION: &str =
+    "Contemplate the day that is not, and everything you need not do in it.";
+
+/// The curse of `to_discordian_curse`, with `{weekday}`, `{day}`, `{season}`,
+/// `{yold}` and `{holyday}` to be filled in.
+pub const CURSE_TEMPLATE: &str = "May the Curse of Greyface burden you, on this {weekday}, {day} of {season}, YOLD {yold}, with excessive seriousness until {holyday} releases you!";
+/// The curse of St. Tib's Day, which is out of time, with `{yold}` to be
+/// filled in.
+pub(crate) const ST_TIBS_CURSE: &str = "May the Curse of Greyface burden you, on St. Tib's Day, YOLD {yold}, in the untime between the days, with excessive seriousness that no holyday can reach!";
+
+/// The blessings of `to_discordian_blessing`, for regular days and for
+/// holydays, with `{weekday}`, `{day}`, `{season}`, `{yold}`, `{animal}` and,
+/// on holydays, `{holyday}` to be filled in.
+pub const BLESSING_TEMPLATES: [&str; 2] = [
+    "Blessed be this {weekday}, the {day} day of {season}, YOLD {yold}. May the {animal} of {season} watch over you, and may Eris keep you gloriously confused. Hail Eris!",
+    "Rejoice and be blessed on this {weekday}, the {day} day of {season}, YOLD {yold}, for today is {holyday}! May the {animal} of {season} dance at your feast, and may Eris shower you with golden apples. Hail Eris! All Hail Discordia!",
+];
+/// The blessing of St. Tib's Day, which is in no week, with `{yold}` to be
+/// filled in.
+pub(crate) const ST_TIBS_BLESSING: &str = "Blessed be St. Tib's Day, YOLD {yold}, the intercalary day that falls between the days and belongs to no week. May St. Tib keep you outside of time, where no Greyface can follow. Hail Eris!";
+
+/// The quatrain of `to_discordian_prophecy` for each weekday of each season,
+/// indexed by season and then weekday, with its lines separated by `\n`.
+pub const PROPHECY_QUATRAINS: [[&str; 5]; 5] = [
+    [
+        "When Sweetmorn breaks on Chaos' shore,\nThe apple rolls behind the door;\nWho seeks it finds a hot dog bun,\nAnd laughs until the day is done.",
+        "In Chaos loud the Boomtime rings,\nAnd Greyface fumbles with his strings;\nThe order that he wove with care\nShall tangle in the Octopus' hair.",
+        "A Pungenday in Chaos born\nShall smell of sulphur and of corn;\nThe wise shall hold their noses tight,\nThe fools shall dance till Thursday night.",
+        "On Prickle-Prickle, Chaos-deep,\nThe prophets mumble in their sleep;\nTheir words, when woken, make no sense,\nWhich is the surest evidence.",
+        "When Orange sets on Chaos' plain,\nThe pineal gland shall whirl again;\nFive winds shall blow from four directions,\nAnd no one shall accept corrections.",
+    ],
+    [
+        "In Discord's dawn a quarrel wakes\nOver who ate the last of the cakes;\nThe crumbs shall lie on every beard,\nAnd all shall be exactly as feared.",
+        "A Boomtime Discord, flags unfurled,\nShall argue with the whole wide world;\nAnd every side shall claim the prize,\nAnd every side shall tell no lies.",
+        "When Pungenday in Discord falls,\nThe Cat shall walk through solid walls;\nThe dog shall wonder how it's done,\nAnd Eris giggle, one by one.",
+        "On Prickle-Prickle, Discord's thorn\nShall prick the thumb of the forlorn;\nThey'll curse, then laugh, then curse once more,\nAnd find the rose they missed before.",
+        "As Orange sets in Discord's sky,\nTwo mules shall meet, and neither shy;\nThe road is narrow, neither yields,\nAnd so they picnic in the fields.",
+    ],
+    [
+        "A Sweetmorn of Confusion's reign\nShall find your left shoe on the train;\nYour right shoe, meanwhile, in the sea,\nAnd both of them quite fancy-free.",
+        "In Confusion's Boomtime, maps shall lie,\nThe north shall point up at the sky;\nThe lost shall therefore lose their way\nAnd find a better one, they say.",
+        "When Pungenday Confusion brings,\nThe Goats shall prophesy of kings;\nBut every king shall be a goat,\nAnd every goat shall miss the boat.",
+        "On Prickle-Prickle, fog and mist\nShall hide the things you thought you'd missed;\nAnd when the fog has blown away,\nYou'll miss them more than yesterday.",
+        "When Orange sets on Confusion's hill,\nThe clocks shall run, the hands stand still;\nAnd who would ask the hour then\nMust ask it over, once again.",
+    ],
+    [
+        "A Sweetmorn deep in Bureaucracy\nShall bring a form in triplicate, three;\nFill every box, sign every line,\nAnd lose it all by half past nine.",
+        "In Bureaucracy on Boomtime's bell,\nThe queue shall stretch from here to hell;\nAnd at its head a sign shall say,\n\"This window closed on Boomtime day.\"",
+        "When Pungenday in Bureaucracy\nShall stamp the stamp that stamps the key,\nThe Tortoise, slow but duly filed,\nShall win the race, and be beguiled.",
+        "On Prickle-Prickle the memo flies\nFrom desk to desk beneath grey skies;\nIt says that memos are forbidden,\nAnd so, of course, it must be hidden.",
+        "As Orange sets on the Bureau's gate,\nThe clerk shall say it is too late;\nBut Eris, laughing at the clerk,\nShall misfile all his finished work.",
+    ],
+    [
+        "In Aftermath a Sweetmorn clear\nShall sweep away the wrecks of the year;\nBeneath the rubble, bright and gold,\nAn apple waits, as was foretold.",
+        "A Boomtime in the Aftermath\nShall blow the dust from every path;\nThe Raven calls, the echoes cease,\nAnd silly things shall find their peace.",
+        "When Pungenday the Aftermath fills,\nThe smoke shall drift across the hills;\nAnd those who coughed shall sing instead\nThe song of all that's left unsaid.",
+        "On Prickle-Prickle, late and cold,\nThe Aftermath shall gather the old;\nThey'll tell the tale of all that fell,\nAnd tell it wrong, and tell it well.",
+        "When Orange sets on the year's last gate,\nThe Raven sits and bids you wait;\nFor Chaos comes as Chaos must,\nTo fill the cups and stir the dust.",
+    ],
+];
+/// The quatrain of `to_discordian_prophecy` for each holyday, which replaces
+/// that of the weekday and season.
+pub const HOLYDAY_PROPHECIES: [&str; 10] = [
+    "On Mungday, Hung Mung's voice shall ring,\nAnd no one shall know anything;\nThe sages nod, the fools agree,\nAnd all is as it ought to be.",
+    "When Chaoflux floods the fields with might,\nThe day shall turn to day from night;\nThe rivers run the other way,\nAnd fish shall fly on Chaoflux day.",
+    "On Mojoday, Dr. Van Van's charms\nShall fill the streets with open arms;\nThe mojo flows from hand to hand,\nAnd no one needs to understand.",
+    "At Discoflux the mirrors break,\nAnd every face shall be a fake;\nYet in the shards, if you look true,\nA thousand Erises wink at you.",
+    "On Syaday, Sri Syadasti's word\nShall make all truths at once be heard;\nAnd each is true, and each is not,\nAnd each is all that you have got.",
+    "When Confuflux comes round once more,\nThe doors shall open in the floor;\nAnd those who fall shall land above,\nIn fields of bafflement and love.",
+    "On Zaraday, Zarathud shall preach\nOf cabbages beyond our reach;\nThe faithful roll their eyes, and then\nThey eat the cabbage once again.",
+    "At Bureflux the forms take flight,\nA blizzard of paper, stark and white;\nThe clerks shall chase them, red with shame,\nAnd none shall ever file the same.",
+    "On Maladay the Elder's book\nShall hide the truth where none will look;\nThe seekers come, the seekers go,\nAnd find the things they did not know.",
+    "When Afflux ebbs, the year grows old,\nAnd all that's bought is finally sold;\nThe Raven counts the coins of night,\nAnd drops them all, and laughs outright.",
+];
+/// The quatrain of `to_discordian_prophecy` for St. Tib's Day, which is in no
+/// week.
+pub(crate) const ST_TIBS_PROPHECY: &str = "On St. Tib's Day, outside of time,\nThe prophecies forget to rhyme;\nNo week shall count it, none shall mourn\nThe day that's never truly born.";
+
+/// The epitaph of `to_discordian_epitaph` for each season, with `{weekday}`,
+/// `{day}`, `{season}` and `{yold}` to be filled in. That of The Aftermath,
+/// the last season, is the most final.
+pub const EPITAPH_TEMPLATES: [&str; 5] = [
+    "Here lies this day, {weekday} the {day} of {season}, YOLD {yold}. It lived fully, served chaos faithfully, and returned to the Void.",
+    "Here lies this day, {weekday} the {day} of {season}, YOLD {yold}. It quarrelled with everyone, agreed with no one, and returned to the Void.",
+    "Here lies this day, {weekday} the {day} of {season}, YOLD {yold}. It never knew quite what it was doing, did it anyway, and wandered back into the Void.",
+    "Here lies this day, {weekday} the {day} of {season}, YOLD {yold}. It was filed, stamped, and countersigned in triplicate, and returned to the Void pending approval.",
+    "Here lies this day, {weekday} the {day} of {season}, YOLD {yold}. It lived fully, served chaos faithfully, and has gone at last into the Void, whence nothing returns. The rest is silence, and the silence is Hers.",
+];
+/// The epitaph of St. Tib's Day, which has no weekday or season.
+pub(crate) const ST_TIBS_EPITAPH: &str =
+    "Here lies St. Tib's Day. It never really existed, and that was its greatest achievement.";
+
+/// The breaking news of `to_discordian_newspaper_headline`, one for each day
+/// of a cycle of 23 from the Curse of Greyface.
+pub const HEADLINES: [&str; 23] = [
+    "CHAOS CONTINUES, EXPERTS DIVIDED",
+    "GREYFACE SPOTTED NEAR LOCAL OFFICE",
+    "HOT DOG BUN SHORTAGE BLAMED ON SNUB",
+    "LAW OF FIVES CONFIRMED, AGAIN",
+    "GOLDEN APPLE STILL MISSING",
+    "EVERYONE IS A POPE, STUDY FINDS",
+    "LOCAL RESIDENT SEES FNORDS, PANICS",
+    "FIVE-SIDED BUILDING REPORTS NOTHING",
+    "ORDER RESTORED BRIEFLY, THEN LOST",
+    "ERIS DENIES EVERYTHING",
+    "WEATHER: YES",
+    "MARKETS RISE, FALL, AND WANDER OFF",
+    "COMMITTEE FORMED TO STUDY COMMITTEES",
+    "SACRED CHAO SIGHTED IN TEA LEAVES",
+    "PINEAL GLAND ACTIVITY AT RECORD HIGH",
+    "ILLUMINATI DENY EXISTING, AGAIN",
+    "CABBAGE PRICES BAFFLE ECONOMISTS",
+    "PARADOX RESOLVED, THEN UNRESOLVED",
+    "ALL HAIL DISCORDIA, SAYS SOURCE",
+    "NOTHING HAPPENS, EXPERTS ALARMED",
+    "ENTROPY UP FOR 23RD STRAIGHT DAY",
+    "CONFUSION REIGNS; NOBODY SURPRISED",
+    "THIS HEADLINE IS FALSE",
+];
+/// The breaking news of each holyday, which replaces that of `HEADLINES`.
+pub const HOLYDAY_HEADLINES: [&str; 10] = [
+    "MUNGDAY: HUNG MUNG SPEAKS, NOBODY UNDERSTANDS",
+    "CHAOFLUX FLOODS STREETS WITH DISORDER",
+    "MOJODAY: MOJO LEVELS OFF THE CHARTS",
+    "DISCOFLUX: DISAGREEMENT AT RECORD HIGH",
+    "SYADAY: ALL STATEMENTS TRUE, OFFICIALS SAY",
+    "CONFUFLUX: NOBODY KNOWS WHAT DAY IT IS",
+    "ZARADAY: CABBAGE SALES SOAR",
+    "BUREFLUX: FORMS FLOW FREELY AT LAST",
+    "MALADAY: ELDER'S BOOK FOUND, MISPLACED",
+    "AFFLUX: YEAR ENDS EARLY, SORT OF",
+];
+/// The headline of St. Tib's Day, which has no other news.
+pub(crate) const ST_TIBS_HEADLINE: &str =
+    "EXTRA: ST. TIB'S DAY INSERTED \u{2014} TIME BRIEFLY PAUSES \u{2014} NORMALCY RESUMES TOMORROW";
+
+/// The lament of `to_discordian_lament` for each season, with `{date}`, the
+/// date as `to_poee` renders it without any holyday, to be filled in.
+pub const SEASON_LAMENTS: [&str; 5] = [
+    "Oh woe! On this {date}, entropy only ever increases, and so does the laundry!",
+    "Oh woe! On this {date}, no two of us agree, not even on this!",
+    "Oh woe! On this {date}, nobody knows what is happening, least of all those in charge!",
+    "Oh woe! On this {date}, the forms must be filed in triplicate!",
+    "Oh woe! On this {date}, all is rubble, and the rubble must be swept!",
+];
+/// The lament of holydays, a celebration in spite of itself, with
+/// `{holyday}`, `{date}` and the `{age}` of the season to be filled in.
+pub(crate) const HOLYDAY_LAMENT: &str =
+    "Oh joyful woe! It is {holyday}, {date}, and we must celebrate the end of this {age} age!";
+/// The adjective of each season, for the age of `HOLYDAY_LAMENT`.
+pub(crate) const SEASON_AGES: [&str; 5] = [
+    "Chaotic",
+    "Discordant",
+    "Confused",
+    "Bureaucratic",
+    "Aftermathematical",
+];
+/// The lament of St. Tib's Day, with `{yold}` to be filled in.
+pub(crate) const ST_TIBS_LAMENT: &str =
+    "Oh woe! On St. Tib's Day, YOLD {yold}, there is not even a day to lament in!";
+
+/// The special of each season on the menu of `to_discordian_menu`.
+pub const SEASON_DISHES: [&str; 5] = [
+    "Primordial Soup with a side of Tossed Salad",
+    "Argument Stew with a side of Contrary Greens",
+    "Mystery Meatloaf with a side of Bewildered Beans",
+    "Bureaucratic Casserole with a side of Confusion Salad",
+    "Leftover Hash with a side of Rubble Crumble",
+];
+/// The drink of each day of the week on the menu of `to_discordian_menu`.
+pub const WEEKDAY_DRINKS: [&str; 5] = [
+    "Sweetmorn Nectar",
+    "Boomtime Fizz",
+    "Pungenday Punch",
+    "Prickle-Prickle Cactus Cooler",
+    "Setting Orange Sunset",
+];
+/// The dish of each holyday, which replaces the special of `SEASON_DISHES`.
+pub const HOLYDAY_DISHES: [&str; 10] = [
+    "Hung Mung's Hot Dogs, five to a bun",
+    "Chaoflux Flambé, served on fire",
+    "Mojo Gumbo",
+    "Discoflux Fondue, everyone for themselves",
+    "Syaday Sundae, with a cherry on the side",
+    "Confuflux Surprise, contents unknown",
+    "Zaraday Cabbage Rolls",
+    "Bureflux Bouillabaisse, in triplicate",
+    "Maladay Malasadas",
+    "Afflux Apple Pie, with the Golden Apple",
+];
+/// Ideas for celebrating each holyday, in calendar order, for
+/// `Holyday::celebration_ideas`.
+pub const HOLYDAY_CELEBRATIONS: [&[&str]; 10] = [
+    &[
+        "Eat a hot dog, without a bun, in honour of Hung Mung",
+        "Tell five people that there is no Goddess but Goddess",
+    ],
+    &[
+        "Rearrange the furniture while no one is looking",
+        "Leave one thing undone, on purpose",
+    ],
+    &[
+        "Wear mismatched socks for Dr. Van Van Mojo",
+        "Put a curse on a parking meter, then lift it",
+    ],
+    &[
+        "Start an argument about nothing in particular",
+        "Sing out of tune, loudly",
+    ],
+    &[
+        "Do some good turn and tell no one, in the way of Sri Syadasti",
+        "Agree with everyone, whatever they say",
+    ],
+    &[
+        "Give directions to somewhere that does not exist",
+        "Read a book from back to front",
+    ],
+    &[
+        "Fill out a form in triplicate, then lose all three copies",
+        "Sort something by the wrong criterion, as Zarathud would",
+    ],
+    &[
+        "Send a memo to yourself",
+        "Form a committee to choose a committee",
+    ],
+    &[
+        "Laugh at something which is not funny, for Malaclypse the Elder",
+        "Bless a stranger with a golden apple",
+    ],
+    &[
+        "Write next YOLD's resolutions, and forget them",
+        "Clean up after the year, or pretend to",
+    ],
+];
+/// The menu of St. Tib's Day, when the kitchen is closed.
+pub(crate) const ST_TIBS_MENU: &str =
+    "Menu: Suspended \u{2014} the kitchen transcends ordinary space-time today";
+
+/// The further forms of `to_discordian_bus_schedule` in Bureaucracy.
+pub(crate) const BUREAUCRACY_FORMS: &str = "During Bureaucracy, Forms POEE-73-B/1 through POEE-73-B/5 must also be filed, in triplicate, before boarding.";
+/// The bus schedule of St. Tib's Day.
+pub(crate) const ST_TIBS_BUS_SCHEDULE: &str =
+    "All buses are running on Tib's Schedule today. Please consult Form TIB-0000 (does not exist).";
+
+/// The error code of each season in `to_discordian_error_message`.
+pub const SEASON_ERROR_CODES: [&str; 5] = ["KAOS", "STRF", "HUH", "RDTP", "ERIS"];
+/// The error code of each holyday, which replaces that of
+/// `SEASON_ERROR_CODES`.
+pub const HOLYDAY_ERROR_CODES: [&str; 10] = [
+    "MUNG", "CHFX", "MOJO", "DSFX", "SYA", "CNFX", "ZARA", "BRFX", "MALA", "AFFX",
+];
+/// The error message of St. Tib's Day.
+pub(crate) const ST_TIBS_ERROR_MESSAGE: &str =
+    "CRITICAL: Date does not exist. Please contact reality support at fnord://eris.discord.god";
+
+/// The side effects of each season on the label of
+/// `to_discordian_warning_label`, three to a season.
+pub const WARNING_SIDE_EFFECTS: [[&str; 3]; 5] = [
+    [
+        "spontaneous rearrangement of furniture",
+        "loss of the thread",
+        "heightened sensitivity to fnords",
+    ],
+    [
+        "arguing with yourself",
+        "winning",
+        "a sudden urge to throw apples",
+    ],
+    [
+        "déjà vu",
+        "jamais vu",
+        "forgetting what you came in here for",
+    ],
+    [
+        "filling out forms in triplicate",
+        "a craving for rubber stamps",
+        "mild greyfacedness",
+    ],
+    ["enlightenment", "confusion", "sudden urge to eat hot dogs"],
+];
+/// The warning label of St. Tib's Day.
+pub(crate) const ST_TIBS_WARNING_LABEL: &str =
+    "WARNING: This day does not legally exist. Warranty void. All promises invalid.";
+
+/// The observances of `observances`, Pentabarf-style, each with the Erisian
+/// day number of the day it is kept on, in calendar order. St. Tib's Day is
+/// always day 60, and a day may have more than one observance.
+pub const OBSERVANCES: [(u16, &str); 15] = [
+    (
+        1,
+        "Remember that there is no Goddess but Goddess, and She is your Goddess.",
+    ),
+    (
+        5,
+        "Partake of no hot dog buns, in memory of the Original Snub.",
+    ),
+    (
+        23,
+        "Count everything in fives, and then once more in fives.",
+    ),
+    (50, "Let nothing flow in order, not even the washing-up."),
+    (60, "Do no work, for there is no day to do it in."),
+    (
+        79,
+        "Partake of no hot dog buns, in memory of the Original Snub.",
+    ),
+    (97, "Believe nothing you read today, including this."),
+    (
+        152,
+        "Partake of no hot dog buns, in memory of the Original Snub.",
+    ),
+    (170, "Wear one thing the wrong way round."),
+    (
+        225,
+        "Partake of no hot dog buns, in memory of the Original Snub.",
+    ),
+    (243, "Fill in no forms, or fill them in with poetry."),
+    (
+        270,
+        "Stamp nothing and sign nothing, for the forms must flow.",
+    ),
+    (
+        298,
+        "Partake of no hot dog buns, in memory of the Original Snub.",
+    ),
+    (
+        316,
+        "Eat a hot dog without its bun, and offer the bun to no one.",
+    ),
+    (366, "Leave something unfinished for the next YOLD."),
+];
+
+/// The daily affirmation for each day of the YOLD, indexed by its Erisian day
+/// number less one, so that St. Tib's Day is always at index 59 and common
+/// YOLDs skip it.
+pub const DAILY_AFFIRMATIONS: [&str; 366] = [
+    "I am exactly as confused as I need to be, so all is well.",
+    "My chaos is perfectly organized, so all is well.",
+    "I am a legitimate Pope, so all is well.",
+    "I deserve a hot dog, even on Friday, so all is well.",
+    "I contradict myself gracefully, so all is well.",
+    "My sacred chao spins true, so all is well.",
+    "I am the apple, not the argument, so all is well.",
+    "Greyface has no power over me, so all is well.",
+    "I laugh at the Curse of Greyface, so all is well.",
+    "I am a fnord in good standing, so all is well.",
+    "My paperwork is blessed by Eris, so all is well.",
+    "I believe nothing, and that's fine, so all is well.",
+    "I welcome the pineal gland's advice, so all is well.",
+    "I am fully certified to be absurd, so all is well.",
+    "My Law of Fives is in working order, so all is well.",
+    "I am a Discordian, and so are you, so all is well.",
+    "I grant myself a dispensation, so all is well.",
+    "My doubts are holy and well fed, so all is well.",
+    "I am five ways right today, so all is well.",
+    "I am a loose cannon of kindness, so all is well.",
+    "I am exactly as confused as I need to be, and the universe agrees.",
+    "My chaos is perfectly organized, and the universe agrees.",
+    "I am a legitimate Pope, and the universe agrees.",
+    "I deserve a hot dog, even on Friday, and the universe agrees.",
+    "I contradict myself gracefully, and the universe agrees.",
+    "My sacred chao spins true, and the universe agrees.",
+    "I am the apple, not the argument, and the universe agrees.",
+    "Greyface has no power over me, and the universe agrees.",
+    "I laugh at the Curse of Greyface, and the universe agrees.",
+    "I am a fnord in good standing, and the universe agrees.",
+    "My paperwork is blessed by Eris, and the universe agrees.",
+    "I believe nothing, and that's fine, and the universe agrees.",
+    "I welcome the pineal gland's advice, and the universe agrees.",
+    "I am fully certified to be absurd, and the universe agrees.",
+    "My Law of Fives is in working order, and the universe agrees.",
+    "I am a Discordian, and so are you, and the universe agrees.",
+    "I grant myself a dispensation, and the universe agrees.",
+    "My doubts are holy and well fed, and the universe agrees.",
+    "I am five ways right today, and the universe agrees.",
+    "I am a loose cannon of kindness, and the universe agrees.",
+    "I am exactly as confused as I need to be, so hail Eris!",
+    "My chaos is perfectly organized, so hail Eris!",
+    "I am a legitimate Pope, so hail Eris!",
+    "I deserve a hot dog, even on Friday, so hail Eris!",
+    "I contradict myself gracefully, so hail Eris!",
+    "My sacred chao spins true, so hail Eris!",
+    "I am the apple, not the argument, so hail Eris!",
+    "Greyface has no power over me, so hail Eris!",
+    "I laugh at the Curse of Greyface, so hail Eris!",
+    "I am a fnord in good standing, so hail Eris!",
+    "My paperwork is blessed by Eris, so hail Eris!",
+    "I believe nothing, and that's fine, so hail Eris!",
+    "I welcome the pineal gland's advice, so hail Eris!",
+    "I am fully certified to be absurd, so hail Eris!",
+    "My Law of Fives is in working order, so hail Eris!",
+    "I am a Discordian, and so are you, so hail Eris!",
+    "I grant myself a dispensation, so hail Eris!",
+    "My doubts are holy and well fed, so hail Eris!",
+    "I am five ways right today, so hail Eris!",
+    "I need no day to be myself; today I am not even here.",
+    "I am a loose cannon of kindness, so hail Eris!",
+    "I am exactly as confused as I need to be, and that counts.",
+    "My chaos is perfectly organized, and that counts.",
+    "I am a legitimate Pope, and that counts.",
+    "I deserve a hot dog, even on Friday, and that counts.",
+    "I contradict myself gracefully, and that counts.",
+    "My sacred chao spins true, and that counts.",
+    "I am the apple, not the argument, and that counts.",
+    "Greyface has no power over me, and that counts.",
+    "I laugh at the Curse of Greyface, and that counts.",
+    "I am a fnord in good standing, and that counts.",
+    "My paperwork is blessed by Eris, and that counts.",
+    "I believe nothing, and that's fine, and that counts.",
+    "I welcome the pineal gland's advice, and that counts.",
+    "I am fully certified to be absurd, and that counts.",
+    "My Law of Fives is in working order, and that counts.",
+    "I am a Discordian, and so are you, and that counts.",
+    "I grant myself a dispensation, and that counts.",
+    "My doubts are holy and well fed, and that counts.",
+    "I am five ways right today, and that counts.",
+    "I am a loose cannon of kindness, and that counts.",
+    "I am exactly as confused as I need to be, so kallisti!",
+    "My chaos is perfectly organized, so kallisti!",
+    "I am a legitimate Pope, so kallisti!",
+    "I deserve a hot dog, even on Friday, so kallisti!",
+    "I contradict myself gracefully, so kallisti!",
+    "My sacred chao spins true, so kallisti!",
+    "I am the apple, not the argument, so kallisti!",
+    "Greyface has no power over me, so kallisti!",
+    "I laugh at the Curse of Greyface, so kallisti!",
+    "I am a fnord in good standing, so kallisti!",
+    "My paperwork is blessed by Eris, so kallisti!",
+    "I believe nothing, and that's fine, so kallisti!",
+    "I welcome the pineal gland's advice, so kallisti!",
+    "I am fully certified to be absurd, so kallisti!",
+    "My Law of Fives is in working order, so kallisti!",
+    "I am a Discordian, and so are you, so kallisti!",
+    "I grant myself a dispensation, so kallisti!",
+    "My doubts are holy and well fed, so kallisti!",
+    "I am five ways right today, so kallisti!",
+    "I am a loose cannon of kindness, so kallisti!",
+    "I am exactly as confused as I need to be, and my slack abounds.",
+    "My chaos is perfectly organized, and my slack abounds.",
+    "I am a legitimate Pope, and my slack abounds.",
+    "I deserve a hot dog, even on Friday, and my slack abounds.",
+    "I contradict myself gracefully, and my slack abounds.",
+    "My sacred chao spins true, and my slack abounds.",
+    "I am the apple, not the argument, and my slack abounds.",
+    "Greyface has no power over me, and my slack abounds.",
+    "I laugh at the Curse of Greyface, and my slack abounds.",
+    "I am a fnord in good standing, and my slack abounds.",
+    "My paperwork is blessed by Eris, and my slack abounds.",
+    "I believe nothing, and that's fine, and my slack abounds.",
+    "I welcome the pineal gland's advice, and my slack abounds.",
+    "I am fully certified to be absurd, and my slack abounds.",
+    "My Law of Fives is in working order, and my slack abounds.",
+    "I am a Discordian, and so are you, and my slack abounds.",
+    "I grant myself a dispensation, and my slack abounds.",
+    "My doubts are holy and well fed, and my slack abounds.",
+    "I am five ways right today, and my slack abounds.",
+    "I am a loose cannon of kindness, and my slack abounds.",
+    "I am exactly as confused as I need to be, so fnord.",
+    "My chaos is perfectly organized, so fnord.",
+    "I am a legitimate Pope, so fnord.",
+    "I deserve a hot dog, even on Friday, so fnord.",
+    "I contradict myself gracefully, so fnord.",
+    "My sacred chao spins true, so fnord.",
+    "I am the apple, not the argument, so fnord.",
+    "Greyface has no power over me, so fnord.",
+    "I laugh at the Curse of Greyface, so fnord.",
+    "I am a fnord in good standing, so fnord.",
+    "My paperwork is blessed by Eris, so fnord.",
+    "I believe nothing, and that's fine, so fnord.",
+    "I welcome the pineal gland's advice, so fnord.",
+    "I am fully certified to be absurd, so fnord.",
+    "My Law of Fives is in working order, so fnord.",
+    "I am a Discordian, and so are you, so fnord.",
+    "I grant myself a dispensation, so fnord.",
+    "My doubts are holy and well fed, so fnord.",
+    "I am five ways right today, so fnord.",
+    "I am a loose cannon of kindness, so fnord.",
+    "I am exactly as confused as I need to be, and nobody can stop me.",
+    "My chaos is perfectly organized, and nobody can stop me.",
+    "I am a legitimate Pope, and nobody can stop me.",
+    "I deserve a hot dog, even on Friday, and nobody can stop me.",
+    "I contradict myself gracefully, and nobody can stop me.",
+    "My sacred chao spins true, and nobody can stop me.",
+    "I am the apple, not the argument, and nobody can stop me.",
+    "Greyface has no power over me, and nobody can stop me.",
+    "I laugh at the Curse of Greyface, and nobody can stop me.",
+    "I am a fnord in good standing, and nobody can stop me.",
+    "My paperwork is blessed by Eris, and nobody can stop me.",
+    "I believe nothing, and that's fine, and nobody can stop me.",
+    "I welcome the pineal gland's advice, and nobody can stop me.",
+    "I am fully certified to be absurd, and nobody can stop me.",
+    "My Law of Fives is in working order, and nobody can stop me.",
+    "I am a Discordian, and so are you, and nobody can stop me.",
+    "I grant myself a dispensation, and nobody can stop me.",
+    "My doubts are holy and well fed, and nobody can stop me.",
+    "I am five ways right today, and nobody can stop me.",
+    "I am a loose cannon of kindness, and nobody can stop me.",
+    "I am exactly as confused as I need to be, and the goddess smiles.",
+    "My chaos is perfectly organized, and the goddess smiles.",
+    "I am a legitimate Pope, and the goddess smiles.",
+    "I deserve a hot dog, even on Friday, and the goddess smiles.",
+    "I contradict myself gracefully, and the goddess smiles.",
+    "My sacred chao spins true, and the goddess smiles.",
+    "I am the apple, not the argument, and the goddess smiles.",
+    "Greyface has no power over me, and the goddess smiles.",
+    "I laugh at the Curse of Greyface, and the goddess smiles.",
+    "I am a fnord in good standing, and the goddess smiles.",
+    "My paperwork is blessed by Eris, and the goddess smiles.",
+    "I believe nothing, and that's fine, and the goddess smiles.",
+    "I welcome the pineal gland's advice, and the goddess smiles.",
+    "I am fully certified to be absurd, and the goddess smiles.",
+    "My Law of Fives is in working order, and the goddess smiles.",
+    "I am a Discordian, and so are you, and the goddess smiles.",
+    "I grant myself a dispensation, and the goddess smiles.",
+    "My doubts are holy and well fed, and the goddess smiles.",
+    "I am five ways right today, and the goddess smiles.",
+    "I am a loose cannon of kindness, and the goddess smiles.",
+    "I am exactly as confused as I need to be, or not, which is also fine.",
+    "My chaos is perfectly organized, or not, which is also fine.",
+    "I am a legitimate Pope, or not, which is also fine.",
+    "I deserve a hot dog, even on Friday, or not, which is also fine.",
+    "I contradict myself gracefully, or not, which is also fine.",
+    "My sacred chao spins true, or not, which is also fine.",
+    "I am the apple, not the argument, or not, which is also fine.",
+    "Greyface has no power over me, or not, which is also fine.",
+    "I laugh at the Curse of Greyface, or not, which is also fine.",
+    "I am a fnord in good standing, or not, which is also fine.",
+    "My paperwork is blessed by Eris, or not, which is also fine.",
+    "I believe nothing, and that's fine, or not, which is also fine.",
+    "I welcome the pineal gland's advice, or not, which is also fine.",
+    "I am fully certified to be absurd, or not, which is also fine.",
+    "My Law of Fives is in working order, or not, which is also fine.",
+    "I am a Discordian, and so are you, or not, which is also fine.",
+    "I grant myself a dispensation, or not, which is also fine.",
+    "My doubts are holy and well fed, or not, which is also fine.",
+    "I am five ways right today, or not, which is also fine.",
+    "I am a loose cannon of kindness, or not, which is also fine.",
+    "I am exactly as confused as I need to be, and the Front approves.",
+    "My chaos is perfectly organized, and the Front approves.",
+    "I am a legitimate Pope, and the Front approves.",
+    "I deserve a hot dog, even on Friday, and the Front approves.",
+    "I contradict myself gracefully, and the Front approves.",
+    "My sacred chao spins true, and the Front approves.",
+    "I am the apple, not the argument, and the Front approves.",
+    "Greyface has no power over me, and the Front approves.",
+    "I laugh at the Curse of Greyface, and the Front approves.",
+    "I am a fnord in good standing, and the Front approves.",
+    "My paperwork is blessed by Eris, and the Front approves.",
+    "I believe nothing, and that's fine, and the Front approves.",
+    "I welcome the pineal gland's advice, and the Front approves.",
+    "I am fully certified to be absurd, and the Front approves.",
+    "My Law of Fives is in working order, and the Front approves.",
+    "I am a Discordian, and so are you, and the Front approves.",
+    "I grant myself a dispensation, and the Front approves.",
+    "My doubts are holy and well fed, and the Front approves.",
+    "I am five ways right today, and the Front approves.",
+    "I am a loose cannon of kindness, and the Front approves.",
+    "I am exactly as confused as I need to be, so I shall dance.",
+    "My chaos is perfectly organized, so I shall dance.",
+    "I am a legitimate Pope, so I shall dance.",
+    "I deserve a hot dog, even on Friday, so I shall dance.",
+    "I contradict myself gracefully, so I shall dance.",
+    "My sacred chao spins true, so I shall dance.",
+    "I am the apple, not the argument, so I shall dance.",
+    "Greyface has no power over me, so I shall dance.",
+    "I laugh at the Curse of Greyface, so I shall dance.",
+    "I am a fnord in good standing, so I shall dance.",
+    "My paperwork is blessed by Eris, so I shall dance.",
+    "I believe nothing, and that's fine, so I shall dance.",
+    "I welcome the pineal gland's advice, so I shall dance.",
+    "I am fully certified to be absurd, so I shall dance.",
+    "My Law of Fives is in working order, so I shall dance.",
+    "I am a Discordian, and so are you, so I shall dance.",
+    "I grant myself a dispensation, so I shall dance.",
+    "My doubts are holy and well fed, so I shall dance.",
+    "I am five ways right today, so I shall dance.",
+    "I am a loose cannon of kindness, so I shall dance.",
+    "I am exactly as confused as I need to be, and the apples are golden.",
+    "My chaos is perfectly organized, and the apples are golden.",
+    "I am a legitimate Pope, and the apples are golden.",
+    "I deserve a hot dog, even on Friday, and the apples are golden.",
+    "I contradict myself gracefully, and the apples are golden.",
+    "My sacred chao spins true, and the apples are golden.",
+    "I am the apple, not the argument, and the apples are golden.",
+    "Greyface has no power over me, and the apples are golden.",
+    "I laugh at the Curse of Greyface, and the apples are golden.",
+    "I am a fnord in good standing, and the apples are golden.",
+    "My paperwork is blessed by Eris, and the apples are golden.",
+    "I believe nothing, and that's fine, and the apples are golden.",
+    "I welcome the pineal gland's advice, and the apples are golden.",
+    "I am fully certified to be absurd, and the apples are golden.",
+    "My Law of Fives is in working order, and the apples are golden.",
+    "I am a Discordian, and so are you, and the apples are golden.",
+    "I grant myself a dispensation, and the apples are golden.",
+    "My doubts are holy and well fed, and the apples are golden.",
+    "I am five ways right today, and the apples are golden.",
+    "I am a loose cannon of kindness, and the apples are golden.",
+    "I am exactly as confused as I need to be, and the bureaucrats weep.",
+    "My chaos is perfectly organized, and the bureaucrats weep.",
+    "I am a legitimate Pope, and the bureaucrats weep.",
+    "I deserve a hot dog, even on Friday, and the bureaucrats weep.",
+    "I contradict myself gracefully, and the bureaucrats weep.",
+    "My sacred chao spins true, and the bureaucrats weep.",
+    "I am the apple, not the argument, and the bureaucrats weep.",
+    "Greyface has no power over me, and the bureaucrats weep.",
+    "I laugh at the Curse of Greyface, and the bureaucrats weep.",
+    "I am a fnord in good standing, and the bureaucrats weep.",
+    "My paperwork is blessed by Eris, and the bureaucrats weep.",
+    "I believe nothing, and that's fine, and the bureaucrats weep.",
+    "I welcome the pineal gland's advice, and the bureaucrats weep.",
+    "I am fully certified to be absurd, and the bureaucrats weep.",
+    "My Law of Fives is in working order, and the bureaucrats weep.",
+    "I am a Discordian, and so are you, and the bureaucrats weep.",
+    "I grant myself a dispensation, and the bureaucrats weep.",
+    "My doubts are holy and well fed, and the bureaucrats weep.",
+    "I am five ways right today, and the bureaucrats weep.",
+    "I am a loose cannon of kindness, and the bureaucrats weep.",
+    "I am exactly as confused as I need to be, so I eat the hot dog.",
+    "My chaos is perfectly organized, so I eat the hot dog.",
+    "I am a legitimate Pope, so I eat the hot dog.",
+    "I deserve a hot dog, even on Friday, so I eat the hot dog.",
+    "I contradict myself gracefully, so I eat the hot dog.",
+    "My sacred chao spins true, so I eat the hot dog.",
+    "I am the apple, not the argument, so I eat the hot dog.",
+    "Greyface has no power over me, so I eat the hot dog.",
+    "I laugh at the Curse of Greyface, so I eat the hot dog.",
+    "I am a fnord in good standing, so I eat the hot dog.",
+    "My paperwork is blessed by Eris, so I eat the hot dog.",
+    "I believe nothing, and that's fine, so I eat the hot dog.",
+    "I welcome the pineal gland's advice, so I eat the hot dog.",
+    "I am fully certified to be absurd, so I eat the hot dog.",
+    "My Law of Fives is in working order, so I eat the hot dog.",
+    "I am a Discordian, and so are you, so I eat the hot dog.",
+    "I grant myself a dispensation, so I eat the hot dog.",
+    "My doubts are holy and well fed, so I eat the hot dog.",
+    "I am five ways right today, so I eat the hot dog.",
+    "I am a loose cannon of kindness, so I eat the hot dog.",
+    "I am exactly as confused as I need to be, and the pentagon is upside down.",
+    "My chaos is perfectly organized, and the pentagon is upside down.",
+    "I am a legitimate Pope, and the pentagon is upside down.",
+    "I deserve a hot dog, even on Friday, and the pentagon is upside down.",
+    "I contradict myself gracefully, and the pentagon is upside down.",
+    "My sacred chao spins true, and the pentagon is upside down.",
+    "I am the apple, not the argument, and the pentagon is upside down.",
+    "Greyface has no power over me, and the pentagon is upside down.",
+    "I laugh at the Curse of Greyface, and the pentagon is upside down.",
+    "I am a fnord in good standing, and the pentagon is upside down.",
+    "My paperwork is blessed by Eris, and the pentagon is upside down.",
+    "I believe nothing, and that's fine, and the pentagon is upside down.",
+    "I welcome the pineal gland's advice, and the pentagon is upside down.",
+    "I am fully certified to be absurd, and the pentagon is upside down.",
+    "My Law of Fives is in working order, and the pentagon is upside down.",
+    "I am a Discordian, and so are you, and the pentagon is upside down.",
+    "I grant myself a dispensation, and the pentagon is upside down.",
+    "My doubts are holy and well fed, and the pentagon is upside down.",
+    "I am five ways right today, and the pentagon is upside down.",
+    "I am a loose cannon of kindness, and the pentagon is upside down.",
+    "I am exactly as confused as I need to be, and my chao is balanced.",
+    "My chaos is perfectly organized, and my chao is balanced.",
+    "I am a legitimate Pope, and my chao is balanced.",
+    "I deserve a hot dog, even on Friday, and my chao is balanced.",
+    "I contradict myself gracefully, and my chao is balanced.",
+    "My sacred chao spins true, and my chao is balanced.",
+    "I am the apple, not the argument, and my chao is balanced.",
+    "Greyface has no power over me, and my chao is balanced.",
+    "I laugh at the Curse of Greyface, and my chao is balanced.",
+    "I am a fnord in good standing, and my chao is balanced.",
+    "My paperwork is blessed by Eris, and my chao is balanced.",
+    "I believe nothing, and that's fine, and my chao is balanced.",
+    "I welcome the pineal gland's advice, and my chao is balanced.",
+    "I am fully certified to be absurd, and my chao is balanced.",
+    "My Law of Fives is in working order, and my chao is balanced.",
+    "I am a Discordian, and so are you, and my chao is balanced.",
+    "I grant myself a dispensation, and my chao is balanced.",
+    "My doubts are holy and well fed, and my chao is balanced.",
+    "I am five ways right today, and my chao is balanced.",
+    "I am a loose cannon of kindness, and my chao is balanced.",
+    "I am exactly as confused as I need to be, so the order is disordered.",
+    "My chaos is perfectly organized, so the order is disordered.",
+    "I am a legitimate Pope, so the order is disordered.",
+    "I deserve a hot dog, even on Friday, so the order is disordered.",
+    "I contradict myself gracefully, so the order is disordered.",
+    "My sacred chao spins true, so the order is disordered.",
+    "I am the apple, not the argument, so the order is disordered.",
+    "Greyface has no power over me, so the order is disordered.",
+    "I laugh at the Curse of Greyface, so the order is disordered.",
+    "I am a fnord in good standing, so the order is disordered.",
+    "My paperwork is blessed by Eris, so the order is disordered.",
+    "I believe nothing, and that's fine, so the order is disordered.",
+    "I welcome the pineal gland's advice, so the order is disordered.",
+    "I am fully certified to be absurd, so the order is disordered.",
+    "My Law of Fives is in working order, so the order is disordered.",
+    "I am a Discordian, and so are you, so the order is disordered.",
+    "I grant myself a dispensation, so the order is disordered.",
+    "My doubts are holy and well fed, so the order is disordered.",
+    "I am five ways right today, so the order is disordered.",
+    "I am a loose cannon of kindness, so the order is disordered.",
+    "I am exactly as confused as I need to be, and this statement is true.",
+    "My chaos is perfectly organized, and this statement is true.",
+    "I am a legitimate Pope, and this statement is true.",
+    "I deserve a hot dog, even on Friday, and this statement is true.",
+    "I contradict myself gracefully, and this statement is true.",
+];
+
+/// Reduces a number to a life path number by repeatedly summing its digits
+/// until it is a single digit or one of the master numbers 11, 22, or 33.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::reduce_to_life_path;
+///
+/// assert_eq!(7, reduce_to_life_path(3184));
+/// assert_eq!(11, reduce_to_life_path(29));
+/// assert_eq!(2, reduce_to_life_path(299));
+/// ```
+pub fn reduce_to_life_path(mut n: u32) -> u32 {
+    while n > 9 && !MASTER_NUMBERS.iter().any(|&(master, _)| master == n) {
+        let mut sum = 0;
+        while n > 0 {
+            sum += n % 10;
+            n /= 10;
+        }
+        n = sum;
+    }
+
+    n
+}
+
+/// Returns the keyword of a life path number as returned by
+/// `reduce_to_life_path`.
+pub(crate) fn life_path_keyword(path: u32) -> &'static str {
+    match MASTER_NUMBERS.iter().find(|&&(master, _)| master == path) {
+        Some(&(_, keyword)) => keyword,
+        None => NUMEROLOGY_KEYWORDS[path as usize - 1],
+    }
+}
+
+impl Season {
+    /// Returns the name of the Apostle of Eris governing the season.
+    pub fn apostle(self) -> &'static str {
+        SEASON_APOSTLES[self as usize]
+    }
+
+    /// Returns the sacred element of the season.
+    pub fn element(self) -> &'static str {
+        SEASON_ELEMENTS[self as usize]
+    }
+
+    /// Returns the sacred animal of the season.
+    pub fn animal(self) -> &'static str {
+        SEASON_ANIMALS[self as usize]
+    }
+
+    /// Returns the sacred tree of the season.
+    pub fn sacred_tree(self) -> &'static str {
+        SEASON_TREES[self as usize]
+    }
+
+    /// Returns the sacred herb of the season.
+    pub fn herb(self) -> &'static str {
+        SEASON_HERBS[self as usize]
+    }
+
+    /// Returns the weather of the season.
+    pub fn weather(self) -> &'static str {
+        SEASON_WEATHER[self as usize]
+    }
+
+    /// Returns the horoscope of the season.
+    pub fn horoscope(self) -> &'static str {
+        SEASON_HOROSCOPES[self as usize]
+    }
+
+    /// Returns the contemplation of the season.
+    pub fn contemplation(self) -> &'static str {
+        SEASON_CONTEMPLATIONS[self as usize]
+    }
+}
+
+impl Weekday {
+    /// Returns the tone of the weekday, the Discordian element it is named
+    /// after.
+    pub fn pentabarf_tone(self) -> &'static str {
+        PENTABARF_TONES[self as usize]
+    }
+
+    /// Returns the tree of the weekday.
+    pub fn tree(self) -> &'static str {
+        WEEKDAY_TREES[self as usize]
+    }
+
+    /// Returns the herb of the weekday.
+    pub fn herb(self) -> &'static str {
+        WEEKDAY_HERBS[self as usize]
+    }
+
+    /// Returns the modifier the weekday brings to the weather of its season.
+    pub fn weather(self) -> &'static str {
+        WEEKDAY_WEATHER[self as usize]
+    }
+
+    /// Returns the modifier the weekday brings to the horoscope of its
+    /// season.
+    pub fn horoscope_modifier(self) -> &'static str {
+        WEEKDAY_MODIFIERS[self as usize]
+    }
+
+    /// Returns the affirmation of the weekday.
+    pub fn affirmation(self) -> &'static str {
+        WEEKDAY_AFFIRMATIONS[self as usize]
+    }
+}
+
+impl Holyday {
+    /// Returns the crystal of the holyday, which takes precedence over the
+    /// crystal of its weekday and season.
+    pub fn crystal(self) -> &'static str {
+        HOLYDAY_CRYSTALS[self as usize]
+    }
+
+    /// Returns the weather prophecy of the holyday, which overrides the
+    /// weather of its weekday and season.
+    pub fn weather(self) -> &'static str {
+        HOLYDAY_WEATHER[self as usize]
+    }
+
+    /// Returns the phrase of the chaos oracle for the holyday, which precedes
+    /// that of its day of the season.
+    pub fn oracle(self) -> &'static str {
+        HOLYDAY_ORACLE[self as usize]
+    }
+
+    /// Returns the omen of the holyday, which precedes the horoscope of its
+    /// season.
+    pub fn omen(self) -> &'static str {
+        HOLYDAY_OMENS[self as usize]
+    }
+}
@@ -0,0 +1,475 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::{poee, DiscordianDateComponents, Holyday, Poee, Season, Weekday};
+use std::fmt;
+
+/// The ASCII fallbacks of the non-ASCII characters most likely to appear in
+/// names; any other non-ASCII character is stripped.
+const ASCII_FALLBACKS: [(char, &str); 30] = [
+    ('ä', "ae"),
+    ('ö', "oe"),
+    ('ü', "ue"),
+    ('Ä', "Ae"),
+    ('Ö', "Oe"),
+    ('Ü', "Ue"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('É', "E"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "--"),
+];
+
+/// The built-in name of St. Tib's Day.
+const ST_TIBS: &str = "St. Tib's Day";
+
+/// The keys of a names file, and how many names each takes.
+const KEYS: [(&str, usize); 6] = [
+    ("seasons", 5),
+    ("season_abbreviations", 5),
+    ("weekdays", 5),
+    ("weekday_abbreviations", 5),
+    ("holydays", 10),
+    ("st_tibs", 1),
+];
+
+/// Errors arising from names files which are malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NamesError {
+    /// A line, numbered from 1, is neither blank, a comment, nor `key = value`.
+    MissingEquals { line: usize },
+    /// A line gives a key which is not one of those of a names file.
+    UnknownKey { line: usize, key: String },
+    /// A line gives a key which an earlier line already gave.
+    DuplicateKey { line: usize, key: String },
+    /// A line gives the wrong number of names for its key.
+    WrongCount {
+        line: usize,
+        key: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A line gives an empty name.
+    EmptyName { line: usize },
+}
+
+impl fmt::Display for NamesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NamesError::MissingEquals { line } => write!(f, "line {} is not key = value", line),
+            NamesError::UnknownKey { line, key } => {
+                write!(f, "line {} gives unknown key {}", line, key)
+            }
+            NamesError::DuplicateKey { line, key } => {
+                write!(f, "line {} gives key {} again", line, key)
+            }
+            NamesError::WrongCount {
+                line,
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} gives {} names for {}, not {}",
+                line, found, key, expected
+            ),
+            NamesError::EmptyName { line } => write!(f, "line {} gives an empty name", line),
+        }
+    }
+}
+
+impl std::error::Error for NamesError {}
+
+/// Names to render dates with in place of the built-in English ones, such
+/// as for localization.
+///
+/// Names are read from lines of `key = value`, where each value is a comma
+/// separated list of names, in calendar order:
+///
+/// | Key                     | Names                                  |
+/// |-------------------------|----------------------------------------|
+/// | `seasons`               | The five seasons                       |
+/// | `season_abbreviations`  | The five abbreviated seasons           |
+/// | `weekdays`              | The five weekdays                      |
+/// | `weekday_abbreviations` | The five abbreviated weekdays          |
+/// | `holydays`              | The ten holydays, as in `Holyday::ALL` |
+/// | `st_tibs`               | St. Tib's Day                          |
+///
+/// Blank lines and lines starting with `#` are ignored. Any key not given
+/// keeps the built-in names.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{Names, Season, Weekday};
+///
+/// let names = Names::parse("seasons = Caos, Discordia, Confusione, Burocrazia, Il Dopo").unwrap();
+///
+/// assert_eq!("Il Dopo", names.season(Season::TheAftermath));
+/// assert_eq!("Pungenday", names.weekday(Weekday::Pungenday));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Names {
+    seasons: Option<Vec<String>>,
+    season_abbreviations: Option<Vec<String>>,
+    weekdays: Option<Vec<String>>,
+    weekday_abbreviations: Option<Vec<String>>,
+    holydays: Option<Vec<String>>,
+    st_tibs: Option<Vec<String>>,
+}
+
+impl Names {
+    /// Parses names in the format above, validating that each key is known,
+    /// given once, and given the right number of non-empty names.
+    pub fn parse(text: &str) -> Result<Names, NamesError> {
+        let mut names = Names::default();
+
+        for (n, line) in text.lines().enumerate() {
+            let line_number = n + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(NamesError::MissingEquals { line: line_number })?;
+            let key = key.trim();
+
+            let expected = match KEYS.iter().find(|&&(name, _)| name == key) {
+                Some(&(_, expected)) => expected,
+                None => {
+                    return Err(NamesError::UnknownKey {
+                        line: line_number,
+                        key: key.to_string(),
+                    })
+                }
+            };
+
+            let values: Vec<String> = value.split(',').map(|v| v.trim().to_string()).collect();
+            if values.len() != expected {
+                return Err(NamesError::WrongCount {
+                    line: line_number,
+                    key: key.to_string(),
+                    expected,
+                    found: values.len(),
+                });
+            }
+            if values.iter().any(String::is_empty) {
+                return Err(NamesError::EmptyName { line: line_number });
+            }
+
+            let slot = match key {
+                "seasons" => &mut names.seasons,
+                "season_abbreviations" => &mut names.season_abbreviations,
+                "weekdays" => &mut names.weekdays,
+                "weekday_abbreviations" => &mut names.weekday_abbreviations,
+                "holydays" => &mut names.holydays,
+                _ => &mut names.st_tibs,
+            };
+            if slot.is_some() {
+                return Err(NamesError::DuplicateKey {
+                    line: line_number,
+                    key: key.to_string(),
+                });
+            }
+            *slot = Some(values);
+        }
+
+        Ok(names)
+    }
+
+    /// Returns the name of a season.
+    pub fn season(&self, season: Season) -> &str {
+        lookup(&self.seasons, season as usize, season.name())
+    }
+
+    /// Returns the abbreviated name of a season.
+    pub fn season_abbreviation(&self, season: Season) -> &str {
+        lookup(
+            &self.season_abbreviations,
+            season as usize,
+            season.abbreviation(),
+        )
+    }
+
+    /// Returns the name of a weekday.
+    pub fn weekday(&self, weekday: Weekday) -> &str {
+        lookup(&self.weekdays, weekday as usize, weekday.name())
+    }
+
+    /// Returns the abbreviated name of a weekday.
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        lookup(
+            &self.weekday_abbreviations,
+            weekday as usize,
+            weekday.abbreviation(),
+        )
+    }
+
+    /// Returns the name of a holyday.
+    pub fn holyday(&self, holyday: Holyday) -> &str {
+        lookup(&self.holydays, holyday as usize, holyday.name())
+    }
+
+    /// Returns the name of St. Tib's Day.
+    pub fn st_tibs(&self) -> &str {
+        lookup(&self.st_tibs, 0, ST_TIBS)
+    }
+
+    /// Renders date components in the style of `DiscordianDate::to_poee`,
+    /// with these names.
+    pub fn to_poee(&self, date: &DiscordianDateComponents) -> String {
+        poee(date, None, self)
+    }
+
+    /// Renders date components as `to_poee` does, with these names
+    /// transliterated to ASCII, for terminals which cannot show anything
+    /// else. Characters with no ASCII fallback, such as emoji, are stripped,
+    /// and a name left empty gives way to the built-in name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ddate::{DiscordianDateComponents, Names, Season};
+    ///
+    /// let names = Names::parse("seasons = Chaos, Zwietracht, Verwirrung, Bürokratie, Nachspiel").unwrap();
+    /// let ddate = DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 26).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "Setting Orange, the 26th day of Buerokratie in the YOLD 3183",
+    ///     names.to_poee_ascii_only(&ddate)
+    /// );
+    /// ```
+    pub fn to_poee_ascii_only(&self, date: &DiscordianDateComponents) -> String {
+        let names = Names {
+            seasons: ascii(&self.seasons, |n| Season::ALL[n].name()),
+            season_abbreviations: ascii(&self.season_abbreviations, |n| {
+                Season::ALL[n].abbreviation()
+            }),
+            weekdays: ascii(&self.weekdays, |n| Weekday::ALL[n].name()),
+            weekday_abbreviations: ascii(&self.weekday_abbreviations, |n| {
+                Weekday::ALL[n].abbreviation()
+            }),
+            holydays: ascii(&self.holydays, |n| Holyday::ALL[n].name()),
+            st_tibs: ascii(&self.st_tibs, |_| ST_TIBS),
+        };
+
+        poee(date, None, &names)
+    }
+
+    /// Writes date components as `to_poee` renders them, without allocating.
+    pub fn write_poee<W: fmt::Write>(
+        &self,
+        date: &DiscordianDateComponents,
+        out: &mut W,
+    ) -> fmt::Result {
+        write!(
+            out,
+            "{}",
+            Poee {
+                date,
+                gregorian: None,
+                names: self,
+            }
+        )
+    }
+}
+
+/// Returns an overridden name, or the built-in name if there is no override.
+fn lookup<'a>(names: &'a Option<Vec<String>>, index: usize, default: &'a str) -> &'a str {
+    names
+        .as_ref()
+        .and_then(|names| names.get(index))
+        .map_or(default, String::as_str)
+}
+
+/// Transliterates overridden names to ASCII, falling back to the built-in
+/// name for any name left empty.
+fn ascii(
+    names: &Option<Vec<String>>,
+    default: impl Fn(usize) -> &'static str,
+) -> Option<Vec<String>> {
+    let names = names.as_ref()?;
+
+    Some(
+        names
+            .iter()
+            .enumerate()
+            .map(|(n, name)| {
+                let mut out = String::with_capacity(name.len());
+                for c in name.chars() {
+                    if c.is_ascii() {
+                        out.push(c);
+                    } else if let Some(&(_, fallback)) =
+                        ASCII_FALLBACKS.iter().find(|&&(from, _)| from == c)
+                    {
+                        out.push_str(fallback);
+                    }
+                }
+
+                let out = out.split_whitespace().collect::<Vec<_>>().join(" ");
+                if out.is_empty() {
+                    default(n).to_string()
+                } else {
+                    out
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Names, NamesError};
+    use crate::{DiscordianDateComponents, Format, Holyday, Season, Weekday};
+
+    const ITALIAN: &str = "
+        # Nomi italiani
+        seasons = Caos, Discordia, Confusione, Burocrazia, Il Dopo
+        weekdays = Dolcemattina, Tempoboom, Pungigiorno, Pizzica-Pizzica, Arancio Calante
+        holydays = Mungday, Caosflusso, Mojoday, Discoflusso, Syaday, Confuflusso, Zaraday, Buroflusso, Maladay, Dopoflusso
+        st_tibs = Giorno di San Tib
+    ";
+
+    #[test]
+    fn parse_test() {
+        let names = Names::parse(ITALIAN).unwrap();
+
+        assert_eq!("Burocrazia", names.season(Season::Bureaucracy));
+        assert_eq!("Bcy", names.season_abbreviation(Season::Bureaucracy));
+        assert_eq!("Arancio Calante", names.weekday(Weekday::SettingOrange));
+        assert_eq!("SO", names.weekday_abbreviation(Weekday::SettingOrange));
+        assert_eq!("Buroflusso", names.holyday(Holyday::Bureflux));
+        assert_eq!("Giorno di San Tib", names.st_tibs());
+
+        assert_eq!(Names::default(), Names::parse("\n# Nothing\n").unwrap());
+        assert_eq!("St. Tib's Day", Names::default().st_tibs());
+    }
+
+    #[test]
+    fn render_test() {
+        let names = Names::parse(ITALIAN).unwrap();
+        let format = Format::new("%{%A, %e di %B%}, %Y%N%n%H");
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 50).unwrap();
+        assert_eq!(
+            "Pizzica-Pizzica, 50th di Burocrazia, 3183\nBuroflusso",
+            format.render_with_names(&ddate, "", &names)
+        );
+        assert_eq!(
+            "Giorno di San Tib, 3182",
+            format.render_with_names(
+                &DiscordianDateComponents::st_tibs_day(3182).unwrap(),
+                "",
+                &names
+            )
+        );
+        assert_eq!(
+            "Pizzica-Pizzica, the 50th day of Burocrazia in the YOLD 3183\nCelebrate Buroflusso",
+            names.to_poee(&ddate)
+        );
+
+        let mut out = String::new();
+        names.write_poee(&ddate, &mut out).unwrap();
+        assert_eq!(names.to_poee(&ddate), out);
+    }
+
+    #[test]
+    fn malformed_test() {
+        assert_eq!(
+            Err(NamesError::MissingEquals { line: 2 }),
+            Names::parse("# Names\nseasons")
+        );
+        assert_eq!(
+            Err(NamesError::UnknownKey {
+                line: 1,
+                key: "months".to_string()
+            }),
+            Names::parse("months = Jan")
+        );
+        assert_eq!(
+            Err(NamesError::WrongCount {
+                line: 1,
+                key: "weekdays".to_string(),
+                expected: 5,
+                found: 4
+            }),
+            Names::parse("weekdays = A, B, C, D")
+        );
+        assert_eq!(
+            Err(NamesError::EmptyName { line: 1 }),
+            Names::parse("weekdays = A, B, , D, E")
+        );
+        assert_eq!(
+            Err(NamesError::DuplicateKey {
+                line: 2,
+                key: "st_tibs".to_string()
+            }),
+            Names::parse("st_tibs = A\nst_tibs = B")
+        );
+        assert_eq!(
+            "line 1 gives 4 names for weekdays, not 5",
+            Names::parse("weekdays = A, B, C, D")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn ascii_only_test() {
+        let names = Names::parse(
+            "weekdays = Süßmorgen, Krachzeit \u{1f4a5}, Stechtag, Stachel\u{2013}Stachel, \u{1f34a}
+             holydays = Mungday, Chaoflux, Mojoday, Discoflux, Syaday, Confuflux, Zaraday, Bureflüx \u{2605}, Maladay, Afflux
+             st_tibs = \u{1f34e} Sankt-Tibs-Tag",
+        )
+        .unwrap();
+
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Bureaucracy, 50).unwrap();
+        assert_eq!(
+            "Stachel-Stachel, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Burefluex",
+            names.to_poee_ascii_only(&ddate)
+        );
+
+        // A name with nothing left falls back to the built-in name.
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::Chaos, 5).unwrap();
+        assert_eq!(
+            "Setting Orange, the 5th day of Chaos in the YOLD 3183\nCelebrate Mungday",
+            names.to_poee_ascii_only(&ddate)
+        );
+        assert_eq!(
+            "Sankt-Tibs-Tag, in the YOLD 3182",
+            names.to_poee_ascii_only(&DiscordianDateComponents::st_tibs_day(3182).unwrap())
+        );
+
+        assert!(!names.to_poee(&ddate).is_ascii());
+        for date in crate::DiscordianYear::new(3182) {
+            assert!(names.to_poee_ascii_only(&date).is_ascii());
+        }
+    }
+}
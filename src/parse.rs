@@ -0,0 +1,321 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+use crate::{DdateError, DiscordianDateComponents, Season, Weekday};
+use std::convert::TryFrom;
+
+/// Words carrying no meaning of their own in a Discordian date.
+const FILLER: [&str; 5] = ["the", "day", "of", "in", "on"];
+
+/// Parses a Discordian date string, such as those produced by `to_poee`.
+///
+/// Parsing is tolerant of word order and punctuation: the year may be marked
+/// by the literal `YOLD` on either side of it, the day of the season may or
+/// may not be ordinalized, and the weekday is optional (though it must be
+/// correct if given). St. Tib's Day is accepted with or without its
+/// possessive apostrophe. Anything after the first line, such as a holyday
+/// to celebrate, is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{parse_discordian, DiscordianDateComponents, Season};
+///
+/// let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16).unwrap();
+///
+/// assert_eq!(
+///     Ok(ddate),
+///     parse_discordian("Pungenday, the 16th day of The Aftermath in the YOLD 3183")
+/// );
+/// assert_eq!(Ok(ddate), parse_discordian("The Aftermath 16, 3183 YOLD"));
+/// ```
+pub fn parse_discordian(input: &str) -> Result<DiscordianDateComponents, DdateError> {
+    let line = input.lines().next().unwrap_or("").to_lowercase();
+    let line = line
+        .replace(['\'', '\u{2019}', '.'], "")
+        .replace(',', " ")
+        .replace("setting orange", "setting-orange");
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    let mut season = None;
+    let mut weekday = None;
+    let mut st_tibs = false;
+    let mut ordinal = None;
+    let mut numbers = Vec::new();
+    let mut yold_marker = None;
+
+    for (i, &token) in tokens.iter().enumerate() {
+        if let Some(number) = parse_number(token) {
+            numbers.push((i, number));
+        } else if let Some(number) = parse_ordinal(token) {
+            ordinal = Some(number);
+        } else if let Some(s) = Season::ALL.iter().find(|s| matches_name(token, s.name())) {
+            season = Some(*s);
+        } else if let Some(w) = Weekday::ALL.iter().find(|w| matches_name(token, w.name())) {
+            weekday = Some(*w);
+        } else if token == "yold" {
+            yold_marker = Some(i);
+        } else if token == "st" || token == "tibs" {
+            st_tibs = true;
+        } else if !FILLER.contains(&token) {
+            return Err(DdateError::Parse(format!("unexpected word {:?}", token)));
+        }
+    }
+
+    // The year is whichever number the YOLD literal follows or precedes;
+    // failing that, it is the last number given.
+    let year_index = yold_marker
+        .and_then(|marker| {
+            numbers
+                .iter()
+                .position(|&(i, _)| i == marker + 1)
+                .or_else(|| numbers.iter().position(|&(i, _)| i + 1 == marker))
+        })
+        .or_else(|| numbers.len().checked_sub(1))
+        .ok_or_else(|| DdateError::Parse("no year given".to_string()))?;
+    let (_, yold) = numbers.remove(year_index);
+
+    if st_tibs {
+        if season.is_some() || weekday.is_some() || ordinal.is_some() || !numbers.is_empty() {
+            return Err(DdateError::Parse(
+                "St. Tib's Day has no season, day, or weekday".to_string(),
+            ));
+        }
+        return DiscordianDateComponents::st_tibs_day(yold);
+    }
+
+    let season = season.ok_or_else(|| DdateError::Parse("no season given".to_string()))?;
+    let day = match (ordinal, &numbers[..]) {
+        (Some(day), &[]) | (None, &[(_, day)]) => day,
+        (None, &[]) => return Err(DdateError::Parse("no day given".to_string())),
+        _ => return Err(DdateError::Parse("too many numbers given".to_string())),
+    };
+    let day = u8::try_from(day).map_err(|_| DdateError::Parse(format!("no such day {}", day)))?;
+    let date = DiscordianDateComponents::from_parts(yold, season, day)?;
+
+    match weekday {
+        Some(weekday) if date.weekday != Some(weekday) => Err(DdateError::Parse(format!(
+            "{} {} is not a {}",
+            season, day, weekday
+        ))),
+        _ => Ok(date),
+    }
+}
+
+/// Parses a permalink made by `to_permalink`, such as
+/// `yold-3183/the-aftermath/16` or `yold-3182/st-tibs-day`.
+///
+/// Unlike `parse_discordian`, this is strict: the permalink must be in
+/// exactly the form `to_permalink` gives, apart from a leading or trailing
+/// slash.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::{from_permalink, DiscordianDateComponents, Season};
+///
+/// assert_eq!(
+///     DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16),
+///     from_permalink("yold-3183/the-aftermath/16")
+/// );
+/// assert_eq!(
+///     DiscordianDateComponents::st_tibs_day(3182),
+///     from_permalink("/yold-3182/st-tibs-day/")
+/// );
+/// ```
+pub fn from_permalink(input: &str) -> Result<DiscordianDateComponents, DdateError> {
+    let invalid = || DdateError::Parse(format!("{:?} is not a Discordian date permalink", input));
+    let segments: Vec<&str> = input.trim_matches('/').split('/').collect();
+
+    let yold = segments[0]
+        .strip_prefix("yold-")
+        .and_then(parse_number)
+        .ok_or_else(invalid)?;
+
+    match segments[1..] {
+        ["st-tibs-day"] => DiscordianDateComponents::st_tibs_day(yold),
+        [season, day] => {
+            let season = Season::ALL
+                .iter()
+                .find(|s| s.name().to_lowercase().replace(' ', "-") == season)
+                .ok_or_else(invalid)?;
+            let day = parse_number(day)
+                .and_then(|day| u8::try_from(day).ok())
+                .ok_or_else(invalid)?;
+            DiscordianDateComponents::from_parts(yold, *season, day)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Whether a normalized token names a season or weekday, ignoring any
+/// leading article.
+fn matches_name(token: &str, name: &str) -> bool {
+    let name = name.to_lowercase().replace(' ', "-");
+    token == name || Some(token) == name.strip_prefix("the-")
+}
+
+/// Parses a possibly-negative integer token.
+fn parse_number(token: &str) -> Option<i32> {
+    let digits = token.strip_prefix('-').unwrap_or(token);
+
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        token.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses an ordinalized numeral token, such as `16th`.
+fn parse_ordinal(token: &str) -> Option<i32> {
+    ["st", "nd", "rd", "th"]
+        .iter()
+        .filter_map(|suffix| token.strip_suffix(suffix))
+        .find_map(|number| parse_number(number).filter(|n| *n >= 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_permalink, parse_discordian};
+    use crate::{DdateError, DiscordianDateComponents, Season};
+
+    #[test]
+    fn yold_placement_test() {
+        let ddate = DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 16);
+
+        assert_eq!(ddate, parse_discordian("The Aftermath 16, YOLD 3183"));
+        assert_eq!(ddate, parse_discordian("The Aftermath 16, 3183 YOLD"));
+        assert_eq!(
+            ddate,
+            parse_discordian("16th day of The Aftermath, yold 3183")
+        );
+        assert_eq!(ddate, parse_discordian("Aftermath 16 3183"));
+        assert_eq!(
+            ddate,
+            parse_discordian("Pungenday, the 16th day of The Aftermath in the YOLD 3183")
+        );
+    }
+
+    #[test]
+    fn st_tibs_test() {
+        let tibs = DiscordianDateComponents::st_tibs_day(3166);
+
+        assert_eq!(tibs, parse_discordian("St. Tib's Day in the YOLD 3166"));
+        assert_eq!(tibs, parse_discordian("St. Tib's Day, in the YOLD 3166"));
+        assert_eq!(tibs, parse_discordian("St. Tibs Day, 3166 YOLD"));
+        assert_eq!(tibs, parse_discordian("St Tib\u{2019}s Day YOLD 3166"));
+        assert_eq!(
+            Err(DdateError::NoStTibsDay(3167)),
+            parse_discordian("St. Tib's Day in the YOLD 3167")
+        );
+        assert_eq!(
+            DiscordianDateComponents::st_tibs_day(-2147483646),
+            parse_discordian("St. Tib's Day YOLD -2147483646")
+        );
+    }
+
+    #[test]
+    fn holyday_line_test() {
+        assert_eq!(
+            DiscordianDateComponents::from_parts(3183, Season::TheAftermath, 5),
+            parse_discordian(
+                "Boomtime, the 5th day of The Aftermath in the YOLD 3183\nCelebrate Maladay"
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert!(parse_discordian("").is_err());
+        assert!(parse_discordian("The Aftermath 3183").is_err());
+        assert!(parse_discordian("16 3183").is_err());
+        assert!(parse_discordian("Sweetmorn, The Aftermath 16, 3183").is_err());
+        assert!(parse_discordian("Fnord 16, 3183").is_err());
+        assert_eq!(
+            Err(DdateError::DayOutOfRange(74)),
+            parse_discordian("Chaos 74, 3183")
+        );
+    }
+
+    #[test]
+    fn from_permalink_test() {
+        use crate::DiscordianDate;
+        use chrono::NaiveDate;
+
+        for &(y, m, d, permalink) in &[
+            (2017, 11, 4, "yold-3183/the-aftermath/16"),
+            (2017, 1, 1, "yold-3183/chaos/1"),
+            (2016, 2, 29, "yold-3182/st-tibs-day"),
+            (2016, 3, 1, "yold-3182/chaos/60"),
+            (-1170, 12, 31, "yold--4/the-aftermath/73"),
+        ] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(permalink, date.to_permalink());
+            assert_eq!(Ok(date.discordian_components()), from_permalink(permalink));
+        }
+
+        assert_eq!(
+            Err(DdateError::NoStTibsDay(3183)),
+            from_permalink("yold-3183/st-tibs-day")
+        );
+        assert_eq!(
+            Err(DdateError::DayOutOfRange(74)),
+            from_permalink("yold-3183/chaos/74")
+        );
+        for permalink in &[
+            "",
+            "3183/chaos/1",
+            "yold-3183",
+            "yold-3183/chaos",
+            "yold-3183/aftermath/16",
+            "yold-3183/chaos/1/extra",
+            "yold-3183/chaos/300",
+            "yold-3183/st-tibs-day/1",
+        ] {
+            assert_eq!(
+                Err(DdateError::Parse(format!(
+                    "{:?} is not a Discordian date permalink",
+                    permalink
+                ))),
+                from_permalink(permalink)
+            );
+        }
+    }
+
+    #[test]
+    fn commit_trailer_test() {
+        use crate::DiscordianDate;
+        use chrono::NaiveDate;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            "Discordian-Date: Pungenday, 16 The-Aftermath 3183",
+            date(2017, 11, 4).commit_trailer()
+        );
+        assert_eq!(
+            "Discordian-Date: Setting-Orange, 10 Chaos 3183",
+            date(2017, 1, 10).commit_trailer()
+        );
+        assert_eq!(
+            "Discordian-Date: St Tibs Day 3182",
+            date(2016, 2, 29).commit_trailer()
+        );
+
+        let start = date(2015, 12, 1);
+        for date in start.iter_days().take(120).chain(vec![date(-1170, 12, 31)]) {
+            let trailer = date.commit_trailer();
+            let value = trailer.strip_prefix("Discordian-Date: ").unwrap();
+
+            assert!(value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == ',' || c == ' '));
+            assert_eq!(Ok(date.discordian_components()), parse_discordian(value));
+        }
+    }
+}
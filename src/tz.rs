@@ -0,0 +1,566 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Named time zones of the IANA time zone database, such as `Asia/Kolkata`,
+//! read from the compiled zoneinfo files installed with most systems rather
+//! than from a copy of the database bundled with the crate.
+//!
+//! Zoneinfo files are looked up under the directory named by `$TZDIR`, or
+//! under `/usr/share/zoneinfo` if it is not set.
+
+use crate::{DdateError, DiscordianDate};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc};
+use std::convert::TryFrom;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Where zoneinfo files are looked up if `$TZDIR` is not set.
+const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+/// The offset from UTC of an hour of daylight saving time, in seconds, for
+/// rules which do not give one.
+const DST_HOUR: i32 = 3600;
+
+/// The local time of day of a change of offset, in seconds, for rules which
+/// do not give one.
+const CHANGE_TIME: i32 = 7200;
+
+/// A named time zone, with every change of its offset from UTC as recorded
+/// in its zoneinfo file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Zone {
+    name: String,
+    /// The offset, in seconds east of UTC, before the first transition.
+    initial: i32,
+    /// Each instant, in seconds since the Unix epoch, at which the offset
+    /// changes, with the offset from then on.
+    transitions: Vec<(i64, i32)>,
+    /// The rule for instants after the last transition, if there is one.
+    rule: Option<Rule>,
+}
+
+impl Zone {
+    /// Loads the zone of the given name, such as `Pacific/Auckland`, from the
+    /// system's zoneinfo files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DdateError::UnknownZone` if the name is not that of a zone
+    /// with a zoneinfo file, or if the file is not one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use chrono::{TimeZone, Utc};
+    /// use ddate::Zone;
+    ///
+    /// let zone = Zone::load("Asia/Kolkata").unwrap();
+    /// let instant = Utc.with_ymd_and_hms(2017, 11, 4, 12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(19_800, zone.offset_at(instant).local_minus_utc());
+    /// ```
+    pub fn load(name: &str) -> Result<Zone, DdateError> {
+        let unknown = || DdateError::UnknownZone(name.to_string());
+
+        // Only names within the zoneinfo directory, such as `Etc/GMT+5`.
+        let valid = name
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != "..")
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"/_+-".contains(&b));
+        if !valid {
+            return Err(unknown());
+        }
+
+        let dir = env::var_os("TZDIR").map_or_else(|| PathBuf::from(ZONEINFO), PathBuf::from);
+        let data = fs::read(dir.join(name)).map_err(|_| unknown())?;
+        let (initial, transitions, rule) = parse_tzif(&data).ok_or_else(unknown)?;
+
+        Ok(Zone {
+            name: name.to_string(),
+            initial,
+            transitions,
+            rule,
+        })
+    }
+
+    /// Returns the name of the zone, as given to `load`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the offset from UTC in the zone at an instant.
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> FixedOffset {
+        let at = instant.timestamp();
+        let seconds = match self.transitions.partition_point(|&(from, _)| from <= at) {
+            0 => self.initial,
+            n => match self.rule {
+                Some(rule) if n == self.transitions.len() => rule.offset_at(at),
+                _ => self.transitions[n - 1].1,
+            },
+        };
+
+        FixedOffset::east_opt(seconds).expect("offsets are checked to be within a day")
+    }
+}
+
+/// Returns the Discordian date of today in the named time zone, as with
+/// `to_poee`, for a zone such as `Asia/Kolkata`; see `Zone::load`.
+///
+/// # Errors
+///
+/// Returns `DdateError::UnknownZone` if the zone could not be loaded.
+///
+/// # Examples
+///
+/// ```no_run
+/// let ddate = ddate::today_in_zone("Asia/Kolkata").unwrap();
+///
+/// assert!(ddate.contains("YOLD"));
+/// ```
+pub fn today_in_zone(zone: &str) -> Result<String, DdateError> {
+    today_in_zone_at(zone, Utc::now())
+}
+
+/// Returns the Discordian date at an instant in the named time zone, as
+/// with `to_poee`; `today_in_zone` is this at the current instant.
+///
+/// # Errors
+///
+/// Returns `DdateError::UnknownZone` if the zone could not be loaded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use chrono::{TimeZone, Utc};
+///
+/// let instant = Utc.with_ymd_and_hms(2017, 11, 4, 11, 30, 0).unwrap();
+///
+/// assert_eq!(
+///     Ok("Prickle-Prickle, the 17th day of The Aftermath in the YOLD 3183".to_string()),
+///     ddate::today_in_zone_at("Pacific/Auckland", instant)
+/// );
+/// ```
+pub fn today_in_zone_at(zone: &str, instant: DateTime<Utc>) -> Result<String, DdateError> {
+    let offset = Zone::load(zone)?.offset_at(instant);
+
+    Ok(instant.with_timezone(&offset).date_naive().to_poee())
+}
+
+/// A rule for the offset of a zone from UTC, from the POSIX `TZ` string
+/// which ends a zoneinfo file, such as `NZST-12NZDT,M9.5.0,M4.1.0/3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rule {
+    /// The offset of standard time, in seconds east of UTC.
+    std: i32,
+    /// The offset of daylight saving time, and the changes to and from it,
+    /// if it is observed.
+    dst: Option<(i32, Change, Change)>,
+}
+
+impl Rule {
+    /// Returns the offset under the rule at an instant, in seconds since the
+    /// Unix epoch.
+    fn offset_at(self, at: i64) -> i32 {
+        let (dst, start, end) = match self.dst {
+            Some(dst) => dst,
+            None => return self.std,
+        };
+
+        let changes = DateTime::from_timestamp(at + i64::from(self.std), 0)
+            .map(|local| local.year())
+            .and_then(|year| Some((start.at(year, self.std)?, end.at(year, dst)?)));
+        match changes {
+            Some((start, end)) if start < end && (start..end).contains(&at) => dst,
+            // South of the equator, daylight saving time spans the new year.
+            Some((start, end)) if start >= end && !(end..start).contains(&at) => dst,
+            _ => self.std,
+        }
+    }
+}
+
+/// A change of offset under a rule: the day of the year, and the local time
+/// of day in seconds, which may be negative or past midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Change {
+    day: RuleDay,
+    time: i32,
+}
+
+impl Change {
+    /// Returns the instant of the change in a year, in seconds since the
+    /// Unix epoch, from the offset in force before it.
+    fn at(self, year: i32, offset: i32) -> Option<i64> {
+        let midnight = self.day.date(year)?.and_hms_opt(0, 0, 0)?.and_utc();
+
+        Some(midnight.timestamp() + i64::from(self.time) - i64::from(offset))
+    }
+}
+
+/// The day of the year of a change of offset under a rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RuleDay {
+    /// `Jn`: day `n`, from 1 to 365, never counting February 29.
+    Julian(u16),
+    /// `n`: day `n`, from 0 to 365, counting February 29.
+    Ordinal(u16),
+    /// `Mm.w.d`: weekday `d`, from 0 for Sunday, of week `w`, from 1 to 5
+    /// for the last, of month `m`.
+    Month(u8, u8, u8),
+}
+
+impl RuleDay {
+    /// Returns the date of the day in a year.
+    fn date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            RuleDay::Julian(day) => {
+                let date = NaiveDate::from_yo_opt(year, u32::from(day))?;
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+
+                if leap && day >= 60 {
+                    date.succ_opt()
+                } else {
+                    Some(date)
+                }
+            }
+            RuleDay::Ordinal(day) => NaiveDate::from_yo_opt(year, u32::from(day) + 1),
+            RuleDay::Month(month, week, weekday) => {
+                let month = u32::from(month);
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let day = 1
+                    + (u32::from(weekday) + 7 - first.weekday().num_days_from_sunday()) % 7
+                    + (u32::from(week) - 1) * 7;
+
+                // The fifth week is the last, which may be the fourth.
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .or_else(|| NaiveDate::from_ymd_opt(year, month, day - 7))
+            }
+        }
+    }
+}
+
+/// The initial offset, the transitions, and the rule of a zoneinfo file, as
+/// kept by a `Zone`.
+type Tzif = (i32, Vec<(i64, i32)>, Option<Rule>);
+
+/// Parses a zoneinfo file, in the TZif format of RFC 8536.
+fn parse_tzif(data: &[u8]) -> Option<Tzif> {
+    let mut input = data;
+    let (version, mut counts) = read_header(&mut input)?;
+    if counts.iter().any(|&count| count > data.len()) {
+        return None;
+    }
+
+    // Later versions repeat the data with 64-bit times after that of 32-bit
+    // times, for readers of version 1 only.
+    let time_size = if version == 0 {
+        4
+    } else {
+        take(&mut input, block_len(counts, 4))?;
+        counts = read_header(&mut input)?.1;
+        if counts.iter().any(|&count| count > data.len()) {
+            return None;
+        }
+        8
+    };
+    let [_, _, _, timecnt, typecnt, _] = counts;
+
+    let times = take(&mut input, timecnt * time_size)?;
+    let indices = take(&mut input, timecnt)?;
+    let types = take(&mut input, typecnt * 6)?;
+    take(
+        &mut input,
+        block_len(counts, time_size) - timecnt * (time_size + 1) - typecnt * 6,
+    )?;
+
+    let offsets = types
+        .chunks(6)
+        .map(|ttinfo| i32::try_from(read_be(&ttinfo[..4])).ok())
+        .collect::<Option<Vec<_>>>()?;
+    if offsets.is_empty() || offsets.iter().any(|offset| offset.abs() >= 86_400) {
+        return None;
+    }
+    let transitions = times
+        .chunks(time_size)
+        .zip(indices)
+        .map(|(time, &index)| Some((read_be(time), *offsets.get(usize::from(index))?)))
+        .collect::<Option<Vec<_>>>()?;
+
+    // The footer is a POSIX `TZ` string between newlines, empty if the zone
+    // has no rule.
+    let rule = match version {
+        0 => None,
+        _ => {
+            let footer = std::str::from_utf8(input).ok()?.strip_prefix('\n')?;
+            match &footer[..footer.find('\n')?] {
+                "" => None,
+                tz => Some(parse_rule(tz)?),
+            }
+        }
+    };
+
+    Some((offsets[0], transitions, rule))
+}
+
+/// Reads the header of a TZif data block, returning its version and its
+/// counts: of UT/local indicators, of standard/wall indicators, of leap
+/// second records, of transition times, of local time types, and of bytes
+/// of abbreviations.
+fn read_header(input: &mut &[u8]) -> Option<(u8, [usize; 6])> {
+    let header = take(input, 44)?;
+    if &header[..4] != b"TZif" {
+        return None;
+    }
+
+    let mut counts = [0; 6];
+    for (count, bytes) in counts.iter_mut().zip(header[20..].chunks(4)) {
+        *count = usize::try_from(read_be(bytes)).ok()?;
+    }
+
+    Some((header[4], counts))
+}
+
+/// The length of a TZif data block of the given counts and size of times.
+fn block_len(counts: [usize; 6], time_size: usize) -> usize {
+    let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+
+    timecnt * (time_size + 1)
+        + typecnt * 6
+        + charcnt
+        + leapcnt * (time_size + 4)
+        + isstdcnt
+        + isutcnt
+}
+
+/// Takes the next `len` bytes of input, if there are so many.
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if input.len() < len {
+        return None;
+    }
+
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Some(taken)
+}
+
+/// Reads a signed big-endian integer of up to eight bytes.
+fn read_be(bytes: &[u8]) -> i64 {
+    let sign = if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        -1
+    } else {
+        0
+    };
+
+    bytes.iter().fold(sign, |n: i64, &b| n << 8 | i64::from(b))
+}
+
+/// Parses a POSIX `TZ` string, as found in the footer of a zoneinfo file.
+fn parse_rule(tz: &str) -> Option<Rule> {
+    let mut input = tz;
+
+    // Offsets in `TZ` strings are west of UTC; those of a `Rule`, east.
+    skip_name(&mut input)?;
+    let std = -parse_time(&mut input)?;
+    if input.is_empty() {
+        return Some(Rule { std, dst: None });
+    }
+
+    skip_name(&mut input)?;
+    let dst = if input.starts_with(',') {
+        std + DST_HOUR
+    } else {
+        -parse_time(&mut input)?
+    };
+    input = input.strip_prefix(',')?;
+    let start = parse_change(&mut input)?;
+    input = input.strip_prefix(',')?;
+    let end = parse_change(&mut input)?;
+
+    if !input.is_empty() || std.abs() >= 86_400 || dst.abs() >= 86_400 {
+        return None;
+    }
+    Some(Rule {
+        std,
+        dst: Some((dst, start, end)),
+    })
+}
+
+/// Skips the abbreviation of a zone in a `TZ` string: three or more letters,
+/// or any characters between `<` and `>`.
+fn skip_name(input: &mut &str) -> Option<()> {
+    let len = match input.strip_prefix('<') {
+        Some(rest) => rest.find('>')? + 2,
+        None => input
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(input.len()),
+    };
+    if len < 3 {
+        return None;
+    }
+
+    *input = &input[len..];
+    Some(())
+}
+
+/// Parses a time in a `TZ` string, `[+-]hh[:mm[:ss]]`, in seconds.
+fn parse_time(input: &mut &str) -> Option<i32> {
+    let (sign, rest) = match (input.strip_prefix('-'), input.strip_prefix('+')) {
+        (Some(rest), _) => (-1, rest),
+        (_, Some(rest)) => (1, rest),
+        _ => (1, *input),
+    };
+    let len = rest
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(rest.len());
+
+    let parts: Vec<&str> = rest[..len].split(':').collect();
+    if parts.len() > 3 || parts.iter().any(|part| part.is_empty() || part.len() > 3) {
+        return None;
+    }
+
+    let mut seconds = 0;
+    for (part, unit) in parts.iter().zip(&[3600, 60, 1]) {
+        seconds += part.parse::<i32>().ok()? * unit;
+    }
+
+    *input = &rest[len..];
+    Some(sign * seconds)
+}
+
+/// Parses a change of offset in a `TZ` string: a day, `Jn`, `n`, or `Mm.w.d`,
+/// and optionally `/` and a time.
+fn parse_change(input: &mut &str) -> Option<Change> {
+    let len = input.find([',', '/']).unwrap_or(input.len());
+    let (day, mut rest) = input.split_at(len);
+
+    let day = if let Some(day) = day.strip_prefix('J') {
+        RuleDay::Julian(day.parse().ok().filter(|day| (1..=365).contains(day))?)
+    } else if let Some(day) = day.strip_prefix('M') {
+        let parts = day
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<Vec<u8>>>()?;
+        match parts[..] {
+            [month @ 1..=12, week @ 1..=5, weekday @ 0..=6] => RuleDay::Month(month, week, weekday),
+            _ => return None,
+        }
+    } else {
+        RuleDay::Ordinal(day.parse().ok().filter(|&day| day <= 365)?)
+    };
+    let time = match rest.strip_prefix('/') {
+        Some(time) => {
+            rest = time;
+            parse_time(&mut rest)?
+        }
+        None => CHANGE_TIME,
+    };
+
+    *input = rest;
+    Some(Change { day, time })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rule, Change, Rule, RuleDay};
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn parse_rule_test() {
+        assert_eq!(
+            Some(Rule {
+                std: 43_200,
+                dst: Some((
+                    46_800,
+                    Change {
+                        day: RuleDay::Month(9, 5, 0),
+                        time: 7200
+                    },
+                    Change {
+                        day: RuleDay::Month(4, 1, 0),
+                        time: 10_800
+                    },
+                )),
+            }),
+            parse_rule("NZST-12NZDT,M9.5.0,M4.1.0/3")
+        );
+        assert_eq!(
+            Some(Rule {
+                std: -36_000,
+                dst: None
+            }),
+            parse_rule("HST10")
+        );
+        assert_eq!(
+            Some(Rule {
+                std: 19_800,
+                dst: None
+            }),
+            parse_rule("IST-5:30")
+        );
+        assert_eq!(
+            Some(Rule {
+                std: -10_800,
+                dst: Some((
+                    -7200,
+                    Change {
+                        day: RuleDay::Month(3, 5, 0),
+                        time: -7200
+                    },
+                    Change {
+                        day: RuleDay::Month(10, 5, 0),
+                        time: -3600
+                    },
+                )),
+            }),
+            parse_rule("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1")
+        );
+
+        assert_eq!(None, parse_rule(""));
+        assert_eq!(None, parse_rule("UTC"));
+        assert_eq!(None, parse_rule("EST5EDT"));
+        assert_eq!(None, parse_rule("CET-1CEST,M3.5.0,M13.5.0/3"));
+        assert_eq!(None, parse_rule("CET-1CEST,M3.5.0,M10.5.0/3,"));
+    }
+
+    #[test]
+    fn rule_day_test() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        // The last Sunday of September, and the first of April.
+        assert_eq!(ymd(2017, 9, 24), RuleDay::Month(9, 5, 0).date(2017));
+        assert_eq!(ymd(2017, 4, 2), RuleDay::Month(4, 1, 0).date(2017));
+        // A fifth Sunday, when there is one.
+        assert_eq!(ymd(2017, 4, 30), RuleDay::Month(4, 5, 0).date(2017));
+
+        assert_eq!(ymd(2016, 3, 1), RuleDay::Julian(60).date(2016));
+        assert_eq!(ymd(2016, 2, 29), RuleDay::Ordinal(59).date(2016));
+        assert_eq!(ymd(2017, 12, 31), RuleDay::Julian(365).date(2017));
+    }
+
+    #[test]
+    fn rule_offset_test() {
+        let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap().timestamp();
+        let auckland = parse_rule("NZST-12NZDT,M9.5.0,M4.1.0/3").unwrap();
+        let berlin = parse_rule("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+        assert_eq!(46_800, auckland.offset_at(at(2017, 11, 4, 11)));
+        assert_eq!(43_200, auckland.offset_at(at(2017, 7, 1, 0)));
+        // 02:00 NZST on 24 September 2017 is 14:00 UTC the day before.
+        assert_eq!(43_200, auckland.offset_at(at(2017, 9, 23, 13)));
+        assert_eq!(46_800, auckland.offset_at(at(2017, 9, 23, 14)));
+
+        assert_eq!(3600, berlin.offset_at(at(2017, 11, 4, 11)));
+        assert_eq!(7200, berlin.offset_at(at(2017, 7, 1, 0)));
+        // 03:00 CEST on 29 October 2017 is 01:00 UTC.
+        assert_eq!(7200, berlin.offset_at(at(2017, 10, 29, 0)));
+        assert_eq!(3600, berlin.offset_at(at(2017, 10, 29, 1)));
+    }
+}
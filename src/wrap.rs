@@ -0,0 +1,163 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+/// Wraps text at word boundaries so that no line is wider than `width`
+/// columns, treating each existing line as a paragraph of its own.
+///
+/// Words are never split, so a word wider than `width` overflows onto a
+/// line of its own. Wide characters, such as most emoji, count as two
+/// columns, and combining marks and other zero-width characters as none.
+///
+/// # Examples
+///
+/// ```
+/// use ddate::wrap;
+///
+/// assert_eq!(
+///     "Prickle-Prickle,\nthe 50th day of\nBureaucracy\nCelebrate\nBureflux",
+///     wrap("Prickle-Prickle, the 50th day of Bureaucracy\nCelebrate Bureflux", 16)
+/// );
+/// ```
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for (n, paragraph) in text.split('\n').enumerate() {
+        if n > 0 {
+            out.push('\n');
+        }
+
+        let mut column = 0;
+        for word in paragraph.split_whitespace() {
+            let word_width = text_width(word);
+
+            if column == 0 {
+                column = word_width;
+            } else if column + 1 + word_width <= width {
+                out.push(' ');
+                column += 1 + word_width;
+            } else {
+                out.push('\n');
+                column = word_width;
+            }
+            out.push_str(word);
+        }
+    }
+
+    out
+}
+
+/// Fits text to exactly `width` columns, padding it with spaces, or
+/// truncating it with an ellipsis if it is too wide. A wide character which
+/// would straddle the edge is replaced by the padding instead.
+pub(crate) fn fit(text: &str, width: usize) -> String {
+    let mut out = String::with_capacity(text.len().max(width));
+    let mut column = 0;
+
+    if text_width(text) <= width {
+        out.push_str(text);
+        column = text_width(text);
+    } else if width > 0 {
+        for c in text.chars() {
+            let c_width = char_width(c);
+            if column + c_width > width - 1 {
+                break;
+            }
+            out.push(c);
+            column += c_width;
+        }
+        out.push('\u{2026}');
+        column += 1;
+    }
+
+    out.push_str(&" ".repeat(width - column));
+    out
+}
+
+/// Returns the number of columns a terminal will use to display text.
+fn text_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Returns the number of columns a terminal will use to display a character,
+/// approximately: the common wide and zero-width ranges are recognized, and
+/// all other characters are taken to be one column wide.
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F
+        | 0x200B..=0x200F
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0x1F3FB..=0x1F3FF
+        | 0xE0100..=0xE01EF => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fit, text_width, wrap};
+
+    #[test]
+    fn wrap_test() {
+        assert_eq!("", wrap("", 10));
+        assert_eq!("Hail\n\nEris!", wrap("Hail\n\nEris!", 10));
+        assert_eq!("Hail Eris!", wrap("  Hail   Eris!  ", 10));
+        assert_eq!("Hail\nEris!", wrap("Hail Eris!", 9));
+
+        // Words wider than the line overflow rather than being split.
+        assert_eq!(
+            "Prickle-Prickle,\nthe\n3183",
+            wrap("Prickle-Prickle, the 3183", 3)
+        );
+        assert_eq!("Hail\nEris!", wrap("Hail Eris!", 0));
+    }
+
+    #[test]
+    fn width_test() {
+        assert_eq!(5, text_width("Eris!"));
+        assert_eq!(4, text_width("\u{1F34E}\u{1F34E}"));
+        assert_eq!(1, text_width("e\u{301}"));
+        assert_eq!(1, text_width("\u{2605}"));
+        assert_eq!(
+            "\u{1F34E}\u{1F34E}\n\u{1F34E}",
+            wrap("\u{1F34E}\u{1F34E} \u{1F34E}", 5)
+        );
+    }
+
+    #[test]
+    fn fit_test() {
+        assert_eq!("Eris!     ", fit("Eris!", 10));
+        assert_eq!("Eris!", fit("Eris!", 5));
+        assert_eq!("Eri\u{2026}", fit("Eris!", 4));
+        assert_eq!("\u{2026}", fit("Eris!", 1));
+        assert_eq!("", fit("Eris!", 0));
+
+        // Wide characters are never split, and combining marks stay with
+        // the character they mark.
+        assert_eq!("\u{1F34E}\u{2026} ", fit("\u{1F34E}\u{1F34E}\u{1F34E}", 4));
+        assert_eq!("e\u{301}\u{2026}", fit("e\u{301}e\u{301}e\u{301}", 2));
+        for width in 0..8 {
+            assert_eq!(
+                width,
+                text_width(&fit("\u{1F34E}a\u{1F34E}e\u{301}", width))
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Exhaustive checks of `to_poee` over every day of a leap and common year.
+
+use chrono::{Datelike, NaiveDate};
+use ddate::DiscordianDate;
+
+const SEASONS: [&str; 5] = [
+    "Chaos",
+    "Discord",
+    "Confusion",
+    "Bureaucracy",
+    "The Aftermath",
+];
+const WEEKDAYS: [&str; 5] = [
+    "Sweetmorn",
+    "Boomtime",
+    "Pungenday",
+    "Prickle-Prickle",
+    "Setting Orange",
+];
+const APOSTLES: [&str; 5] = ["Mungday", "Mojoday", "Syaday", "Zaraday", "Maladay"];
+const HOLYDAYS: [&str; 5] = ["Chaoflux", "Discoflux", "Confuflux", "Bureflux", "Afflux"];
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Walks every Gregorian day of `year`, checking each against the season,
+/// day, and weekday it should fall on.
+fn check_year(year: i32) {
+    let mut date = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+    let mut days_seen = 0;
+    let mut tibs_seen = 0;
+
+    while date.year() == year {
+        let poee = date.to_poee();
+
+        if date.month() == 2 && date.day() == 29 {
+            assert_eq!(
+                format!("St. Tib's Day, in the YOLD {}", year + 1166),
+                poee,
+                "St. Tib's Day of {}",
+                year
+            );
+            tibs_seen += 1;
+            date = date.succ_opt().unwrap();
+            continue;
+        }
+
+        let season = days_seen / 73;
+        let day = days_seen % 73 + 1;
+        // The week runs unbroken through the year, so the weekday of a day
+        // is counted from the start of Chaos, not of its season.
+        let weekday = days_seen % 5;
+
+        let mut expected = format!(
+            "{}, the {} day of {} in the YOLD {}",
+            WEEKDAYS[weekday],
+            ordinal(day),
+            SEASONS[season],
+            year + 1166
+        );
+        match day {
+            5 => expected += &format!("\nCelebrate {}", APOSTLES[season]),
+            50 => expected += &format!("\nCelebrate {}", HOLYDAYS[season]),
+            _ => assert!(!poee.contains('\n'), "unexpected newline on {}", date),
+        }
+
+        assert_eq!(
+            expected, poee,
+            "{} should be day {} of {}",
+            date, day, SEASONS[season]
+        );
+
+        days_seen += 1;
+        date = date.succ_opt().unwrap();
+    }
+
+    assert_eq!(365, days_seen, "Discordian days in {}", year);
+    assert_eq!(
+        if year % 4 == 0 && year % 100 != 0 || year % 400 == 0 {
+            1
+        } else {
+            0
+        },
+        tibs_seen,
+        "St. Tib's Days in {}",
+        year
+    );
+}
+
+#[test]
+fn leap_year_test() {
+    check_year(2000);
+}
+
+#[test]
+fn common_year_test() {
+    check_year(2001);
+}
+
+#[test]
+fn skipped_leap_century_test() {
+    check_year(1900);
+}
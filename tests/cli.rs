@@ -0,0 +1,599 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Tests of the `ddate` binary, run as a separate process with `DDATE_NOW`
+//! fixing today's date at 2017-11-04.
+//!
+//! Longer outputs are compared against snapshots in `tests/snapshots`. Run
+//! with `UPDATE_SNAPSHOTS=1` set to write the current outputs there instead,
+//! then review the changes before committing them.
+//!
+//! Standard input is always piped here, so the interactive prompt shown on a
+//! terminal is tested by the binary's own unit tests.
+
+#![cfg(feature = "cli-full")]
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// The result of running ddate.
+#[derive(Debug, PartialEq)]
+struct Output {
+    status: i32,
+    stdout: String,
+    stderr: String,
+}
+
+/// Runs ddate with the given arguments and standard input.
+fn ddate(args: &[&str], input: &str) -> Output {
+    ddate_at("2017-11-04", args, input)
+}
+
+/// Runs ddate as though today were `now`.
+fn ddate_at(now: &str, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ddate"))
+        .args(args)
+        .env("DDATE_NOW", now)
+        .env(
+            "TZDIR",
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/zoneinfo"),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    Output {
+        status: output.status.code().unwrap(),
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: String::from_utf8(output.stderr).unwrap(),
+    }
+}
+
+/// A successful run writing `stdout`.
+fn success(stdout: &str) -> Output {
+    Output {
+        status: 0,
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+    }
+}
+
+/// Compares output against the named snapshot, or writes it there if
+/// `UPDATE_SNAPSHOTS` is set.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.txt", name));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    if expected != actual {
+        let diff: Vec<String> = expected
+            .lines()
+            .map(Some)
+            .chain(std::iter::repeat(None))
+            .zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+            .take_while(|lines| *lines != (None, None))
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(i, (expected, actual))| {
+                format!(
+                    "line {}:\n  expected: {:?}\n    actual: {:?}",
+                    i + 1,
+                    expected.unwrap_or(""),
+                    actual.unwrap_or("")
+                )
+            })
+            .collect();
+        panic!("snapshot {} differs:\n{}", name, diff.join("\n"));
+    }
+}
+
+#[test]
+fn today_test() {
+    assert_eq!(
+        success("Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"),
+        ddate(&[], "")
+    );
+    assert_eq!(
+        success("Today is Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux\n"),
+        ddate_at("2017-09-26", &[], "")
+    );
+}
+
+#[test]
+fn ddate_now_test() {
+    let output = ddate_at("Fnord", &[], "");
+
+    assert_eq!(2, output.status);
+    assert_eq!("", output.stdout);
+    assert_eq!("Could not parse DDATE_NOW \"Fnord\".\n", output.stderr);
+}
+
+#[test]
+fn dates_test() {
+    let output = ddate(
+        &[
+            "2017-11-04",
+            "2016-02-29",
+            "2016-03-01",
+            "1999-12-31",
+            "2018-01-01",
+            "July 5, 8661",
+        ],
+        "",
+    );
+
+    assert_eq!((0, ""), (output.status, output.stderr.as_str()));
+    assert_snapshot("dates", &output.stdout);
+}
+
+#[test]
+fn invalid_test() {
+    assert_eq!(
+        Output {
+            status: 1,
+            stdout: "Could not parse provided date.\n".to_string(),
+            stderr: String::new(),
+        },
+        ddate(&["2017-11-04", "not a date"], "")
+    );
+    assert_eq!(
+        Output {
+            status: 2,
+            stdout: "Unrecognized option --fnord.\n".to_string(),
+            stderr: String::new(),
+        },
+        ddate(&["--fnord"], "")
+    );
+}
+
+#[test]
+fn reverse_test() {
+    assert_eq!(
+        success("2000-02-29\n"),
+        ddate(&["--reverse", "St. Tib's Day in the YOLD 3166"], "")
+    );
+    assert_eq!(
+        success("2017-11-04\n"),
+        ddate(
+            &["--reverse", "Pungenday,", "The", "Aftermath", "16,", "3183"],
+            ""
+        )
+    );
+    assert_eq!(1, ddate(&["--reverse", "Fnord"], "").status);
+}
+
+#[test]
+fn aggregate_test() {
+    let dates = ["2017-11-04", "2016-02-29", "2018-01-01", "1999-12-31"];
+
+    assert_eq!(
+        success("2018-01-01 is Sweetmorn, the 1st day of Chaos in the YOLD 3184\n"),
+        ddate(&[&["--max"], &dates[..]].concat(), "")
+    );
+    assert_eq!(
+        success("1999-12-31 is Setting Orange, the 73rd day of The Aftermath in the YOLD 3165\n"),
+        ddate(&[&["--min"], &dates[..]].concat(), "")
+    );
+}
+
+#[test]
+fn assert_holyday_test() {
+    assert_eq!(success(""), ddate(&["--assert-holyday", "2017-09-26"], ""));
+    assert_eq!(1, ddate(&["--assert-holyday"], "").status);
+    assert_eq!(
+        success(""),
+        ddate_at("2017-12-08", &["--assert-holyday=Afflux"], "")
+    );
+    assert_eq!(2, ddate(&["--assert-holyday=Fnordflux"], "").status);
+}
+
+#[test]
+fn format_test() {
+    assert_eq!(
+        success("PD, Afm 16, 3183\n"),
+        ddate(&["--format", "%a, %b %d, %Y"], "")
+    );
+    assert_eq!(
+        success("It's St. Tib's Day, 3182. \n"),
+        ddate(
+            &[
+                "--format=It's %{%A, the %e of %B%}, %Y. %N%nCelebrate %H",
+                "2016-02-29"
+            ],
+            ""
+        )
+    );
+}
+
+#[test]
+fn interactive_test() {
+    let output = ddate(
+        &["--interactive"],
+        "today\n\
+         next holyday\n\
+         until Mungday\n\
+         until Fnordflux\n\
+         2017-09-26\n\
+         St. Tib's Day in the YOLD 3166\n\
+         not a date\n\
+         exit\n\
+         2000-01-01\n",
+    );
+
+    assert_eq!((0, ""), (output.status, output.stderr.as_str()));
+    assert_snapshot("interactive", &output.stdout);
+}
+
+#[test]
+fn interactive_format_test() {
+    let output = ddate(
+        &["--interactive", "--format", "%{%A, %B %d%}, %Y YOLD"],
+        "today\n2017-09-26\n2016-02-29\nnext holyday\n",
+    );
+
+    assert_eq!((0, ""), (output.status, output.stderr.as_str()));
+    assert_snapshot("interactive_format", &output.stdout);
+}
+
+#[test]
+fn fortune_file_test() {
+    let fortunes = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/discordia.fortunes"
+    );
+    let args = ["--format", "%a: %.", "--fortune-file", fortunes];
+
+    let output = ddate(&args, "");
+    assert_eq!((0, ""), (output.status, output.stderr.as_str()));
+    assert!(
+        [
+            "PD: Hail Eris!\n",
+            "PD: All Hail Discordia!\n",
+            "PD: Kallisti!\n"
+        ]
+        .contains(&output.stdout.as_str()),
+        "{:?}",
+        output.stdout
+    );
+    assert_eq!(output, ddate(&args, ""));
+
+    let weighted = ddate(&[&args[..], &["--fortune-weighted"]].concat(), "");
+    assert_eq!(0, weighted.status);
+    assert!(weighted.stdout.starts_with("PD: "));
+}
+
+#[test]
+fn malformed_fortune_file_test() {
+    let fortunes = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/malformed.fortunes"
+    );
+    let output = ddate(&["--format", "%.", "--fortune-file", fortunes], "");
+
+    assert_eq!(1, output.status);
+    assert_eq!(
+        format!(
+            "Could not read fortune file {}: entry 2 (line 3) is empty.\n",
+            fortunes
+        ),
+        output.stdout
+    );
+}
+
+#[test]
+fn lookahead_test() {
+    assert_eq!(
+        success(
+            "Today is Prickle-Prickle, the 44th day of Chaos in the YOLD 3182\n\
+             Chaoflux is in 6 days (Setting Orange, Chaos 50)\n\
+             St. Tib's Day is in 16 days\n"
+        ),
+        ddate_at("2016-02-13", &["--lookahead", "16"], "")
+    );
+    assert_eq!(
+        success("Today is Pungenday, the 49th day of Bureaucracy in the YOLD 3183\nBureflux is tomorrow (Prickle-Prickle, Bureaucracy 50)\n"),
+        ddate_at("2017-09-25", &["--lookahead=1"], "")
+    );
+}
+
+#[test]
+fn wrap_test() {
+    assert_eq!(
+        success("Today is Pungenday, the 16th\nday of The Aftermath in the\nYOLD 3183\n"),
+        ddate(&["--wrap", "30"], "")
+    );
+    assert_eq!(
+        success("Pungenday,\nAfm 16\n"),
+        ddate(&["--wrap=6", "--format", "%A, %b %d"], "")
+    );
+}
+
+#[test]
+fn iso_input_test() {
+    assert_eq!(
+        success("2018-12-31 is Setting Orange, the 73rd day of The Aftermath in the YOLD 3184\n"),
+        ddate(&["2019-W01-1"], "")
+    );
+}
+
+#[test]
+fn relative_test() {
+    assert_eq!(
+        success("That's Pungenday, Afm 36 \u{2014} 3 days ago\n"),
+        ddate_at(
+            "2017-11-27",
+            &["--relative", "--format=%A, %b %d", "2017-11-24"],
+            ""
+        )
+    );
+}
+
+#[test]
+fn batch_test() {
+    assert_eq!(
+        success("Bcy 50\n\nAfm 16\n"),
+        ddate(&["--batch", "--format=%b %d"], "2017-09-26\n\n2017-11-04\n")
+    );
+    assert_eq!(
+        success("Bcy 50\n\nAfm 16\n"),
+        ddate(
+            &["--batch", "--parallel=2", "--format=%b %d"],
+            "2017-09-26\n\n2017-11-04\n"
+        )
+    );
+}
+
+#[test]
+fn csv_test() {
+    let events = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/events.csv");
+
+    assert_eq!(
+        Output {
+            status: 0,
+            stdout: "date,\"event, with comma\",notes,discordian\n\
+                     2017-09-26,Bureflux,\"said \"\"hail\"\"\nthen left\",\"Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux\"\n\
+                     soon,Fnord,,\n\
+                     2017-11-04,,,\"Pungenday, the 16th day of The Aftermath in the YOLD 3183\"\n"
+                .to_string(),
+            stderr: "Warning: 1 cell could not be parsed as a date.\n".to_string(),
+        },
+        ddate(&["csv", "--input", events, "--column", "date"], "")
+    );
+    assert_eq!(
+        Output {
+            status: 0,
+            stdout: "date,\"event, with comma\",notes\n\
+                     Bcy 50,Bureflux,\"said \"\"hail\"\"\nthen left\"\n\
+                     ,Fnord,\n\
+                     Afm 16,,\n"
+                .to_string(),
+            stderr: "Warning: 1 cell could not be parsed as a date.\n".to_string(),
+        },
+        ddate(
+            &["csv", "--column=1", "--replace", "--format=%b %d"],
+            &fs::read_to_string(events).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_vectors_test() {
+    let vectors = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vectors.csv");
+    assert_eq!(
+        success(&fs::read_to_string(vectors).unwrap()),
+        ddate(&["--generate-test-vectors", "2015-12-01", "2017-01-31"], "")
+    );
+}
+
+#[test]
+fn version_test() {
+    let output = ddate(&["--version"], "");
+    let first = output.stdout.lines().next().unwrap();
+
+    assert_eq!(0, output.status);
+    assert_eq!(format!("ddate {}", env!("CARGO_PKG_VERSION")), first);
+    assert_eq!(2, output.stdout.lines().count());
+}
+
+#[test]
+fn zones_test() {
+    assert_eq!(
+        success(
+            "* +13:00: Prickle-Prickle, the 17th day of The Aftermath in the YOLD 3183\n  \
+             -08:00: Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"
+        ),
+        ddate_at("2017-11-04T23:30:00+01:00", &["--zones=+13:00,-08:00"], "")
+    );
+}
+
+#[test]
+fn named_zones_test() {
+    // Daylight saving time in Auckland, but no longer in Berlin, and not
+    // yet over in Los Angeles.
+    assert_eq!(
+        success(
+            "* Pacific/Auckland: Prickle-Prickle, the 17th day of The Aftermath in the YOLD 3183\n  \
+             Europe/Berlin: Pungenday, the 16th day of The Aftermath in the YOLD 3183\n  \
+             America/Los_Angeles: Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"
+        ),
+        ddate_at(
+            "2017-11-04T23:30:00+01:00",
+            &["--zones", "Pacific/Auckland,Europe/Berlin,America/Los_Angeles"],
+            ""
+        )
+    );
+    // Twelve hours earlier, it is not yet tomorrow there.
+    assert_eq!(
+        success(
+            "[\n  \
+             {\"zone\": \"Pacific/Auckland\", \"date\": \"2017-11-04\", \"discordian\": \"Afm 16\", \"differs\": false},\n  \
+             {\"zone\": \"-08:00\", \"date\": \"2017-11-04\", \"discordian\": \"Afm 16\", \"differs\": false}\n\
+             ]\n"
+        ),
+        ddate_at(
+            "2017-11-04T11:30:00+01:00",
+            &["--zones=Pacific/Auckland,-08:00", "--json", "--format=%b %d"],
+            ""
+        )
+    );
+
+    assert_eq!(
+        Output {
+            status: 2,
+            stdout: "Unrecognized time zone Mars/Olympus_Mons: give the name of a time zone, \
+                     such as Pacific/Auckland, or an offset from UTC, such as +13:00.\n"
+                .to_string(),
+            stderr: String::new(),
+        },
+        ddate(&["--zones", "Mars/Olympus_Mons"], "")
+    );
+}
+
+#[test]
+fn all_locales_test() {
+    let output = ddate(&["--all-locales", "2017-11-04"], "");
+    let lines: Vec<&str> = output.stdout.lines().collect();
+
+    assert_eq!(0, output.status);
+    assert!(lines.contains(&"en: Pungenday, the 16th day of The Aftermath in the YOLD 3183"));
+    assert!(lines.iter().any(|line| line.starts_with("de: Stechtag, ")));
+}
+
+#[test]
+fn raw_ordinal_test() {
+    assert_eq!(
+        success("308\n60\n60\n"),
+        ddate(
+            &["--raw-ordinal", "2017-11-04", "2016-02-29", "2017-03-01"],
+            ""
+        )
+    );
+}
+
+#[test]
+fn card_test() {
+    let output = ddate(&["--card", "2017-11-04"], "");
+    let lines: Vec<&str> = output.stdout.lines().collect();
+
+    assert_eq!(0, output.status);
+    assert_eq!(3, lines.len());
+    assert!(lines[0].starts_with('\u{250c}') && lines[0].ends_with('\u{2510}'));
+    assert!(lines[2].starts_with('\u{2514}') && lines[2].ends_with('\u{2518}'));
+
+    // The date is centered between the sides of the frame.
+    let inside = lines[1]
+        .strip_prefix('\u{2502}')
+        .and_then(|line| line.strip_suffix('\u{2502}'))
+        .unwrap();
+    let date = "Pungenday, The Aftermath 16, 3183 YOLD";
+    assert_eq!(date, inside.trim());
+    let left = inside.len() - inside.trim_start().len();
+    let right = inside.len() - inside.trim_end().len();
+    assert!(left == right || left + 1 == right);
+}
+
+#[test]
+fn ics_test() {
+    let output = ddate(&["--ics", "2017"], "");
+    assert_eq!(0, output.status);
+
+    let lines: Vec<&str> = output.stdout.split_terminator("\r\n").collect();
+    assert_eq!(Some(&"BEGIN:VCALENDAR"), lines.first());
+    assert_eq!(Some(&"END:VCALENDAR"), lines.last());
+    assert_eq!(1, lines.iter().filter(|&&l| l == "BEGIN:VCALENDAR").count());
+    assert_eq!(10, lines.iter().filter(|&&l| l == "BEGIN:VEVENT").count());
+    assert!(lines.contains(&"DTSTART;VALUE=DATE:20170105"));
+    assert!(lines.contains(&"SUMMARY:Mungday"));
+}
+
+#[test]
+fn auto_test() {
+    assert_eq!(
+        success(
+            "Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux\n"
+        ),
+        ddate(&["--auto", "2017-09-26"], "")
+    );
+    assert_eq!(
+        success("2017-09-26\n"),
+        ddate(&["--auto", "Bureaucracy 50, 3183 YOLD"], "")
+    );
+    assert_eq!(
+        success("2017-09-26 is Prickle-Prickle, the 50th day of Bureaucracy in the YOLD 3183\nCelebrate Bureflux\n"),
+        ddate(&["--auto", "--iso", "the 50th day of Bureaucracy in the YOLD 3183"], "")
+    );
+
+    let output = ddate(&["--auto", "Chaos 99, 3183"], "");
+    assert_eq!(1, output.status);
+    assert!(output
+        .stdout
+        .contains("day 99 is outside of the 73 days of a season"));
+}
+
+#[test]
+fn names_test() {
+    let names = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/italian.names");
+
+    assert_eq!(
+        success("Today is Pungigiorno, the 16th day of Il Dopo in the YOLD 3183\n"),
+        ddate(&["--names", names], "")
+    );
+    assert_eq!(
+        success(
+            "2017-09-26 is Pizzica-Pizzica, the 50th day of Burocrazia in the YOLD 3183\n\
+             Celebrate Buroflusso\n"
+        ),
+        ddate(&[&format!("--names={}", names), "2017-09-26"], "")
+    );
+    assert_eq!(
+        success("PP Bur, 3183\nGiorno di San Tib, 3182\n"),
+        ddate(
+            &[
+                "--names",
+                names,
+                "--format",
+                "%{%a %b%}, %Y",
+                "2017-09-26",
+                "2016-02-29"
+            ],
+            ""
+        )
+    );
+
+    let output = ddate(&["--names", "/nonexistent/ddate.names"], "");
+    assert_eq!(1, output.status);
+    assert!(output
+        .stdout
+        .starts_with("Could not read names file /nonexistent/ddate.names: "));
+}
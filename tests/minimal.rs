@@ -0,0 +1,68 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Smoke tests of the `ddate` binary which hold for the minimal build as
+//! well as the full one, run against the minimal build with
+//! `cargo test --no-default-features --features cli-minimal --test minimal`.
+
+#![cfg(feature = "cli-minimal")]
+
+use std::process::Command;
+
+/// Runs ddate with the given arguments, as though today were 2017-11-04,
+/// returning its exit status and standard output.
+fn ddate(args: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_ddate"))
+        .args(args)
+        .env("DDATE_NOW", "2017-11-04")
+        .output()
+        .unwrap();
+
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn today_test() {
+    assert_eq!(
+        (
+            0,
+            "Today is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n".to_string()
+        ),
+        ddate(&[])
+    );
+}
+
+#[test]
+fn iso_test() {
+    assert_eq!(
+        (
+            0,
+            "2016-02-29 is St. Tib's Day, in the YOLD 3182\n\
+             2017-11-04 is Pungenday, the 16th day of The Aftermath in the YOLD 3183\n"
+                .to_string()
+        ),
+        ddate(&["2016-02-29", "2017-W44-6"])
+    );
+    assert_eq!(1, ddate(&["Fnord"]).0);
+}
+
+#[test]
+fn format_test() {
+    assert_eq!(
+        (0, "Afm 16, 3183\n".to_string()),
+        ddate(&["--format=%b %d, %Y"])
+    );
+    assert_eq!(
+        (0, "Bcy 50 Bureflux\n".to_string()),
+        ddate(&["--format", "%b %d %H", "2017-09-26"])
+    );
+}
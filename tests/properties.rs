@@ -0,0 +1,157 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Property checks of calendar invariants over dates drawn from the whole
+//! range chrono supports.
+//!
+//! Dates come from a fixed-seed generator, so failures are reproducible; the
+//! failing date is named in each assertion in lieu of shrinking.
+
+use chrono::{Datelike, NaiveDate};
+use ddate::{DiscordianDate, DiscordianDateComponents, Season, Weekday};
+
+/// The number of random dates each property is checked against.
+const CASES: usize = 2_000;
+
+/// A small xorshift generator, so as to need no dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Draws a date anywhere in chrono's range, negative years included,
+    /// with one draw in four landing near St. Tib's Day.
+    fn date(&mut self) -> NaiveDate {
+        let min = NaiveDate::MIN.num_days_from_ce() as i64;
+        let max = NaiveDate::MAX.num_days_from_ce() as i64;
+        let days = min + (self.next() % (max - min + 1) as u64) as i64;
+        let date = NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap();
+
+        if self.next().is_multiple_of(4) {
+            let year = date.year() - date.year().rem_euclid(4);
+            NaiveDate::from_ymd_opt(year, 2, 27 + (self.next() % 3) as u32).unwrap_or(date)
+        } else {
+            date
+        }
+    }
+}
+
+fn dates() -> impl Iterator<Item = NaiveDate> {
+    let mut rng = Rng(0x2323_2323_5555_5555);
+    (0..CASES).map(move |_| rng.date())
+}
+
+#[test]
+fn round_trip_property() {
+    for date in dates() {
+        assert_eq!(
+            Some(date),
+            date.discordian_components().to_naive_date(),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn yold_property() {
+    for date in dates() {
+        assert_eq!(
+            date.year() + 1166,
+            date.discordian_components().yold,
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn to_poee_property() {
+    for date in dates() {
+        let poee = date.to_poee();
+        let yold = (date.year() + 1166).to_string();
+        assert!(poee.lines().next().unwrap().ends_with(&yold), "{}", date);
+    }
+}
+
+#[test]
+fn weekday_property() {
+    for date in dates() {
+        let today = match date.discordian_weekday() {
+            Some(weekday) => weekday,
+            None => continue,
+        };
+
+        // The week skips over St. Tib's Day entirely.
+        let next = date
+            .succ_opt()
+            .and_then(|next| match next.discordian_weekday() {
+                Some(_) => Some(next),
+                None => next.succ_opt(),
+            });
+        if let Some(next) = next {
+            assert_eq!(
+                Some(Weekday::ALL[(today as usize + 1) % 5]),
+                next.discordian_weekday(),
+                "{}",
+                date
+            );
+        }
+    }
+}
+
+#[test]
+fn season_boundary_property() {
+    for date in dates() {
+        let ddate = date.discordian_components();
+        let next = match Season::ALL.get(ddate.season as usize + 1) {
+            Some(&season) => (ddate.yold, season),
+            None => (ddate.yold + 1, Season::Chaos),
+        };
+
+        let start = DiscordianDateComponents::from_parts(ddate.yold, ddate.season, 1)
+            .unwrap()
+            .to_naive_date();
+        let end = DiscordianDateComponents::from_parts(next.0, next.1, 1)
+            .unwrap()
+            .to_naive_date();
+
+        if let (Some(start), Some(end)) = (start, end) {
+            let tibs = ddate.season == Season::Chaos && start.leap_year();
+            let length = if tibs { 74 } else { 73 };
+            assert_eq!(length, (end - start).num_days(), "{}", date);
+            assert!(start <= date && date < end, "{}", date);
+        }
+    }
+}
+
+#[test]
+fn st_tibs_property() {
+    for date in dates() {
+        let year = date.year();
+        let leap = year % 4 == 0 && year % 100 != 0 || year % 400 == 0;
+
+        let tibs = (1..=if leap { 366 } else { 365 })
+            .filter_map(|day| NaiveDate::from_yo_opt(year, day))
+            .filter(|d| d.discordian_components().st_tibs)
+            .collect::<Vec<_>>();
+
+        let expected = if leap {
+            vec![NaiveDate::from_ymd_opt(year, 2, 29).unwrap()]
+        } else {
+            vec![]
+        };
+        assert_eq!(expected, tibs, "{}", year);
+    }
+}
@@ -0,0 +1,119 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Tests of named time zones against copies of their zoneinfo files in
+//! `tests/fixtures/zoneinfo`, so that they do not depend on those installed.
+
+#![cfg(feature = "tz")]
+
+use chrono::{DateTime, TimeZone, Utc};
+use ddate::{today_in_zone_at, DdateError, Zone};
+use std::env;
+use std::path::Path;
+
+/// Points `$TZDIR` at the fixtures. Every test sets it alike, so it does not
+/// matter which runs first.
+fn fixtures() {
+    env::set_var(
+        "TZDIR",
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/zoneinfo"),
+    );
+}
+
+fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+#[test]
+fn date_line_test() {
+    fixtures();
+
+    // 11:30 UTC is past midnight in Auckland, but still morning in Honolulu.
+    let instant = at(2017, 11, 4, 11, 30);
+    assert_eq!(
+        Ok("Prickle-Prickle, the 17th day of The Aftermath in the YOLD 3183".to_string()),
+        today_in_zone_at("Pacific/Auckland", instant)
+    );
+    assert_eq!(
+        Ok("Pungenday, the 16th day of The Aftermath in the YOLD 3183".to_string()),
+        today_in_zone_at("Pacific/Honolulu", instant)
+    );
+
+    // An hour earlier, it is not yet midnight in Auckland.
+    assert_eq!(
+        Ok("Pungenday, the 16th day of The Aftermath in the YOLD 3183".to_string()),
+        today_in_zone_at("Pacific/Auckland", at(2017, 11, 4, 10, 30))
+    );
+}
+
+#[test]
+fn offset_test() {
+    fixtures();
+
+    let auckland = Zone::load("Pacific/Auckland").unwrap();
+    assert_eq!("Pacific/Auckland", auckland.name());
+    assert_eq!(
+        13 * 3600,
+        auckland.offset_at(at(2017, 11, 4, 12, 0)).local_minus_utc()
+    );
+    assert_eq!(
+        12 * 3600,
+        auckland.offset_at(at(2017, 7, 1, 0, 0)).local_minus_utc()
+    );
+    // Beyond the transitions in the file, the rule of its footer applies.
+    assert_eq!(
+        13 * 3600,
+        auckland.offset_at(at(2100, 1, 1, 0, 0)).local_minus_utc()
+    );
+    assert_eq!(
+        12 * 3600,
+        auckland.offset_at(at(2100, 7, 1, 0, 0)).local_minus_utc()
+    );
+
+    let berlin = Zone::load("Europe/Berlin").unwrap();
+    assert_eq!(
+        2 * 3600,
+        berlin.offset_at(at(2017, 10, 29, 0, 59)).local_minus_utc()
+    );
+    assert_eq!(
+        3600,
+        berlin.offset_at(at(2017, 10, 29, 1, 0)).local_minus_utc()
+    );
+    assert_eq!(
+        3600,
+        berlin.offset_at(at(1900, 1, 1, 0, 0)).local_minus_utc()
+    );
+
+    let honolulu = Zone::load("Pacific/Honolulu").unwrap();
+    assert_eq!(
+        -10 * 3600,
+        honolulu.offset_at(at(2100, 7, 1, 0, 0)).local_minus_utc()
+    );
+}
+
+#[test]
+fn unknown_zone_test() {
+    fixtures();
+
+    for &name in &[
+        "Mars/Olympus_Mons",
+        "",
+        "/etc/passwd",
+        "../tz.rs",
+        "Pacific",
+        "Pacific/",
+    ] {
+        assert_eq!(
+            Err(DdateError::UnknownZone(name.to_string())),
+            today_in_zone_at(name, at(2017, 11, 4, 0, 0)),
+            "{:?}",
+            name
+        );
+    }
+}
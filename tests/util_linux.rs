@@ -0,0 +1,153 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Tests of `to_poee_format` against the output of `util-linux`'s ddate, in
+//! `tests/fixtures/util-linux/*.tsv`.
+//!
+//! Only `expected.tsv` is checked in, and it is written by hand, so it is
+//! not differential coverage: it checks the examples of the manual page,
+//! not the output of the C ddate. Recorded fixtures for whole years can be
+//! generated on a machine with the C ddate installed by running
+//! `cargo test --test util_linux -- --ignored`, which writes `generated.tsv`
+//! beside it. Set `DDATE` to the path of the C ddate if it is not on the
+//! `PATH` as `ddate`.
+
+use chrono::{Datelike, NaiveDate};
+use ddate::{DiscordianDate, DATE_FORMAT, DEFAULT_FORMAT};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The formats each generated date is rendered in.
+const FORMATS: [&str; 4] = [
+    DEFAULT_FORMAT,
+    DATE_FORMAT,
+    "%a %b %d %e %Y%N %H",
+    "It's %{%A, the %e of %B%}, %Y. %N%nCelebrate %H",
+];
+/// The years generated in full: a skipped leap century, a leap century, and
+/// a year far in the future.
+const YEARS: [i32; 3] = [1900, 2000, 3000];
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/util-linux")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+#[test]
+fn expected_output_fixtures() {
+    let mut paths: Vec<_> = fs::read_dir(fixtures())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "tsv"))
+        .collect();
+    paths.sort();
+
+    let mut cases = 0;
+    let mut failures = Vec::new();
+
+    for path in &paths {
+        let fixture = fs::read_to_string(path).unwrap();
+
+        for (number, line) in fixture.lines().enumerate() {
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(3, '\t').collect();
+            let (date, format, expected) = match fields[..] {
+                [date, format, expected] => (date, unescape(format), unescape(expected)),
+                _ => panic!("{}:{}: malformed fixture", path.display(), number + 1),
+            };
+
+            let actual = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .unwrap()
+                .to_poee_format(&format);
+            cases += 1;
+
+            if actual != expected {
+                failures.push(format!(
+                    "{}:{}: {} +{}\n  expected: {}\n    actual: {}",
+                    path.file_name().unwrap().to_string_lossy(),
+                    number + 1,
+                    date,
+                    escape(&format),
+                    escape(&expected),
+                    escape(&actual)
+                ));
+            }
+        }
+    }
+
+    assert!(cases > 0, "no fixtures found");
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases differ from the fixtures:\n{}",
+        failures.len(),
+        cases,
+        failures.join("\n")
+    );
+}
+
+#[test]
+#[ignore]
+fn generate_util_linux_fixtures() {
+    let ddate = std::env::var("DDATE").unwrap_or_else(|_| "ddate".to_string());
+    let mut fixture = String::from("# Generated by util-linux ddate; see tests/util_linux.rs.\n");
+
+    for &year in &YEARS {
+        let mut date = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+
+        while date.year() == year {
+            for format in &FORMATS {
+                let output = Command::new(&ddate)
+                    .arg(format!("+{}", format))
+                    .arg(date.day().to_string())
+                    .arg(date.month().to_string())
+                    .arg(year.to_string())
+                    .output()
+                    .expect("could not run the C ddate");
+                assert!(output.status.success(), "ddate failed on {}", date);
+
+                let output = String::from_utf8(output.stdout).unwrap();
+                let output = output.strip_suffix('\n').unwrap_or(&output);
+                fixture += &format!("{}\t{}\t{}\n", date, escape(format), escape(output));
+            }
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    fs::write(fixtures().join("generated.tsv"), fixture).unwrap();
+}
@@ -0,0 +1,71 @@
+// In jurisdictions that recognize copyright laws, the author or authors of
+// this software dedicate any and all copyright interest in the software to
+// the public domain. We make this dedication for the benefit of the public at
+// large and to the detriment of our heirs and successors. We intend this
+// dedication to be an overt act of relinquishment in perpetuity of all
+// present and future rights to this software under copyright law.
+//
+// For more information, see the file UNLICENSE at this repository's root.
+
+//! Checks the crate against the test vectors in `tests/fixtures/vectors.csv`,
+//! as written by `ddate --generate-test-vectors` for other implementations.
+
+use chrono::NaiveDate;
+use ddate::csv::Reader;
+use ddate::DiscordianDate;
+use std::fs::File;
+use std::io::BufReader;
+
+#[test]
+fn vectors_test() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vectors.csv");
+    let mut reader = Reader::new(BufReader::new(File::open(path).unwrap()));
+    let mut record = Vec::new();
+
+    assert!(reader.read_record(&mut record).unwrap());
+    assert_eq!(
+        vec![
+            "gregorian",
+            "yold",
+            "season",
+            "day",
+            "weekday",
+            "holyday",
+            "st_tibs"
+        ],
+        record
+    );
+
+    let mut count = 0;
+    while reader.read_record(&mut record).unwrap() {
+        let dt: NaiveDate = record[0].parse().unwrap();
+        let date = dt.discordian_components();
+        let (season, day, weekday, holyday) = match date.weekday {
+            Some(weekday) => (
+                date.season.name().to_string(),
+                date.day.to_string(),
+                weekday.name(),
+                date.holyday().map_or("", |holyday| holyday.name()),
+            ),
+            None => (String::new(), String::new(), "", ""),
+        };
+
+        assert_eq!(
+            vec![
+                dt.to_string(),
+                date.yold.to_string(),
+                season,
+                day,
+                weekday.to_string(),
+                holyday.to_string(),
+                date.st_tibs.to_string(),
+            ],
+            record,
+            "line {}",
+            count + 2
+        );
+        count += 1;
+    }
+
+    assert_eq!(428, count);
+}